@@ -41,7 +41,7 @@ pub fn main() -> Result<(), PdfiumError> {
             println!(
                 "Annotation {} author: {:?}",
                 annotation_index,
-                annotation.creator()
+                annotation.author()
             );
 
             println!(
@@ -70,9 +70,10 @@ pub fn main() -> Result<(), PdfiumError> {
         text_annotation.modification_date()
     );
 
-    let mut free_text_annotation = page
-        .annotations_mut()
-        .create_free_text_annotation("An inline comment on this pretty picture")?;
+    let mut free_text_annotation = page.annotations_mut().create_free_text_annotation(
+        PdfRect::new_from_values(450.0, 150.0, 500.0, 250.0),
+        "An inline comment on this pretty picture",
+    )?;
 
     println!(
         "Free text annotation creation date: {:?}",
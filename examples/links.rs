@@ -24,7 +24,15 @@ pub fn main() -> Result<(), PdfiumError> {
                 link.action().map(|action| action.action_type())
             );
 
-            // For links that have URI actions, output the destination URI.
+            println!(
+                "Page {} link {} has link action type {:?} and URI {:?}",
+                page_index,
+                link_index,
+                link.action_type(),
+                link.uri()
+            );
+
+            // Output the target of each link, depending on the type of its action.
 
             if let Some(action) = link.action() {
                 if let Some(local_destination_action) = action.as_local_destination_action() {
@@ -33,6 +41,15 @@ pub fn main() -> Result<(), PdfiumError> {
                         local_destination_action.destination()?.page_index()?
                     )
                 }
+                if let Some(remote_destination_action) = action.as_remote_destination_action() {
+                    println!(
+                        "Remote destination file path: {:#?}",
+                        remote_destination_action.file_path()
+                    )
+                }
+                if let Some(launch_action) = action.as_launch_action() {
+                    println!("Launch file path: {:#?}", launch_action.file_path())
+                }
                 if let Some(uri_action) = action.as_uri_action() {
                     println!("Link URI destination: {:#?}", uri_action.uri())
                 }
@@ -7,7 +7,9 @@ pub fn main() -> Result<(), PdfiumError> {
 
     // For every digital signature in our sample file...
 
-    let document = pdfium.load_pdf_from_file("test/signatures-test.pdf", None)?;
+    let bytes = std::fs::read("test/signatures-test.pdf").map_err(PdfiumError::IoError)?;
+
+    let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
 
     for (index, signature) in document.signatures().iter().enumerate() {
         // ... output information about the signature to the console.
@@ -26,6 +28,33 @@ pub fn main() -> Result<(), PdfiumError> {
 
         println!("Content length: {} bytes", contents.len());
         println!("Content: {:?}", contents);
+
+        // Output information about any certificates embedded in the signature.
+
+        for certificate in signature.signing_certificates()? {
+            println!(
+                "Certificate subject: {:?}, issuer: {:?}, valid until: {:?}",
+                certificate.subject_common_name(),
+                certificate.issuer_common_name(),
+                certificate.not_after()
+            );
+        }
+
+        // Check the integrity of the byte ranges covered by the signature.
+
+        match signature.verify(&bytes) {
+            Ok(verification) => {
+                match verification.digest_matches() {
+                    Some(digest_matches) => println!("Digest matches: {}", digest_matches),
+                    None => println!("Digest not recorded in signature"),
+                }
+                println!(
+                    "Covers whole document: {}",
+                    verification.covers_whole_document()
+                );
+            }
+            Err(err) => println!("Unable to verify signature: {:?}", err),
+        }
     }
 
     Ok(())
@@ -311,3 +311,49 @@ impl<'a> From<PdfActionUnsupported<'a>> for PdfAction<'a> {
         Self::Unsupported(action)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_action_file_paths() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/link-actions-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let launch = annotations.get(2)?;
+
+        let launch = launch.as_link_annotation().unwrap().link()?;
+
+        let action = launch.action().unwrap();
+
+        assert_eq!(action.action_type(), PdfActionType::Launch);
+        assert_eq!(
+            action.as_launch_action().unwrap().file_path()?,
+            "viewer.exe"
+        );
+
+        let remote = annotations.get(3)?;
+
+        let remote = remote.as_link_annotation().unwrap().link()?;
+
+        let action = remote.action().unwrap();
+
+        assert_eq!(
+            action.action_type(),
+            PdfActionType::GoToDestinationInRemoteDocument
+        );
+        assert_eq!(
+            action.as_remote_destination_action().unwrap().file_path()?,
+            "other.pdf"
+        );
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 use crate::action_private::internal::PdfActionPrivate;
 use crate::bindgen::FPDF_ACTION;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 
 pub struct PdfActionLaunch<'a> {
     handle: FPDF_ACTION,
@@ -18,6 +19,16 @@ impl<'a> PdfActionLaunch<'a> {
     ) -> Self {
         PdfActionLaunch { handle, bindings }
     }
+
+    /// Returns the path of the application or document that will be launched by this
+    /// [PdfActionLaunch].
+    ///
+    /// The path is returned exactly as recorded in the action, and may be relative
+    /// to the location of the document containing the action.
+    #[inline]
+    pub fn file_path(&self) -> Result<String, PdfiumError> {
+        self.file_path_impl()
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionLaunch<'a> {
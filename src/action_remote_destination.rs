@@ -4,6 +4,7 @@
 use crate::action_private::internal::PdfActionPrivate;
 use crate::bindgen::FPDF_ACTION;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 
 pub struct PdfActionRemoteDestination<'a> {
     handle: FPDF_ACTION,
@@ -18,6 +19,15 @@ impl<'a> PdfActionRemoteDestination<'a> {
     ) -> Self {
         PdfActionRemoteDestination { handle, bindings }
     }
+
+    /// Returns the path of the remote document targeted by this [PdfActionRemoteDestination].
+    ///
+    /// The path is returned exactly as recorded in the action, and may be relative
+    /// to the location of the document containing the action.
+    #[inline]
+    pub fn file_path(&self) -> Result<String, PdfiumError> {
+        self.file_path_impl()
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionRemoteDestination<'a> {
@@ -86,6 +86,36 @@ impl<'a> PdfAttachment<'a> {
         get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
     }
 
+    /// Returns the MIME type of the data in this [PdfAttachment], if one was recorded
+    /// when the attachment was embedded.
+    pub fn mime_type(&self) -> Option<String> {
+        // Retrieving the MIME type from Pdfium is a two-step operation, just like
+        // retrieving the attachment name. If no MIME type is recorded, Pdfium returns
+        // the length of an empty, null-terminated UTF16-LE string, i.e. two bytes.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFAttachment_GetSubtype(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length <= 2 {
+            // There is no MIME type given for this attachment.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFAttachment_GetSubtype(
+            self.handle,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+
     /// Returns the size of this [PdfAttachment] in bytes.
     pub fn len(&self) -> usize {
         // Calling FPDFAttachment_GetFile() with a null buffer will retrieve the length of the
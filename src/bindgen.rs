@@ -3484,6 +3484,17 @@ extern "C" {
         uri: *const ::std::os::raw::c_char,
     ) -> FPDF_BOOL;
 }
+extern "C" {
+    #[doc = " Experimental API.\n Get the attachment from |annot|.\n\n   annot - handle to a file annotation.\n\n Returns the handle to the attachment object, or NULL on failure."]
+    pub fn FPDFAnnot_GetFileAttachment(annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT;
+}
+extern "C" {
+    #[doc = " Experimental API.\n Add an embedded file with |name| to |annot|.\n\n   annot    - handle to a file annotation.\n   name     - name of the new attachment.\n\n Returns a handle to the new attachment object, or NULL on failure."]
+    pub fn FPDFAnnot_AddFileAttachment(
+        annot: FPDF_ANNOTATION,
+        name: FPDF_WIDESTRING,
+    ) -> FPDF_ATTACHMENT;
+}
 extern "C" {
     #[doc = " Experimental API.\n Get the number of embedded files in |document|.\n\n   document - handle to a document.\n\n Returns the number of embedded files in |document|."]
     pub fn FPDFDoc_GetAttachmentCount(document: FPDF_DOCUMENT) -> ::std::os::raw::c_int;
@@ -3561,6 +3572,14 @@ extern "C" {
         out_buflen: *mut ::std::os::raw::c_ulong,
     ) -> FPDF_BOOL;
 }
+extern "C" {
+    #[doc = " Experimental API.\n Get the MIME type (Subtype) of the embedded file |attachment|. |buffer| is\n only modified if |buflen| is longer than the length of the MIME type string.\n If the Subtype is not found or if there is no file stream, an empty string\n would be copied to |buffer| and the return value would be 2. On other errors,\n nothing would be added to |buffer| and the return value would be 0.\n\n   attachment - handle to an attachment.\n   buffer     - buffer for holding the MIME type string encoded in\n                UTF-16LE.\n   buflen     - length of the buffer in bytes.\n\n Returns the length of the MIME type string in bytes."]
+    pub fn FPDFAttachment_GetSubtype(
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut FPDF_WCHAR,
+        buflen: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_ulong;
+}
 extern "C" {
     #[doc = " Experimental API.\n\n Determine if |document| represents a tagged PDF.\n\n For the definition of tagged PDF, See (see 10.7 \"Tagged PDF\" in PDF\n Reference 1.7).\n\n   document - handle to a document.\n\n Returns |true| iff |document| is a tagged PDF."]
     pub fn FPDFCatalog_IsTagged(document: FPDF_DOCUMENT) -> FPDF_BOOL;
@@ -7,15 +7,15 @@ use crate::bindgen::{
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::render_config::PdfRenderSettings;
-use crate::utils::pixels::aligned_rgb_to_rgba;
+use crate::utils::pixels::{
+    aligned_bgr_to_rgba, aligned_bgra_to_rgba, aligned_bgrx_to_rgba, aligned_gray_to_gray,
+    aligned_rgb_to_rgba, aligned_rgba_to_rgba, aligned_rgbx_to_rgba,
+};
 use std::os::raw::c_int;
 
 #[cfg(feature = "image")]
 use image::{DynamicImage, GrayImage, RgbaImage};
 
-#[cfg(feature = "image")]
-use crate::utils::pixels::{aligned_bgr_to_rgba, bgra_to_rgba};
-
 #[cfg(not(target_arch = "wasm32"))]
 use std::os::raw::c_void;
 
@@ -252,6 +252,10 @@ impl<'a> PdfBitmap<'a> {
 
     /// Returns an owned copy of the bitmap buffer backing this [PdfBitmap], normalizing all
     /// color channels into RGBA irrespective of the original pixel format.
+    ///
+    /// Any alignment bytes at the end of each scanline are removed, so the returned buffer is
+    /// always tightly packed. Bitmaps in [PdfBitmapFormat::Gray] format are returned as
+    /// single-channel grayscale data.
     pub fn as_rgba_bytes(&self) -> Vec<u8> {
         let bytes = self.as_raw_bytes();
 
@@ -259,36 +263,49 @@ impl<'a> PdfBitmap<'a> {
 
         let width = self.width() as usize;
 
-        let stride = bytes.len() / self.height() as usize;
+        let stride = self.bindings.FPDFBitmap_GetStride(self.handle) as usize;
+
+        if stride == 0 {
+            return Vec::new();
+        }
 
         if self.was_byte_order_reversed_during_rendering {
             // The R and B channels were swapped by Pdfium during rendering, as configured by
             // a call to PdfRenderConfig::set_reverse_byte_order(true).
 
             match format {
+                PdfBitmapFormat::BGRA => {
+                    // No color conversion necessary; data was already swapped from BGRA
+                    // to four-channel RGBA during rendering.
+                    aligned_rgba_to_rgba(bytes, width, stride)
+                }
                 #[allow(deprecated)]
-                PdfBitmapFormat::BGRA | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => {
-                    // No color conversion necessary; data was already swapped from BGRx
-                    // to four-channel RGB during rendering.
-                    bytes.to_vec()
+                PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => {
+                    aligned_rgbx_to_rgba(bytes, width, stride)
                 }
                 PdfBitmapFormat::BGR => aligned_rgb_to_rgba(bytes, width, stride),
-                PdfBitmapFormat::Gray => bytes.to_vec(),
+                PdfBitmapFormat::Gray => aligned_gray_to_gray(bytes, width, stride),
             }
         } else {
             match format {
+                PdfBitmapFormat::BGRA => aligned_bgra_to_rgba(bytes, width, stride),
                 #[allow(deprecated)]
-                PdfBitmapFormat::BGRA | PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRx => {
-                    bgra_to_rgba(bytes)
+                PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => {
+                    aligned_bgrx_to_rgba(bytes, width, stride)
                 }
                 PdfBitmapFormat::BGR => aligned_bgr_to_rgba(bytes, width, stride),
-                PdfBitmapFormat::Gray => bytes.to_vec(),
+                PdfBitmapFormat::Gray => aligned_gray_to_gray(bytes, width, stride),
             }
         }
     }
 
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing this [PdfBitmap].
     ///
+    /// Color channels are normalized from the pixel format reported by Pdfium, and any
+    /// alignment bytes at the end of each scanline are skipped. Bitmaps in
+    /// [PdfBitmapFormat::Gray] format are returned as `DynamicImage::ImageLuma8`;
+    /// all other formats are returned as `DynamicImage::ImageRgba8`.
+    ///
     /// This function is only available when this crate's `image` feature is enabled.
     #[cfg(feature = "image")]
     pub fn as_image(&self) -> DynamicImage {
@@ -441,4 +458,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_as_image_skips_alignment_bytes() -> Result<(), PdfiumError> {
+        use image::GenericImageView;
+
+        // A three pixel wide grayscale bitmap has a stride of four bytes, so each scanline
+        // ends in a single alignment byte that must not appear in the converted image.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let bitmap = PdfBitmap::empty(3, 2, PdfBitmapFormat::Gray, pdfium.bindings())?;
+
+        assert_eq!(pdfium.bindings().FPDFBitmap_GetStride(bitmap.handle), 4);
+
+        pdfium
+            .bindings()
+            .FPDFBitmap_FillRect(bitmap.handle, 0, 0, 3, 2, 0xFFFFFFFF);
+
+        let image = bitmap.as_image();
+
+        assert_eq!(image.dimensions(), (3, 2));
+        assert!(image.as_luma8().unwrap().iter().all(|value| *value == 255));
+
+        Ok(())
+    }
 }
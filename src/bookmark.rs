@@ -2,7 +2,7 @@
 //! in a `PdfBookmarks` collection.
 
 use crate::action::PdfAction;
-use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT};
+use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT, PDFACTION_GOTO};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::bookmarks::PdfBookmarksIterator;
 use crate::destination::PdfDestination;
@@ -99,7 +99,10 @@ impl<'a> PdfBookmark<'a> {
     /// The action indicates the behaviour that will occur when the user interacts with the
     /// bookmark in a PDF viewer. For most bookmarks, this will be a local navigation action
     /// of type `PdfActionType::GoToDestinationInSameDocument`, but the PDF file format supports
-    /// a variety of other actions.
+    /// a variety of other actions, such as opening a URI.
+    ///
+    /// Pdfium does not recognize Javascript actions; a bookmark that runs a script will
+    /// return an action of type `PdfActionType::Unsupported`.
     pub fn action(&self) -> Option<PdfAction<'a>> {
         let handle = self.bindings.FPDFBookmark_GetAction(self.bookmark_handle);
 
@@ -118,10 +121,24 @@ impl<'a> PdfBookmark<'a> {
     ///
     /// The destination specifies the page and region, if any, that will be the target
     /// of the action behaviour specified by [PdfBookmark::action()].
+    ///
+    /// If the bookmark has an action, the action takes precedence over any destination set
+    /// directly on the bookmark, as per the PDF specification. In this case, a destination
+    /// is only returned if the action navigates to a destination in the same document.
     pub fn destination(&self) -> Option<PdfDestination<'a>> {
-        let handle = self
-            .bindings
-            .FPDFBookmark_GetDest(self.document_handle, self.bookmark_handle);
+        let action = self.bindings.FPDFBookmark_GetAction(self.bookmark_handle);
+
+        let handle = if action.is_null() {
+            self.bindings
+                .FPDFBookmark_GetDest(self.document_handle, self.bookmark_handle)
+        } else if self.bindings.FPDFAction_GetType(action) as u32 == PDFACTION_GOTO {
+            self.bindings
+                .FPDFAction_GetDest(self.document_handle, action)
+        } else {
+            // The bookmark's action does something other than navigate within this document.
+
+            return None;
+        };
 
         if handle.is_null() {
             None
@@ -259,3 +276,48 @@ impl<'a> PdfBookmark<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_bookmark_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/bookmark-actions-test.pdf", None)?;
+
+        // A bookmark with only a destination navigates within the document.
+
+        let bookmark = document.bookmarks().find_first_by_title("Chapter 1")?;
+
+        assert!(bookmark.action().is_none());
+        assert_eq!(bookmark.destination().unwrap().page_index()?, 0);
+
+        // A bookmark with both a destination and a URI action opens the URI.
+
+        let bookmark = document.bookmarks().find_first_by_title("Website")?;
+
+        let action = bookmark.action().unwrap();
+
+        assert_eq!(action.action_type(), PdfActionType::Uri);
+        assert_eq!(
+            action.as_uri_action().unwrap().uri()?,
+            "https://example.com/"
+        );
+        assert!(bookmark.destination().is_none());
+
+        // Pdfium does not recognize Javascript actions.
+
+        let bookmark = document.bookmarks().find_first_by_title("Script")?;
+
+        assert_eq!(
+            bookmark.action().unwrap().action_type(),
+            PdfActionType::Unsupported
+        );
+        assert!(bookmark.destination().is_none());
+
+        Ok(())
+    }
+}
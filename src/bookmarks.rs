@@ -18,6 +18,11 @@ use std::ptr::null_mut;
 /// To search the tree for a bookmark with a specific title, use the [PdfBookmarks::find_first_by_title()]
 /// and [PdfBookmarks::find_all_by_title()] functions. To traverse the tree breadth-first, visiting
 /// every bookmark in the tree, create an iterator using the [PdfBookmarks::iter()] function.
+///
+/// The bookmark tree is read-only. Pdfium does not provide any function for creating or
+/// removing bookmarks, nor any general-purpose function for editing the document catalog's
+/// /Outlines dictionary that bookmarks are stored in, so bookmarks cannot be added to a document,
+/// even when building a table of contents for a document assembled from several others.
 pub struct PdfBookmarks<'a> {
     document_handle: FPDF_DOCUMENT,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -0,0 +1,282 @@
+//! Defines the [PdfCertificate] struct, exposing functionality related to a single
+//! X.509 certificate embedded in a `PdfSignature`.
+
+use crate::error::PdfiumError;
+use crate::utils::asn1::{read_element, DerElement, TAG_INTEGER, TAG_SEQUENCE, TAG_SET};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+// Object identifier of the common name attribute in an X.509 distinguished name.
+
+const OID_COMMON_NAME: &str = "2.5.4.3";
+
+// DER tags of the string and time types used in X.509 certificates.
+
+const TAG_UTF8_STRING: u8 = 0x0C;
+const TAG_PRINTABLE_STRING: u8 = 0x13;
+const TAG_TELETEX_STRING: u8 = 0x14;
+const TAG_IA5_STRING: u8 = 0x16;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_BMP_STRING: u8 = 0x1E;
+
+/// A single DER-encoded X.509 certificate embedded in a `PdfSignature`.
+///
+/// Certificates are returned exactly as embedded in the signature. No attempt is made
+/// to validate a certificate or its chain of trust.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfCertificate {
+    bytes: Vec<u8>,
+}
+
+impl PdfCertificate {
+    #[inline]
+    pub(crate) fn from_der(bytes: Vec<u8>) -> Self {
+        PdfCertificate { bytes }
+    }
+
+    /// Returns the raw DER-encoded byte data for this [PdfCertificate].
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the common name (CN) attribute of the subject of this [PdfCertificate], if any.
+    ///
+    /// The subject is the entity that holds the private key corresponding to the certificate,
+    /// typically the signer.
+    pub fn subject_common_name(&self) -> Result<Option<String>, PdfiumError> {
+        Self::common_name(self.tbs_certificate_field(5)?)
+    }
+
+    /// Returns the common name (CN) attribute of the issuer of this [PdfCertificate], if any.
+    ///
+    /// The issuer is the certificate authority that signed the certificate.
+    pub fn issuer_common_name(&self) -> Result<Option<String>, PdfiumError> {
+        Self::common_name(self.tbs_certificate_field(3)?)
+    }
+
+    /// Returns the date and time from which this [PdfCertificate] is valid.
+    pub fn not_before(&self) -> Result<DateTime<Utc>, PdfiumError> {
+        Self::time(self.validity_field(0)?)
+    }
+
+    /// Returns the date and time after which this [PdfCertificate] is no longer valid.
+    pub fn not_after(&self) -> Result<DateTime<Utc>, PdfiumError> {
+        Self::time(self.validity_field(1)?)
+    }
+
+    /// Returns the serial number assigned to this [PdfCertificate] by its issuer,
+    /// as a big-endian sequence of bytes.
+    pub fn serial_number(&self) -> Result<Vec<u8>, PdfiumError> {
+        let serial_number = self.tbs_certificate_field(1)?;
+
+        if serial_number.tag != TAG_INTEGER || serial_number.contents.is_empty() {
+            return Err(PdfiumError::CertificateMalformed);
+        }
+
+        // Positive DER integers with a leading one bit are prefixed with a zero byte;
+        // the prefix is not part of the serial number.
+
+        match serial_number.contents {
+            [0, rest @ ..] if !rest.is_empty() => Ok(rest.to_vec()),
+            contents => Ok(contents.to_vec()),
+        }
+    }
+
+    /// Returns the field at the given index in the TBSCertificate structure of this
+    /// [PdfCertificate], taking the optional leading version field into account.
+    ///
+    /// TBSCertificate ::= SEQUENCE { version [0] EXPLICIT OPTIONAL, serialNumber, signature,
+    ///     issuer, validity, subject, subjectPublicKeyInfo, ... }
+    fn tbs_certificate_field(&self, index: usize) -> Result<DerElement, PdfiumError> {
+        let certificate = read_element(self.bytes.as_slice())
+            .filter(|(element, _)| element.tag == TAG_SEQUENCE)
+            .and_then(|(element, _)| element.children())
+            .ok_or(PdfiumError::CertificateMalformed)?;
+
+        let tbs_certificate = certificate
+            .first()
+            .filter(|element| element.tag == TAG_SEQUENCE)
+            .and_then(|element| element.children())
+            .ok_or(PdfiumError::CertificateMalformed)?;
+
+        // Version 1 certificates omit the version field entirely.
+
+        let offset = match tbs_certificate.first() {
+            Some(element) if element.is_context_specific(0) => 0,
+            _ => 1,
+        };
+
+        index
+            .checked_sub(offset)
+            .and_then(|index| tbs_certificate.get(index))
+            .copied()
+            .ok_or(PdfiumError::CertificateMalformed)
+    }
+
+    /// Returns the field at the given index in the Validity structure of this [PdfCertificate].
+    fn validity_field(&self, index: usize) -> Result<DerElement, PdfiumError> {
+        self.tbs_certificate_field(4)?
+            .children()
+            .and_then(|validity| validity.get(index).copied())
+            .ok_or(PdfiumError::CertificateMalformed)
+    }
+
+    /// Returns the value of the common name attribute in the given distinguished name, if any.
+    fn common_name(name: DerElement) -> Result<Option<String>, PdfiumError> {
+        // Name ::= SEQUENCE OF RelativeDistinguishedName
+        // RelativeDistinguishedName ::= SET OF SEQUENCE { type OBJECT IDENTIFIER, value ANY }
+
+        let relative_distinguished_names = name
+            .children()
+            .filter(|_| name.tag == TAG_SEQUENCE)
+            .ok_or(PdfiumError::CertificateMalformed)?;
+
+        for relative_distinguished_name in relative_distinguished_names {
+            let attributes = relative_distinguished_name
+                .children()
+                .filter(|_| relative_distinguished_name.tag == TAG_SET)
+                .ok_or(PdfiumError::CertificateMalformed)?;
+
+            for attribute in attributes {
+                let attribute = attribute
+                    .children()
+                    .ok_or(PdfiumError::CertificateMalformed)?;
+
+                if let [attribute_type, value, ..] = attribute.as_slice() {
+                    if attribute_type.as_oid().as_deref() == Some(OID_COMMON_NAME) {
+                        return Self::string(value).map(Some);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Decodes the given DER string element into a Rust String.
+    fn string(element: &DerElement) -> Result<String, PdfiumError> {
+        match element.tag {
+            TAG_UTF8_STRING | TAG_PRINTABLE_STRING | TAG_IA5_STRING => {
+                String::from_utf8(element.contents.to_vec())
+                    .map_err(|_| PdfiumError::CertificateMalformed)
+            }
+            TAG_TELETEX_STRING => {
+                // In practice, T61 strings in certificates almost always contain Latin-1 text.
+
+                Ok(element.contents.iter().map(|byte| *byte as char).collect())
+            }
+            TAG_BMP_STRING => {
+                if !element.contents.len().is_multiple_of(2) {
+                    return Err(PdfiumError::CertificateMalformed);
+                }
+
+                String::from_utf16(
+                    element
+                        .contents
+                        .chunks_exact(2)
+                        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                )
+                .map_err(|_| PdfiumError::CertificateMalformed)
+            }
+            _ => Err(PdfiumError::CertificateMalformed),
+        }
+    }
+
+    /// Decodes the given DER UTCTime or GeneralizedTime element into a [DateTime].
+    fn time(element: DerElement) -> Result<DateTime<Utc>, PdfiumError> {
+        // X.509 requires both time formats to be expressed in UTC, with seconds and
+        // without fractional seconds: YYMMDDHHMMSSZ or YYYYMMDDHHMMSSZ.
+
+        let text = std::str::from_utf8(element.contents)
+            .ok()
+            .and_then(|text| text.strip_suffix('Z'))
+            .filter(|text| text.bytes().all(|byte| byte.is_ascii_digit()))
+            .ok_or(PdfiumError::CertificateMalformed)?;
+
+        let field = |range: std::ops::Range<usize>| -> Result<u32, PdfiumError> {
+            text.get(range)
+                .and_then(|value| value.parse().ok())
+                .ok_or(PdfiumError::CertificateMalformed)
+        };
+
+        let (year, rest) = match (element.tag, text.len()) {
+            (TAG_UTC_TIME, 12) => {
+                // Two-digit years from 50 onwards refer to the twentieth century.
+
+                let year = field(0..2)? as i32;
+
+                (if year >= 50 { 1900 + year } else { 2000 + year }, 2)
+            }
+            (TAG_GENERALIZED_TIME, 14) => (field(0..4)? as i32, 4),
+            _ => return Err(PdfiumError::CertificateMalformed),
+        };
+
+        let date_time =
+            NaiveDate::from_ymd_opt(year, field(rest..rest + 2)?, field(rest + 2..rest + 4)?)
+                .and_then(|date| {
+                    date.and_hms_opt(
+                        field(rest + 4..rest + 6).ok()?,
+                        field(rest + 6..rest + 8).ok()?,
+                        field(rest + 8..rest + 10).ok()?,
+                    )
+                })
+                .ok_or(PdfiumError::CertificateMalformed)?;
+
+        Ok(Utc.from_utc_datetime(&date_time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::certificate::PdfCertificate;
+    use crate::error::PdfiumError;
+    use chrono::{TimeZone, Utc};
+
+    // A minimal hand-assembled version 1 certificate containing only the fields read by
+    // PdfCertificate. The signature algorithm and signature fields are empty placeholders.
+
+    const CERTIFICATE: [u8; 80] = [
+        0x30, 0x4E, // Certificate
+        0x30, 0x48, // TBSCertificate
+        0x02, 0x02, 0x00, 0x9A, // serialNumber
+        0x30, 0x00, // signature
+        0x30, 0x0F, 0x31, 0x0D, 0x30, 0x0B, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x04, b'R', b'o',
+        b'o', b't', // issuer
+        0x30, 0x1E, 0x17, 0x0D, b'2', b'4', b'0', b'1', b'0', b'2', b'0', b'3', b'0', b'4', b'0',
+        b'5', b'Z', 0x17, 0x0D, b'3', b'4', b'0', b'1', b'0', b'2', b'0', b'3', b'0', b'4', b'0',
+        b'5', b'Z', // validity
+        0x30, 0x0F, 0x31, 0x0D, 0x30, 0x0B, 0x06, 0x03, 0x55, 0x04, 0x03, 0x13, 0x04, b'L', b'e',
+        b'a', b'f', // subject
+        0x30, 0x00, // signatureAlgorithm
+        0x03, 0x00, // signature
+    ];
+
+    #[test]
+    fn test_certificate_fields() -> Result<(), PdfiumError> {
+        let certificate = PdfCertificate::from_der(CERTIFICATE.to_vec());
+
+        assert_eq!(certificate.serial_number()?, [0x9A]);
+        assert_eq!(certificate.issuer_common_name()?, Some("Root".to_string()));
+        assert_eq!(certificate.subject_common_name()?, Some("Leaf".to_string()));
+        assert_eq!(
+            certificate.not_before()?,
+            Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+        );
+        assert_eq!(
+            certificate.not_after()?,
+            Utc.with_ymd_and_hms(2034, 1, 2, 3, 4, 5).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_certificate() {
+        let certificate = PdfCertificate::from_der(vec![0x30, 0x02, 0x02]);
+
+        assert!(certificate.serial_number().is_err());
+    }
+}
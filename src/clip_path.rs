@@ -1,5 +1,6 @@
-//! Defines the [PdfClipPath] struct, exposing functionality related to a clip path.
-#[doc(hidden)]
+//! Defines the [PdfClipPath] struct, exposing functionality related to a single path
+//! within the clipping region applied to a `PdfPageObject`.
+
 use crate::bindgen::FPDF_CLIPPATH;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
@@ -8,32 +9,38 @@ use crate::path_segments::{PdfPathSegmentIndex, PdfPathSegments, PdfPathSegments
 use std::convert::TryInto;
 use std::os::raw::c_int;
 
-// TODO: AJRC - 22/10/22 - "clip path" is a slight misnomer, since a single clip path can actually
-// contain zero or more path objects. Each path object can then return a PdfClipPathSegments object
-// that implements the PdfPathSegments trait. Want to complete implementation of top-level PdfClipPath
-// collection, then add clip path support to pages and page objects.
-
-#[allow(dead_code)]
-// The PdfClipPath struct is not currently used, but we expect it to be in future
+/// A single path within the clipping region applied to a `PdfPageObject`. Only the parts
+/// of the page object that lie inside every one of its clip paths are painted.
+///
+/// Clip paths are retrieved using the `PdfPageObjectCommon::clip_paths()` function.
+/// Pdfium does not currently expose the fill rule used to determine the inside of
+/// a clip path.
 pub struct PdfClipPath<'a> {
-    // TODO: AJRC - 22/10/22 - this will contain a collection of paths
-    // each of which can return a PdfClipPathSegments object
-    handle: FPDF_CLIPPATH,
-    bindings: &'a dyn PdfiumLibraryBindings,
+    segments: PdfClipPathSegments<'a>,
 }
 
 impl<'a> PdfClipPath<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_CLIPPATH,
+        path_index: c_int,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfClipPath {
+            segments: PdfClipPathSegments::from_pdfium(handle, path_index, bindings),
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfClipPath].
     #[inline]
     pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
-        self.bindings
+        self.segments.bindings
     }
-}
 
-impl<'a> Drop for PdfClipPath<'a> {
-    /// Closes this [PdfClipPath], releasing held memory.
+    /// Returns the collection of [PdfPathSegment] objects that outline this [PdfClipPath].
     #[inline]
-    fn drop(&mut self) {
-        self.bindings.FPDF_DestroyClipPath(self.handle)
+    pub fn segments(&self) -> &PdfClipPathSegments<'a> {
+        &self.segments
     }
 }
 
@@ -46,8 +53,6 @@ pub struct PdfClipPathSegments<'a> {
 
 impl<'a> PdfClipPathSegments<'a> {
     #[inline]
-    #[allow(dead_code)]
-    // The from_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn from_pdfium(
         handle: FPDF_CLIPPATH,
         path_index: c_int,
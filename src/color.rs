@@ -10,7 +10,7 @@ use crate::error::PdfiumError;
 /// Note that when used as a form field highlight color, a solid color with no opacity
 /// will overprint any user data in the field. Use the [PdfColor::with_alpha()] function
 /// to apply an alpha channel value to an existing [PdfColor].
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct PdfColor {
     r: u8,
     g: u8,
@@ -0,0 +1,36 @@
+//! Defines the [PdfDataAvailability] trait, a source of document data that may arrive
+//! incrementally, for use with `Pdfium::load_pdf_from_avail()`.
+
+/// A source of document data that may not yet be fully available, such as a file that is
+/// still being downloaded over the network.
+///
+/// Pdfium asks the source whether a given byte range has arrived before reading from it.
+/// When Pdfium needs data that has not yet arrived, it reports the byte ranges it requires
+/// through [PdfDataAvailability::request_data()]; an implementation can use these hints
+/// to prioritize its downloads.
+///
+/// Progressive loading works best with linearized documents, in which the data needed to
+/// display the first page is placed at the start of the file. A document that is not
+/// linearized generally cannot be opened until all of its data has arrived.
+pub trait PdfDataAvailability {
+    /// Returns the total length of the document in bytes, including any data that has
+    /// not yet arrived.
+    fn content_length(&self) -> u64;
+
+    /// Returns `true` if all `size` bytes of document data starting at the given offset
+    /// have arrived.
+    fn is_data_available(&mut self, offset: u64, size: usize) -> bool;
+
+    /// Copies document data starting at the given offset into the given buffer, filling it
+    /// completely. Returns `true` if the data was copied successfully.
+    ///
+    /// Pdfium only reads data after confirming that it is available.
+    fn read_data(&mut self, offset: u64, buffer: &mut [u8]) -> bool;
+
+    /// Notifies this source that Pdfium needs the `size` bytes of document data starting
+    /// at the given offset in order to make progress.
+    ///
+    /// The default implementation does nothing.
+    #[allow(unused_variables)]
+    fn request_data(&mut self, offset: u64, size: usize) {}
+}
@@ -2,19 +2,24 @@
 //! related to a single PDF file.
 
 use crate::attachments::PdfAttachments;
-use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindgen::{FPDF_AVAIL, FPDF_DOCUMENT, FPDF_DWORD, FPDF_INCREMENTAL};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::bookmarks::PdfBookmarks;
+use crate::destination::PdfDestination;
+use crate::document_search::PdfDocumentSearchIterator;
 use crate::error::PdfiumError;
 use crate::error::PdfiumInternalError;
 use crate::fonts::PdfFonts;
 use crate::form::PdfForm;
+use crate::javascript_actions::PdfJavascriptActions;
 use crate::metadata::PdfMetadata;
-use crate::pages::PdfPages;
+use crate::named_destinations::PdfNamedDestinations;
+use crate::page_text_search::PdfSearchOptions;
+use crate::pages::{PdfPageIndex, PdfPages};
 use crate::permissions::PdfPermissions;
 use crate::signatures::PdfSignatures;
 use crate::utils::files::get_pdfium_file_writer_from_writer;
-use crate::utils::files::FpdfFileAccessExt;
+use crate::utils::files::{FpdfDataAvailabilityExt, FpdfFileAccessExt};
 use std::fmt::{Debug, Formatter};
 use std::io::Cursor;
 use std::io::Write;
@@ -133,7 +138,11 @@ impl PdfDocumentVersion {
 /// * [PdfDocument::fonts()], an immutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::fonts_mut()], a mutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::form()], an immutable reference to the [PdfForm] embedded in the document, if any.
+/// * [PdfDocument::javascript_actions()], an immutable collection of all the document-level
+/// [PdfJavascriptActions] in the document.
 /// * [PdfDocument::metadata()], an immutable collection of all the [PdfMetadata] tags in the document.
+/// * [PdfDocument::named_destinations()], an immutable collection of all the
+/// [PdfNamedDestinations] in the document.
 /// * [PdfDocument::pages()], an immutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::pages_mut()], a mutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::permissions()], settings relating to security handlers and document permissions
@@ -146,16 +155,23 @@ pub struct PdfDocument<'a> {
     bookmarks: PdfBookmarks<'a>,
     form: Option<PdfForm<'a>>,
     fonts: PdfFonts<'a>,
+    javascript_actions: PdfJavascriptActions<'a>,
     metadata: PdfMetadata<'a>,
+    named_destinations: PdfNamedDestinations<'a>,
     pages: PdfPages<'a>,
     permissions: PdfPermissions<'a>,
     signatures: PdfSignatures<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
     source_byte_buffer: Option<Vec<u8>>,
+    linearized_first_page_index: Option<PdfPageIndex>,
 
     #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
     // This field is never used when compiling to WASM.
     file_access_reader: Option<Box<FpdfFileAccessExt<'a>>>,
+
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This field is never used when compiling to WASM.
+    data_availability: Option<(FPDF_AVAIL, Box<FpdfDataAvailabilityExt<'a>>)>,
 }
 
 impl<'a> PdfDocument<'a> {
@@ -166,8 +182,9 @@ impl<'a> PdfDocument<'a> {
     ) -> Self {
         let form = PdfForm::from_pdfium(handle, bindings);
 
-        let pages =
-            PdfPages::from_pdfium(handle, form.as_ref().map(|form| form.handle()), bindings);
+        let form_handle = form.as_ref().map(|form| form.handle());
+
+        let pages = PdfPages::from_pdfium(handle, form_handle, bindings);
 
         PdfDocument {
             handle,
@@ -176,13 +193,17 @@ impl<'a> PdfDocument<'a> {
             bookmarks: PdfBookmarks::from_pdfium(handle, bindings),
             form,
             fonts: PdfFonts::from_pdfium(handle, bindings),
+            javascript_actions: PdfJavascriptActions::from_pdfium(handle, bindings),
             metadata: PdfMetadata::from_pdfium(handle, bindings),
+            named_destinations: PdfNamedDestinations::from_pdfium(handle, bindings),
             pages,
             permissions: PdfPermissions::from_pdfium(handle, bindings),
-            signatures: PdfSignatures::from_pdfium(handle, bindings),
+            signatures: PdfSignatures::from_pdfium(handle, form_handle, bindings),
             bindings,
             source_byte_buffer: None,
+            linearized_first_page_index: None,
             file_access_reader: None,
+            data_availability: None,
         }
     }
 
@@ -205,6 +226,13 @@ impl<'a> PdfDocument<'a> {
         self.source_byte_buffer = Some(bytes);
     }
 
+    /// Records the index of the first page declared in the linearization parameter dictionary
+    /// at the start of the file this [PdfDocument] was loaded from, if the file is linearized.
+    #[inline]
+    pub(crate) fn set_linearized_first_page_index(&mut self, index: Option<PdfPageIndex>) {
+        self.linearized_first_page_index = index;
+    }
+
     /// Binds an `FPDF_FILEACCESS` reader to the lifetime of this [PdfDocument], so that
     /// it will always be available for Pdfium to read data from as needed.
     #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
@@ -214,6 +242,22 @@ impl<'a> PdfDocument<'a> {
         self.file_access_reader = Some(reader);
     }
 
+    /// Binds an `FPDF_AVAIL` availability provider and its data source to the lifetime of
+    /// this [PdfDocument], so that Pdfium can continue to check for and read data as it arrives.
+    /// The availability provider will be destroyed when this [PdfDocument] is closed.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This function is never used when compiling to WASM.
+    pub(crate) fn set_data_availability(
+        &mut self,
+        avail: FPDF_AVAIL,
+        mut data_availability: Box<FpdfDataAvailabilityExt<'a>>,
+    ) {
+        self.pages
+            .set_data_availability(avail, data_availability.as_fx_download_hints_mut_ptr());
+
+        self.data_availability = Some((avail, data_availability));
+    }
+
     /// Returns the file version of this [PdfDocument].
     pub fn version(&self) -> PdfDocumentVersion {
         let mut version = 0;
@@ -226,10 +270,41 @@ impl<'a> PdfDocument<'a> {
     }
 
     /// Sets the file version that will be used the next time this [PdfDocument] is saved.
+    ///
+    /// Pdfium only changes the version number in the file header; it does not downgrade
+    /// document content. See [PdfDocument::save_with_version()] for more information.
     pub fn set_version(&mut self, version: PdfDocumentVersion) {
         self.output_version = Some(version);
     }
 
+    /// Returns `true` if this [PdfDocument] was loaded from a linearized file, also known as
+    /// a "fast web view" or "web optimized" file.
+    ///
+    /// A linearized file places the objects needed to display its first page at the start of
+    /// the file, so a viewer retrieving the file over HTTP range requests can display that page
+    /// before the rest of the file has arrived. A file that was linearized but has since been
+    /// modified by an incremental update is no longer considered linearized, matching Pdfium's
+    /// own behaviour.
+    ///
+    /// Pdfium never writes linearized files, so saving this [PdfDocument] will always produce
+    /// a file that is not linearized. Documents created with `Pdfium::create_new_pdf()` are
+    /// never linearized.
+    #[inline]
+    pub fn is_linearized(&self) -> bool {
+        self.linearized_first_page_index.is_some()
+    }
+
+    /// Returns the index of the page that can be displayed first, before the rest of the file
+    /// has arrived, when this [PdfDocument] is loaded progressively from a linearized file.
+    /// This is usually, but not always, the first page in the document.
+    ///
+    /// Returns `None` if this [PdfDocument] was not loaded from a linearized file, in which case
+    /// the entire file must be available before any page can be displayed.
+    #[inline]
+    pub fn first_page_for_range_loading(&self) -> Option<PdfPageIndex> {
+        self.linearized_first_page_index
+    }
+
     /// Returns an immutable collection of all the [PdfAttachments] embedded in this [PdfDocument].
     #[inline]
     pub fn attachments(&self) -> &PdfAttachments {
@@ -266,12 +341,32 @@ impl<'a> PdfDocument<'a> {
         &mut self.fonts
     }
 
+    /// Returns an immutable collection of all the document-level [PdfJavascriptActions]
+    /// embedded in this [PdfDocument]. The scripts in the collection are never executed.
+    #[inline]
+    pub fn javascript_actions(&self) -> &PdfJavascriptActions {
+        &self.javascript_actions
+    }
+
     /// Returns an immutable collection of all the [PdfMetadata] tags in this [PdfDocument].
     #[inline]
     pub fn metadata(&self) -> &PdfMetadata {
         &self.metadata
     }
 
+    /// Returns an immutable collection of all the [PdfNamedDestinations] in this [PdfDocument].
+    #[inline]
+    pub fn named_destinations(&self) -> &PdfNamedDestinations {
+        &self.named_destinations
+    }
+
+    /// Returns the [PdfDestination] registered under the given name in this [PdfDocument],
+    /// if any.
+    #[inline]
+    pub fn named_destination(&self, name: &str) -> Result<Option<PdfDestination>, PdfiumError> {
+        Ok(self.named_destinations.get_by_name(name))
+    }
+
     /// Returns an immutable collection of all the [PdfPages] in this [PdfDocument].
     #[inline]
     pub fn pages(&self) -> &PdfPages<'a> {
@@ -284,6 +379,17 @@ impl<'a> PdfDocument<'a> {
         &mut self.pages
     }
 
+    /// Searches every page in this [PdfDocument] for the given text string, returning an
+    /// iterator over all results in page order.
+    ///
+    /// Pages are searched lazily as the iterator advances. Only the text of the page currently
+    /// being searched is held in memory at any time. If a page cannot be searched, the iterator
+    /// yields an error for that page and then moves on to the next one.
+    #[inline]
+    pub fn search(&self, text: &str, options: &PdfSearchOptions) -> PdfDocumentSearchIterator {
+        PdfDocumentSearchIterator::new(self.pages(), text, options)
+    }
+
     /// Returns an immutable collection of all the [PdfPermissions] applied to this [PdfDocument].
     #[inline]
     pub fn permissions(&self) -> &PdfPermissions {
@@ -297,17 +403,65 @@ impl<'a> PdfDocument<'a> {
     }
 
     /// Writes this [PdfDocument] to the given writer.
-    pub fn save_to_writer<W: Write + 'static>(&self, writer: &mut W) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
-        // and FPDF_REMOVE_SECURITY flags defined in fpdf_save.h. There's not a lot of information
-        // on what they actually do, however.
+    ///
+    /// Document data is passed to the writer in blocks as Pdfium generates it, so the
+    /// writer can stream the document to its destination without the entire file being
+    /// buffered in memory.
+    ///
+    /// If `incremental` is `true`, Pdfium will perform an incremental save: the original
+    /// document data is written unchanged, followed by an update section containing only
+    /// the objects that were changed. This preserves any existing digital signatures in the
+    /// document, since the signed byte ranges are not rewritten. Incremental saving is
+    /// only meaningful for documents loaded from existing PDF data.
+    ///
+    /// If `incremental` is `false`, Pdfium writes a complete fresh copy of the document.
+    pub fn save_to_writer<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        incremental: bool,
+    ) -> Result<(), PdfiumError> {
+        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_REMOVE_SECURITY flag defined
+        // in fpdf_save.h. There's not a lot of information on what it actually does, however.
         // Some small info at https://forum.patagames.com/posts/t155-PDF-SaveFlags.
 
-        let flags = 0;
+        let flags = if incremental {
+            FPDF_INCREMENTAL as FPDF_DWORD
+        } else {
+            0
+        };
+
+        self.save_to_writer_with_flags_and_version(writer, flags, self.output_version)
+    }
 
+    /// Writes this [PdfDocument] to the given writer, setting the file version in the
+    /// written document to the given [PdfDocumentVersion]. This overrides any version
+    /// previously set using [PdfDocument::set_version()].
+    ///
+    /// Pdfium only changes the version number in the file header; it does not downgrade
+    /// document content to match the requested version. Requesting a lower version than that
+    /// required by the features used in the document - for instance, requesting PDF 1.3 for
+    /// a document that uses transparency, which was introduced in PDF 1.4 - may silently
+    /// produce a non-conformant file.
+    pub fn save_with_version<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        version: PdfDocumentVersion,
+    ) -> Result<(), PdfiumError> {
+        self.save_to_writer_with_flags_and_version(writer, 0, Some(version))
+    }
+
+    /// Writes this [PdfDocument] to the given writer using the given Pdfium save flags.
+    /// If a [PdfDocumentVersion] is given, the file version in the written document will be
+    /// set to that version.
+    fn save_to_writer_with_flags_and_version<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        flags: FPDF_DWORD,
+        version: Option<PdfDocumentVersion>,
+    ) -> Result<(), PdfiumError> {
         let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(writer);
 
-        let result = match self.output_version {
+        let result = match version {
             Some(version) => self.bindings.FPDF_SaveWithVersion(
                 self.handle,
                 pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
@@ -349,14 +503,17 @@ impl<'a> PdfDocument<'a> {
     /// Javascript `Blob` object. This function is only available when compiling to WASM.
     #[cfg(not(target_arch = "wasm32"))]
     pub fn save_to_file(&self, path: &(impl AsRef<Path> + ?Sized)) -> Result<(), PdfiumError> {
-        self.save_to_writer(&mut File::create(path).map_err(PdfiumError::IoError)?)
+        self.save_to_writer(
+            &mut File::create(path).map_err(PdfiumError::IoError)?,
+            false,
+        )
     }
 
     /// Writes this [PdfDocument] to a new byte buffer, returning the byte buffer.
     pub fn save_to_bytes(&self) -> Result<Vec<u8>, PdfiumError> {
         let mut cursor = Cursor::new(Vec::new());
 
-        self.save_to_writer(&mut cursor)?;
+        self.save_to_writer(&mut cursor, false)?;
 
         Ok(cursor.into_inner())
     }
@@ -380,6 +537,91 @@ impl<'a> PdfDocument<'a> {
     }
 }
 
+/// Returns the first page index declared in the linearization parameter dictionary at the start
+/// of the given file header, or `None` if the file is not linearized.
+///
+/// Following Pdfium, the dictionary must be the first object in the file, must appear within
+/// the first 1024 bytes of the file, and must declare a file length matching the given actual
+/// length of the file; a linearized file that has since been incrementally updated is therefore
+/// not considered linearized.
+pub(crate) fn find_linearized_first_page_index(
+    header: &[u8],
+    file_length: u64,
+) -> Option<PdfPageIndex> {
+    const HEADER_LENGTH: usize = 1024;
+
+    let header = &header[..header.len().min(HEADER_LENGTH)];
+
+    let object = find_subslice(header, b"obj")? + 3;
+
+    let dictionary_start = object
+        + header[object..]
+            .iter()
+            .position(|byte| !byte.is_ascii_whitespace())?;
+
+    if !header[dictionary_start..].starts_with(b"<<") {
+        return None;
+    }
+
+    let dictionary_end = dictionary_start + find_subslice(&header[dictionary_start..], b">>")?;
+
+    let dictionary = &header[dictionary_start + 2..dictionary_end];
+
+    find_pdf_name(dictionary, b"Linearized")?;
+
+    if find_pdf_integer(dictionary, b"L")? != file_length {
+        return None;
+    }
+
+    let page_count = find_pdf_integer(dictionary, b"N")?;
+
+    let first_page_index = find_pdf_integer(dictionary, b"P").unwrap_or(0);
+
+    if first_page_index < page_count && first_page_index <= PdfPageIndex::MAX as u64 {
+        Some(first_page_index as PdfPageIndex)
+    } else {
+        None
+    }
+}
+
+/// Returns the position immediately following the given name in the given dictionary body,
+/// if the name is present as a complete name rather than as the prefix of a longer name.
+fn find_pdf_name(dictionary: &[u8], name: &[u8]) -> Option<usize> {
+    (0..dictionary.len())
+        .filter(|index| dictionary[*index] == b'/')
+        .map(|index| index + 1)
+        .find(|start| {
+            dictionary[*start..].starts_with(name)
+                && dictionary
+                    .get(start + name.len())
+                    .map(|byte| !byte.is_ascii_alphanumeric())
+                    .unwrap_or(true)
+        })
+        .map(|start| start + name.len())
+}
+
+/// Returns the non-negative integer value of the given name in the given dictionary body, if any.
+fn find_pdf_integer(dictionary: &[u8], name: &[u8]) -> Option<u64> {
+    let value = &dictionary[find_pdf_name(dictionary, name)?..];
+
+    let value = &value[value.iter().position(|byte| !byte.is_ascii_whitespace())?..];
+
+    let digits = value
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(value.len());
+
+    std::str::from_utf8(&value[..digits]).ok()?.parse().ok()
+}
+
+/// Returns the position of the first occurrence of the given needle in the given haystack, if any.
+#[inline]
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
 impl<'a> Drop for PdfDocument<'a> {
     /// Closes this [PdfDocument], releasing held memory and, if the document was loaded
     /// from a file, the file handle on the document.
@@ -391,6 +633,16 @@ impl<'a> Drop for PdfDocument<'a> {
 
         self.form = None;
         self.bindings.FPDF_CloseDocument(self.handle);
+
+        // A document loaded progressively continues to read through its availability provider,
+        // so the provider can only be destroyed once the document itself is closed.
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some((avail, _)) = self.data_availability.as_ref() {
+                self.bindings.FPDFAvail_Destroy(*avail);
+            }
+        }
     }
 }
 
@@ -408,3 +660,343 @@ unsafe impl<'a> Sync for PdfDocument<'a> {}
 
 #[cfg(feature = "sync")]
 unsafe impl<'a> Send for PdfDocument<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::find_linearized_first_page_index;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::{Cell, RefCell};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_incremental_save_preserves_original_bytes() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let original = std::fs::read("./test/form-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let mut document = pdfium.load_pdf_from_byte_slice(&original, None)?;
+
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let mut cursor = Cursor::new(Vec::new());
+
+        document.save_to_writer(&mut cursor, true)?;
+
+        let saved = cursor.into_inner();
+
+        // An incremental save appends an update section to the original document data.
+
+        assert!(saved.len() > original.len());
+        assert_eq!(&saved[..original.len()], original.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_with_version() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let mut cursor = Cursor::new(Vec::new());
+
+        document.save_with_version(&mut cursor, PdfDocumentVersion::Pdf1_4)?;
+
+        assert!(cursor.into_inner().starts_with(b"%PDF-1.4"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_destinations() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert!(document.named_destinations().is_empty());
+        assert_eq!(document.named_destinations().iter().count(), 0);
+        assert!(document.named_destination("missing")?.is_none());
+
+        // The value registered under the name "Broken" is an integer rather than a
+        // destination, so it should be skipped by the iterator.
+
+        let document = pdfium.load_pdf_from_file("./test/named-destinations-test.pdf", None)?;
+
+        let destinations = document.named_destinations();
+
+        assert_eq!(destinations.len(), 3);
+        assert!(destinations.get(1).is_err());
+
+        let names = destinations
+            .iter()
+            .map(|destination| {
+                (
+                    destination.name().to_owned(),
+                    destination.destination().page_index().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            vec![("Alpha".to_owned(), 0), ("Gamma".to_owned(), 1)]
+        );
+
+        assert_eq!(
+            document.named_destination("Gamma")?.unwrap().page_index()?,
+            1
+        );
+        assert!(document.named_destination("Broken")?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_javascript_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert!(document.javascript_actions().is_empty());
+        assert_eq!(document.javascript_actions().iter().count(), 0);
+
+        // The form test document carries document-level Javascript.
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let actions = document.javascript_actions();
+
+        assert!(!actions.is_empty());
+        assert_eq!(actions.iter().count(), actions.len() as usize);
+
+        for action in actions.iter() {
+            assert!(!action.script().is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_linearized_first_page_index() {
+        let header =
+            b"%PDF-1.7\n1 0 obj\n<</Linearized 1/L 5000/H [ 700 120]/N 3/T 4800>>\nendobj\n";
+
+        assert_eq!(find_linearized_first_page_index(header, 5000), Some(0));
+
+        // An incrementally updated file is longer than its declared length.
+
+        assert_eq!(find_linearized_first_page_index(header, 6000), None);
+
+        let header =
+            b"%PDF-1.7\n1 0 obj\n<< /Linearized 1.0 /L 5000 /N 3 /P 2 /T 4800 >>\nendobj\n";
+
+        assert_eq!(find_linearized_first_page_index(header, 5000), Some(2));
+
+        let header = b"%PDF-1.7\n1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n";
+
+        assert_eq!(find_linearized_first_page_index(header, 5000), None);
+    }
+
+    #[test]
+    fn test_is_linearized() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/linearized-test.pdf", None)?;
+
+        assert!(document.is_linearized());
+        assert_eq!(document.first_page_for_range_loading(), Some(0));
+
+        drop(document);
+
+        let bytes = std::fs::read("./test/linearized-test.pdf").unwrap();
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        assert!(document.is_linearized());
+
+        drop(document);
+
+        // This file was linearized, but has since been incrementally updated.
+
+        let document = pdfium.load_pdf_from_file("./test/signatures-test.pdf", None)?;
+
+        assert!(!document.is_linearized());
+        assert_eq!(document.first_page_for_range_loading(), None);
+
+        drop(document);
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        assert!(!document.is_linearized());
+
+        drop(document);
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert!(!document.is_linearized());
+
+        Ok(())
+    }
+
+    struct TestDataAvailability<'a> {
+        bytes: &'a [u8],
+        available: &'a Cell<usize>,
+        requested: &'a RefCell<Vec<(u64, usize)>>,
+    }
+
+    impl<'a> PdfDataAvailability for TestDataAvailability<'a> {
+        fn content_length(&self) -> u64 {
+            self.bytes.len() as u64
+        }
+
+        fn is_data_available(&mut self, offset: u64, size: usize) -> bool {
+            offset as usize + size <= self.available.get()
+        }
+
+        fn read_data(&mut self, offset: u64, buffer: &mut [u8]) -> bool {
+            let start = offset as usize;
+
+            let end = start + buffer.len();
+
+            if end <= self.available.get() {
+                buffer.copy_from_slice(&self.bytes[start..end]);
+
+                true
+            } else {
+                false
+            }
+        }
+
+        fn request_data(&mut self, offset: u64, size: usize) {
+            self.requested.borrow_mut().push((offset, size));
+        }
+    }
+
+    #[test]
+    fn test_load_pdf_from_avail() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+
+            document.save_to_bytes()?
+        };
+
+        let available = Cell::new(0);
+
+        let requested = RefCell::new(Vec::new());
+
+        // Nothing has arrived yet, so the document cannot be opened, and Pdfium should
+        // ask for the data it needs.
+
+        let pending = match pdfium.load_pdf_from_avail(
+            TestDataAvailability {
+                bytes: bytes.as_slice(),
+                available: &available,
+                requested: &requested,
+            },
+            None,
+        )? {
+            PdfProgressiveDocument::Pending(pending) => pending,
+            PdfProgressiveDocument::Loaded(_) => panic!("document should not be available"),
+        };
+
+        assert!(!requested.borrow().is_empty());
+
+        // A document that is not linearized cannot be opened until all its data has arrived.
+
+        available.set(bytes.len() / 2);
+
+        let pending = match pending.poll()? {
+            PdfProgressiveDocument::Pending(pending) => pending,
+            PdfProgressiveDocument::Loaded(_) => panic!("document should not be available"),
+        };
+
+        available.set(bytes.len());
+
+        let document = match pending.poll()? {
+            PdfProgressiveDocument::Loaded(document) => document,
+            PdfProgressiveDocument::Pending(_) => panic!("document should be available"),
+        };
+
+        assert!(!document.is_linearized());
+        assert_eq!(document.pages().len(), 2);
+        assert!(document.pages().get(0).is_ok());
+        assert!(document.pages().get(1).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_across_pages() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        for content in ["Hello world", "Nothing to see", "hello there, helloworld"].iter() {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(700.0),
+                content,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let results = document
+            .search("hello", &PdfSearchOptions::new())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.page_index())
+                .collect::<Vec<_>>(),
+            vec![0, 2, 2]
+        );
+        assert_eq!(results[0].text(), "Hello");
+        assert_eq!(results[0].char_count(), 5);
+        assert!(!results[0].bounds().is_empty());
+
+        let case_sensitive = document
+            .search("hello", &PdfSearchOptions::new().match_case(true))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(case_sensitive.len(), 2);
+        assert!(case_sensitive.iter().all(|result| result.page_index() == 2));
+
+        let whole_word = document
+            .search("hello", &PdfSearchOptions::new().match_whole_word(true))
+            .map(|result| result.map(|result| result.page_index()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(whole_word, vec![0, 2]);
+
+        assert_eq!(
+            document.search("missing", &PdfSearchOptions::new()).count(),
+            0
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+//! Defines the [PdfDocumentSearchIterator] struct, yielding the results of searching for
+//! a given string across every page in a single `PdfDocument`.
+
+use crate::error::PdfiumError;
+use crate::page_text_char::PdfPageTextChar;
+use crate::page_text_chars::PdfPageTextCharIndex;
+use crate::page_text_search::{PdfSearchDirection, PdfSearchOptions};
+use crate::pages::{PdfPageIndex, PdfPages};
+use crate::rect::PdfRect;
+use std::collections::VecDeque;
+
+#[cfg(doc)]
+use crate::document::PdfDocument;
+
+#[cfg(doc)]
+use crate::page_text_segment::PdfPageTextSegment;
+
+/// A single result of searching for a given string across every page in a [PdfDocument].
+///
+/// Unlike the results of a page-level `PdfPageTextSearch`, a [PdfDocumentSearchResult]
+/// holds copies of all its values, so it remains valid after the text of the page on which
+/// it was found has been released.
+#[derive(Debug, Clone)]
+pub struct PdfDocumentSearchResult {
+    page_index: PdfPageIndex,
+    char_index: PdfPageTextCharIndex,
+    char_count: PdfPageTextCharIndex,
+    text: String,
+    bounds: Vec<PdfRect>,
+}
+
+impl PdfDocumentSearchResult {
+    /// Returns the index of the page on which this [PdfDocumentSearchResult] was found.
+    #[inline]
+    pub fn page_index(&self) -> PdfPageIndex {
+        self.page_index
+    }
+
+    /// Returns the index of the first matching character in the text of the page
+    /// on which this [PdfDocumentSearchResult] was found.
+    #[inline]
+    pub fn char_index(&self) -> PdfPageTextCharIndex {
+        self.char_index
+    }
+
+    /// Returns the number of matching characters in this [PdfDocumentSearchResult].
+    #[inline]
+    pub fn char_count(&self) -> PdfPageTextCharIndex {
+        self.char_count
+    }
+
+    /// Returns the matching text, as it appears on the page.
+    ///
+    /// This may differ in case from the search target if the search was not case-sensitive.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding boxes of the [PdfPageTextSegment] text segments spanned by this
+    /// [PdfDocumentSearchResult]. A match that wraps across lines spans several segments.
+    #[inline]
+    pub fn bounds(&self) -> &[PdfRect] {
+        self.bounds.as_slice()
+    }
+}
+
+/// An iterator over the results of searching for a given string across every page in
+/// a [PdfDocument], in page order.
+///
+/// Pages are searched lazily. The text of each page is loaded only once the iterator reaches
+/// that page, and is released again as soon as all results on the page have been collected.
+///
+/// If a page or its text cannot be loaded, the iterator yields the error in place of that
+/// page's results, then continues searching from the following page.
+pub struct PdfDocumentSearchIterator<'a> {
+    pages: &'a PdfPages<'a>,
+    target: String,
+    options: PdfSearchOptions,
+    next_page_index: PdfPageIndex,
+    results: VecDeque<PdfDocumentSearchResult>,
+}
+
+impl<'a> PdfDocumentSearchIterator<'a> {
+    pub(crate) fn new(pages: &'a PdfPages<'a>, target: &str, options: &PdfSearchOptions) -> Self {
+        PdfDocumentSearchIterator {
+            pages,
+            target: target.to_owned(),
+            options: *options,
+            next_page_index: 0,
+            results: VecDeque::new(),
+        }
+    }
+
+    /// Searches the page at the given index, appending all results on the page
+    /// to this iterator's queue of pending results.
+    fn search_page(&mut self, page_index: PdfPageIndex) -> Result<(), PdfiumError> {
+        let page = self.pages.get(page_index)?;
+
+        let text = page.text()?;
+
+        let search = text.search(self.target.as_str(), &self.options);
+
+        for segments in search.iter(PdfSearchDirection::SearchForward) {
+            let range = segments.char_range();
+
+            let matched = range
+                .clone()
+                .filter_map(|index| {
+                    PdfPageTextChar::from_pdfium(*text.handle(), index as i32, text.bindings())
+                        .unicode_char()
+                })
+                .collect();
+
+            self.results.push_back(PdfDocumentSearchResult {
+                page_index,
+                char_index: range.start,
+                char_count: range.len(),
+                text: matched,
+                bounds: segments.iter().map(|segment| segment.bounds()).collect(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for PdfDocumentSearchIterator<'a> {
+    type Item = Result<PdfDocumentSearchResult, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.results.is_empty() && self.next_page_index < self.pages.len() {
+            let page_index = self.next_page_index;
+
+            self.next_page_index += 1;
+
+            if let Err(err) = self.search_page(page_index) {
+                return Some(Err(err));
+            }
+        }
+
+        self.results.pop_front().map(Ok)
+    }
+}
@@ -4,6 +4,7 @@ use crate::bindgen::{
     FPDF_ERR_FILE, FPDF_ERR_FORMAT, FPDF_ERR_PAGE, FPDF_ERR_PASSWORD, FPDF_ERR_SECURITY,
     FPDF_ERR_UNKNOWN,
 };
+use crate::pages::PdfPageIndex;
 use std::error::Error;
 use std::ffi::IntoStringError;
 use std::fmt::{Display, Formatter, Result};
@@ -87,8 +88,27 @@ pub enum PdfiumError {
     UnknownPdfColorSpace,
     InvalidTransformationMatrix,
     SignatureIndexOutOfBounds,
+
+    /// No `PdfSignature` matching the given search criteria could be found.
+    SignatureNotFound,
+
+    /// The `/ByteRange` of a `PdfSignature` is missing, malformed, or refers to data
+    /// outside the bounds of the document data being verified.
+    SignatureByteRangeInvalid,
+
+    /// The contents of a `PdfSignature` could not be parsed as DER-encoded data.
+    SignatureContentsMalformed,
+
+    /// The digest algorithm used by a `PdfSignature` is not supported by `pdfium-render`.
+    SignatureDigestAlgorithmNotSupported,
+
+    /// A `PdfCertificate` could not be parsed as a DER-encoded X.509 certificate.
+    CertificateMalformed,
+
     AttachmentIndexOutOfBounds,
     NoDataInAttachment,
+    JavascriptActionIndexOutOfBounds,
+    NamedDestinationIndexOutOfBounds,
     FontGlyphIndexOutOfBounds,
     UnknownPathSegmentType,
     NoPagesInDocument,
@@ -102,10 +122,68 @@ pub enum PdfiumError {
     GroupContainsNonCopyablePageObjects,
     SourcePageIndexNotInCache,
     NoUriForAction,
+    NoFilePathForAction,
     DestinationPageIndexNotAvailable,
     DestinationPageLocationNotAvailable,
     PageAnnotationAttachmentPointIndexOutOfBounds,
     NoAttachmentPointsInPageAnnotation,
+    PageAnnotationAttachmentPointsNotSupported,
+    PageAnnotationAttachmentPointAppendFailed,
+    NoRectsForAttachmentPoints,
+    PageAnnotationAttachmentPointsCannotShrink,
+    AnnotationOpacityIsNaN,
+    NoPointsInInkStroke,
+
+    /// The annotation passed to `PdfPageAnnotations::copy_annotation_from()` is of a type
+    /// Pdfium cannot create, or carries page objects that cannot be copied to the
+    /// destination document.
+    PageAnnotationNotCopyable,
+
+    /// Pdfium will not change the interior color of an annotation that has an appearance
+    /// stream. Remove the appearance stream using
+    /// `PdfPageAnnotationCommon::set_appearance_stream()` first.
+    PageAnnotationHasAppearanceStream,
+
+    /// An empty string was given to `PdfPageAnnotationCommon::set_name()`. Annotation
+    /// names are used to identify annotations, so they must not be empty.
+    EmptyAnnotationName,
+
+    /// The caller-supplied callback passed to `PdfPage::render_progressive()` requested
+    /// that rendering be abandoned before it completed.
+    RenderCancelled,
+
+    /// The data needed to load a `PdfPage` from a progressively loaded `PdfDocument`
+    /// has not yet arrived from the document's `PdfDataAvailability` source.
+    PageDataNotYetAvailable,
+
+    /// The effective resolution of a `PdfPageImageObject` cannot be calculated because
+    /// the object's transformation matrix collapses the image to zero width or height.
+    ImageObjectHasZeroPlacedSize,
+
+    /// The `PdfPage` passed to `PdfPage::structure_tree()` or `PdfPageText::structured_text()`
+    /// has no tagged structure tree, usually because the containing `PdfDocument` is not
+    /// a tagged PDF.
+    NoStructureTree,
+
+    /// The index passed to `PdfStructTree::get()` or `PdfStructElement::get()` is out of bounds,
+    /// or refers to a marked content or object reference rather than a structure element.
+    StructElementIndexOutOfBounds,
+
+    /// The index passed to `PdfPageObjectMarks::get()` is out of bounds.
+    PageObjectMarkIndexOutOfBounds,
+
+    /// Rendering the page at the given index failed during a call to `PdfPages::render_range()`.
+    /// The wrapped error describes the cause of the failure.
+    PageRenderingFailed(PdfPageIndex, Box<PdfiumError>),
+
+    /// Pdfium was unable to render a `PdfPageImageObject` to a bitmap, for instance because
+    /// the image data could not be decoded.
+    ImageObjectRenderingFailed,
+
+    /// The mask image passed to `PdfPageImageObject::set_mask_image()` does not have the same
+    /// pixel dimensions as the image it masks.
+    ImageMaskSizeMismatch,
+
     CoordinateConversionFunctionIndicatedError,
 
     /// A call to `FPDFDest_GetView()` returned a valid FPDFDEST_VIEW_* value, but the number
@@ -120,6 +198,11 @@ pub enum PdfiumError {
     /// characters long.
     ParseHexadecimalColorUnexpectedLength,
 
+    /// A PDF date string, such as the modification date of a `PdfPageAnnotation`, could not be
+    /// parsed because it does not follow the `D:YYYYMMDDHHmmSSOHH'mm'` format defined in the
+    /// PDF specification.
+    ParsePdfDateStringError,
+
     /// The leading `#` character was not found while attempting to parse a `PdfColor` from
     /// a hexidecimal string in `PdfColor::from_hex()`.
     ParseHexadecimalColorMissingLeadingHash,
@@ -74,6 +74,27 @@ impl PdfFontBuiltin {
             PdfFontBuiltin::ZapfDingbats => "ZapfDingbats",
         }
     }
+
+    /// Returns the built-in PDF font with the given PostScript name, if any.
+    pub(crate) fn from_pdf_font_name(name: &str) -> Option<Self> {
+        match name {
+            "Times-Roman" => Some(PdfFontBuiltin::TimesRoman),
+            "Times-Bold" => Some(PdfFontBuiltin::TimesBold),
+            "Times-Italic" => Some(PdfFontBuiltin::TimesItalic),
+            "Times-BoldItalic" => Some(PdfFontBuiltin::TimesBoldItalic),
+            "Helvetica" => Some(PdfFontBuiltin::Helvetica),
+            "Helvetica-Bold" => Some(PdfFontBuiltin::HelveticaBold),
+            "Helvetica-Oblique" => Some(PdfFontBuiltin::HelveticaOblique),
+            "Helvetica-BoldOblique" => Some(PdfFontBuiltin::HelveticaBoldOblique),
+            "Courier" => Some(PdfFontBuiltin::Courier),
+            "Courier-Bold" => Some(PdfFontBuiltin::CourierBold),
+            "Courier-Oblique" => Some(PdfFontBuiltin::CourierOblique),
+            "Courier-BoldOblique" => Some(PdfFontBuiltin::CourierBoldOblique),
+            "Symbol" => Some(PdfFontBuiltin::Symbol),
+            "ZapfDingbats" => Some(PdfFontBuiltin::ZapfDingbats),
+            _ => None,
+        }
+    }
 }
 
 /// A reusable token referencing a [PdfFont] previously added to the [PdfFonts] collection
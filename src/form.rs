@@ -9,6 +9,7 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::form_field::PdfFormFieldCommon;
 use crate::form_field::PdfFormFieldType;
+use crate::form_fields::PdfFormFields;
 use crate::pages::PdfPages;
 use std::collections::HashMap;
 use std::ops::DerefMut;
@@ -69,8 +70,9 @@ impl PdfFormType {
 /// }
 /// ```
 ///
-/// Alternatively, use the [PdfForm::field_values()] function to eagerly retrieve the values of all
-/// fields in the document as a map of (field name, field value) pairs.
+/// Alternatively, use the [PdfForm::fields()] function to gather every form field in the document
+/// into a single collection, or the [PdfForm::field_values()] function to eagerly retrieve the
+/// values of all fields in the document as a map of (field name, field value) pairs.
 pub struct PdfForm<'a> {
     form_handle: FPDF_FORMHANDLE,
     document_handle: FPDF_DOCUMENT,
@@ -190,6 +192,16 @@ impl<'a> PdfForm<'a> {
             .unwrap()
     }
 
+    /// Returns a [PdfFormFields] collection containing every form field on every page of
+    /// the given [PdfPages] collection.
+    ///
+    /// Each form field exposes its name, its type, and a string representation of its value,
+    /// as well as the field type-specific properties available by unwrapping the field.
+    #[inline]
+    pub fn fields(&self, pages: &PdfPages<'a>) -> PdfFormFields<'a> {
+        PdfFormFields::from_pages(pages)
+    }
+
     /// Captures a string representation of the value of every form field on every page of
     /// the given [PdfPages] collection, returning a map of (field name, field value) pairs.
     ///
@@ -198,8 +210,6 @@ impl<'a> PdfForm<'a> {
     pub fn field_values(&self, pages: &'a PdfPages<'a>) -> HashMap<String, Option<String>> {
         let mut result = HashMap::new();
 
-        let field_value_true = Some("true".to_string());
-
         let field_value_false = Some("false".to_string());
 
         for page in pages.iter() {
@@ -207,35 +217,7 @@ impl<'a> PdfForm<'a> {
                 if let Some(field) = annotation.as_form_field() {
                     let field_type = field.field_type();
 
-                    let field_value = match field_type {
-                        PdfFormFieldType::Checkbox => {
-                            if field
-                                .as_checkbox_field()
-                                .unwrap()
-                                .is_checked()
-                                .unwrap_or(false)
-                            {
-                                field_value_true.clone()
-                            } else {
-                                field_value_false.clone()
-                            }
-                        }
-                        PdfFormFieldType::ComboBox => field.as_combo_box_field().unwrap().value(),
-                        PdfFormFieldType::ListBox => field.as_list_box_field().unwrap().value(),
-                        PdfFormFieldType::RadioButton => {
-                            let field = field.as_radio_button_field().unwrap();
-
-                            if field.is_checked().unwrap_or(false) {
-                                field.group_value()
-                            } else {
-                                field_value_false.clone()
-                            }
-                        }
-                        PdfFormFieldType::Text => field.as_text_field().unwrap().value(),
-                        PdfFormFieldType::PushButton
-                        | PdfFormFieldType::Signature
-                        | PdfFormFieldType::Unknown => None,
-                    };
+                    let field_value = field.value();
 
                     // A group of checkbox or radio button controls all share the same name, so
                     // as we iterate over the controls, the value of the group will be updated.
@@ -276,3 +258,64 @@ impl<'a> Drop for PdfForm<'a> {
             .FPDFDOC_ExitFormFillEnvironment(self.form_handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_fields() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-fields-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let fields = form.fields(document.pages());
+
+        assert_eq!(fields.len(), 4);
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.field_type())
+                .filter(|field_type| *field_type == PdfFormFieldType::RadioButton)
+                .count(),
+            2
+        );
+
+        let text = fields.find("person.name").unwrap();
+
+        assert_eq!(text.field_type(), PdfFormFieldType::Text);
+        assert_eq!(text.value(), Some("Alice".to_string()));
+        assert!(text.is_read_only());
+
+        let checkbox = fields
+            .iter()
+            .find(|field| field.field_type() == PdfFormFieldType::Checkbox)
+            .unwrap();
+
+        assert_eq!(checkbox.value(), Some("true".to_string()));
+        assert!(!checkbox.is_read_only());
+
+        // Only the second radio button in the "choice" group is selected.
+
+        assert_eq!(
+            fields
+                .iter()
+                .filter(|field| field.name() == Some("choice".to_string()))
+                .map(|field| field.value())
+                .collect::<Vec<_>>(),
+            vec![Some("false".to_string()), Some("B".to_string())]
+        );
+
+        // The values reported by each field should agree with field_values().
+
+        let values = form.field_values(document.pages());
+
+        assert_eq!(values.get("person.name"), Some(&text.value()));
+        assert_eq!(values.get("choice"), Some(&Some("B".to_string())));
+
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@ use crate::appearance_mode::PdfAppearanceMode;
 use crate::bindgen::{
     FPDF_ANNOTATION, FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX,
     FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE,
-    FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMHANDLE,
+    FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMFLAG_CHOICE_COMBO,
+    FPDF_FORMFLAG_CHOICE_EDIT, FPDF_FORMFLAG_CHOICE_MULTI_SELECT, FPDF_FORMFLAG_NOEXPORT,
+    FPDF_FORMFLAG_READONLY, FPDF_FORMFLAG_REQUIRED, FPDF_FORMFLAG_TEXT_MULTILINE,
+    FPDF_FORMFLAG_TEXT_PASSWORD, FPDF_FORMHANDLE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
@@ -17,8 +20,41 @@ use crate::form_field_radio::PdfFormRadioButtonField;
 use crate::form_field_signature::PdfFormSignatureField;
 use crate::form_field_text::PdfFormTextField;
 use crate::form_field_unknown::PdfFormUnknownField;
+use bitflags::bitflags;
 use std::os::raw::c_int;
 
+bitflags! {
+    /// The flags set on a single [PdfFormField], as defined by the /Ff entry in
+    /// table 8.70 of the PDF Reference, version 1.7, on page 676, and in the
+    /// field type-specific tables that follow it.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct PdfFormFieldFlags: u32 {
+        /// The user may not change the value of the field.
+        const ReadOnly = FPDF_FORMFLAG_READONLY;
+
+        /// The field must have a value when the form is submitted.
+        const Required = FPDF_FORMFLAG_REQUIRED;
+
+        /// The field must not be exported when the form is submitted.
+        const NoExport = FPDF_FORMFLAG_NOEXPORT;
+
+        /// The text field may contain multiple lines of text.
+        const TextMultiline = FPDF_FORMFLAG_TEXT_MULTILINE;
+
+        /// The text field is intended for entering a secure password.
+        const TextPassword = FPDF_FORMFLAG_TEXT_PASSWORD;
+
+        /// The choice field is a combo box rather than a list box.
+        const ChoiceCombo = FPDF_FORMFLAG_CHOICE_COMBO;
+
+        /// The combo box includes an editable text box as well as a drop-down list.
+        const ChoiceEdit = FPDF_FORMFLAG_CHOICE_EDIT;
+
+        /// More than one of the choice field's options may be selected simultaneously.
+        const ChoiceMultiSelect = FPDF_FORMFLAG_CHOICE_MULTI_SELECT;
+    }
+}
+
 /// The widget display type of a single interactive form field in a `PdfForm`.
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 pub enum PdfFormFieldType {
@@ -158,6 +194,38 @@ impl<'a> PdfFormField<'a> {
         }
     }
 
+    /// Returns a string representation of the value of this [PdfFormField], if any.
+    ///
+    /// * Text, combo box, and list box fields return their current value.
+    /// * Checkbox fields return `true` if checked or `false` if unchecked.
+    /// * Radio button fields return the value of their control group if checked
+    ///   or `false` if unchecked.
+    /// * Push button, signature, and unknown fields have no value and always return `None`.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            PdfFormField::Checkbox(field) => {
+                if field.is_checked().unwrap_or(false) {
+                    Some("true".to_string())
+                } else {
+                    Some("false".to_string())
+                }
+            }
+            PdfFormField::ComboBox(field) => field.value(),
+            PdfFormField::ListBox(field) => field.value(),
+            PdfFormField::RadioButton(field) => {
+                if field.is_checked().unwrap_or(false) {
+                    field.group_value()
+                } else {
+                    Some("false".to_string())
+                }
+            }
+            PdfFormField::Text(field) => field.value(),
+            PdfFormField::PushButton(_) | PdfFormField::Signature(_) | PdfFormField::Unknown(_) => {
+                None
+            }
+        }
+    }
+
     /// Returns the underlying [PdfFormPushButtonField] for this [PdfFormField],
     /// if this form field has a field type of [PdfFormField::PushButton].
     #[inline]
@@ -241,9 +309,21 @@ impl<'a> PdfFormField<'a> {
 
 /// Functionality common to all [PdfFormField] objects, regardless of their [PdfFormFieldType].
 pub trait PdfFormFieldCommon {
-    /// Returns the name of this [PdfFormField], if any.
+    /// Returns the fully qualified name of this [PdfFormField], if any.
+    ///
+    /// The fully qualified name joins the partial names of the field and all its
+    /// ancestors with periods, for example `person.name`.
     fn name(&self) -> Option<String>;
 
+    /// Returns the [PdfFormFieldFlags] currently set for this [PdfFormField].
+    fn field_flags(&self) -> PdfFormFieldFlags;
+
+    /// Returns `true` if the user may not change the value of this [PdfFormField].
+    fn is_read_only(&self) -> bool;
+
+    /// Returns `true` if this [PdfFormField] must have a value when the form is submitted.
+    fn is_required(&self) -> bool;
+
     /// Returns the name of the currently set appearance stream for this [PdfFormField], if any.
     fn appearance_stream(&self) -> Option<String>;
 
@@ -263,6 +343,23 @@ where
         self.name_impl()
     }
 
+    #[inline]
+    fn field_flags(&self) -> PdfFormFieldFlags {
+        self.field_flags_impl()
+    }
+
+    #[inline]
+    fn is_read_only(&self) -> bool {
+        self.field_flags_impl()
+            .contains(PdfFormFieldFlags::ReadOnly)
+    }
+
+    #[inline]
+    fn is_required(&self) -> bool {
+        self.field_flags_impl()
+            .contains(PdfFormFieldFlags::Required)
+    }
+
     #[inline]
     fn appearance_stream(&self) -> Option<String> {
         self.appearance_stream_impl()
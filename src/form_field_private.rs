@@ -15,7 +15,7 @@ pub(crate) mod internal {
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::PdfiumError;
-    use crate::form_field::PdfFormFieldCommon;
+    use crate::form_field::{PdfFormFieldCommon, PdfFormFieldFlags};
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use std::os::raw::c_int;
@@ -262,9 +262,10 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfFormFieldCommon::field_flags()].
         #[inline]
-        fn form_field_flags_impl(&self) -> FpdfAnnotationFlags {
-            FpdfAnnotationFlags::from_bits_truncate(
+        fn field_flags_impl(&self) -> PdfFormFieldFlags {
+            PdfFormFieldFlags::from_bits_truncate(
                 self.bindings()
                     .FPDFAnnot_GetFormFieldFlags(*self.form_handle(), *self.annotation_handle())
                     as u32,
@@ -60,6 +60,15 @@ impl<'a> PdfFormRadioButtonField<'a> {
         self.value_impl()
     }
 
+    /// Returns the value this [PdfFormRadioButtonField] exports when it is selected, if any.
+    ///
+    /// The export value is the name of the field's "on" appearance state. When this
+    /// field is selected, [PdfFormRadioButtonField::group_value()] returns the same value.
+    #[inline]
+    pub fn export_value(&self) -> Option<String> {
+        self.export_value_impl()
+    }
+
     /// Returns `true` if this [PdfFormRadioButtonField] object has its radio button selected.
     #[inline]
     pub fn is_checked(&self) -> Result<bool, PdfiumError> {
@@ -0,0 +1,104 @@
+//! Defines the [PdfFormFields] struct, a collection of all the [PdfFormField] objects
+//! in a `PdfForm`.
+
+use crate::form_field::{PdfFormField, PdfFormFieldCommon};
+use crate::page::PdfPage;
+use crate::page_annotation::PdfPageAnnotation;
+use crate::pages::PdfPages;
+
+/// A collection of all the [PdfFormField] objects in a `PdfForm`, gathered from
+/// every page in a document.
+///
+/// Form fields in Pdfium are wrapped inside page annotations of type
+/// `PdfPageAnnotationType::Widget` or `PdfPageAnnotationType::XfaWidget`. This collection
+/// keeps the pages and widget annotations containing each form field open for as long as
+/// the collection itself is in scope, so the form fields it yields remain valid.
+pub struct PdfFormFields<'a> {
+    // Fields are dropped in declaration order, so the widget annotations will always
+    // be closed before the pages that contain them.
+    widgets: Vec<PdfPageAnnotation<'a>>,
+
+    #[allow(dead_code)]
+    // The pages field is never read; it exists only to keep the pages open.
+    pages: Vec<PdfPage<'a>>,
+}
+
+impl<'a> PdfFormFields<'a> {
+    /// Gathers the form fields from every page in the given [PdfPages] collection.
+    pub(crate) fn from_pages(pages: &PdfPages<'a>) -> Self {
+        let mut widgets = Vec::new();
+
+        let mut pages_with_fields = Vec::new();
+
+        for index in 0..pages.len() {
+            if let Ok(page) = pages.get(index) {
+                let annotations = page.annotations();
+
+                let count = widgets.len();
+
+                for annotation_index in annotations.as_range() {
+                    if let Ok(annotation) = annotations.get(annotation_index) {
+                        if annotation.as_form_field().is_some() {
+                            widgets.push(annotation);
+                        }
+                    }
+                }
+
+                if widgets.len() > count {
+                    pages_with_fields.push(page);
+                }
+            }
+        }
+
+        PdfFormFields {
+            widgets,
+            pages: pages_with_fields,
+        }
+    }
+
+    /// Returns the number of form fields in this [PdfFormFields] collection.
+    ///
+    /// Each control in a group of checkbox or radio button controls is counted separately,
+    /// even though all controls in the group share the same field name.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.widgets.len()
+    }
+
+    /// Returns `true` if this [PdfFormFields] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    /// Returns the first [PdfFormField] in this [PdfFormFields] collection with the given
+    /// fully qualified name, if any.
+    pub fn find(&self, name: &str) -> Option<&PdfFormField> {
+        self.iter()
+            .find(|field| field.name().as_deref() == Some(name))
+    }
+
+    /// Returns an iterator over all the form fields in this [PdfFormFields] collection,
+    /// in page order.
+    #[inline]
+    pub fn iter(&self) -> PdfFormFieldsIterator {
+        PdfFormFieldsIterator {
+            widgets: self.widgets.iter(),
+        }
+    }
+}
+
+/// An iterator over all the [PdfFormField] objects in a [PdfFormFields] collection.
+pub struct PdfFormFieldsIterator<'a> {
+    widgets: std::slice::Iter<'a, PdfPageAnnotation<'a>>,
+}
+
+impl<'a> Iterator for PdfFormFieldsIterator<'a> {
+    type Item = &'a PdfFormField<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.widgets
+            .by_ref()
+            .find_map(|annotation| annotation.as_form_field())
+    }
+}
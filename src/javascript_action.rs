@@ -0,0 +1,103 @@
+//! Defines the [PdfJavascriptAction] struct, exposing functionality related to a single
+//! document-level Javascript action in a `PdfJavascriptActions` collection.
+
+use crate::bindgen::{FPDF_JAVASCRIPT_ACTION, FPDF_WCHAR};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+
+/// A single document-level Javascript action embedded in a `PdfDocument`.
+///
+/// Document-level Javascript actions are run by interactive PDF viewers when the document
+/// is opened. `pdfium-render` never executes these scripts; it only exposes their contents
+/// so they can be inspected.
+pub struct PdfJavascriptAction<'a> {
+    handle: FPDF_JAVASCRIPT_ACTION,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfJavascriptAction<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_JAVASCRIPT_ACTION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfJavascriptAction { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfJavascriptAction].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name of this [PdfJavascriptAction] in the document's name tree.
+    pub fn name(&self) -> String {
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDFJavaScriptAction_GetName() with a null buffer; this will retrieve the length of
+        // the name in bytes. If the length is zero, then there is no name associated
+        // with this action.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFJavaScriptAction_GetName() again with a pointer to the buffer;
+        // this will write the name to the buffer in UTF16-LE format.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFJavaScriptAction_GetName(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no name given for this action.
+
+            return String::new();
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFJavaScriptAction_GetName(
+            self.handle,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+    }
+
+    /// Returns the Javascript source code of this [PdfJavascriptAction].
+    pub fn script(&self) -> String {
+        // Retrieving the script from Pdfium follows the same two-step operation
+        // as retrieving the name.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFJavaScriptAction_GetScript(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no script given for this action.
+
+            return String::new();
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFJavaScriptAction_GetScript(
+            self.handle,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+    }
+}
+
+impl<'a> Drop for PdfJavascriptAction<'a> {
+    /// Closes this [PdfJavascriptAction], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFDoc_CloseJavaScriptAction(self.handle);
+    }
+}
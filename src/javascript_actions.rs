@@ -0,0 +1,126 @@
+//! Defines the [PdfJavascriptActions] struct, a collection of all the `PdfJavascriptAction`
+//! objects in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::javascript_action::PdfJavascriptAction;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_int;
+
+pub type PdfJavascriptActionIndex = u16;
+
+/// The collection of document-level [PdfJavascriptAction] objects embedded in a `PdfDocument`.
+pub struct PdfJavascriptActions<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfJavascriptActions<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfJavascriptActions {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfJavascriptActions] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of Javascript actions in this [PdfJavascriptActions] collection.
+    pub fn len(&self) -> PdfJavascriptActionIndex {
+        // FPDFDoc_GetJavaScriptActionCount() returns -1 on error, which we treat
+        // as an empty collection.
+
+        self.bindings()
+            .FPDFDoc_GetJavaScriptActionCount(self.document_handle)
+            .max(0) as PdfJavascriptActionIndex
+    }
+
+    /// Returns `true` if this [PdfJavascriptActions] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of Javascript actions)` for this
+    /// [PdfJavascriptActions] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfJavascriptActionIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of Javascript actions - 1)`
+    /// for this [PdfJavascriptActions] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfJavascriptActionIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfJavascriptAction] from this [PdfJavascriptActions] collection.
+    pub fn get(
+        &self,
+        index: PdfJavascriptActionIndex,
+    ) -> Result<PdfJavascriptAction<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::JavascriptActionIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings()
+            .FPDFDoc_GetJavaScriptAction(self.document_handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfJavascriptAction::from_pdfium(handle, self.bindings()))
+        }
+    }
+
+    /// Returns an iterator over all the Javascript actions in this [PdfJavascriptActions] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfJavascriptActionsIterator {
+        PdfJavascriptActionsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfJavascriptAction] objects in a [PdfJavascriptActions] collection.
+pub struct PdfJavascriptActionsIterator<'a> {
+    actions: &'a PdfJavascriptActions<'a>,
+    next_index: PdfJavascriptActionIndex,
+}
+
+impl<'a> PdfJavascriptActionsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(actions: &'a PdfJavascriptActions<'a>) -> Self {
+        PdfJavascriptActionsIterator {
+            actions,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfJavascriptActionsIterator<'a> {
+    type Item = PdfJavascriptAction<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.actions.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
@@ -25,11 +25,14 @@ pub mod bindings;
 pub mod bitmap;
 pub mod bookmark;
 pub mod bookmarks;
-mod clip_path; // Keep private while PdfClipPath is still in development.
+pub mod certificate;
+pub mod clip_path;
 pub mod color;
 pub mod color_space;
+pub mod data_availability;
 pub mod destination;
 pub mod document;
+pub mod document_search;
 pub mod error;
 pub mod font;
 pub mod font_glyph;
@@ -48,18 +51,26 @@ pub mod form_field_radio;
 pub mod form_field_signature;
 pub mod form_field_text;
 pub mod form_field_unknown;
+pub mod form_fields;
+pub mod javascript_action;
+pub mod javascript_actions;
 pub mod link;
 pub mod matrix;
 pub mod metadata;
+pub mod named_destinations;
 pub mod page;
 pub mod page_annotation;
 pub mod page_annotation_attachment_points;
 pub mod page_annotation_circle;
+pub mod page_annotation_file_attachment;
 pub mod page_annotation_free_text;
 pub mod page_annotation_highlight;
 pub mod page_annotation_ink;
+pub mod page_annotation_line;
 pub mod page_annotation_link;
 pub mod page_annotation_objects;
+pub mod page_annotation_polygon;
+pub mod page_annotation_polyline;
 pub mod page_annotation_popup;
 mod page_annotation_private; // Keep private so that the PdfPageAnnotationPrivate trait is not exposed.
 pub mod page_annotation_redacted;
@@ -79,6 +90,8 @@ pub mod page_links;
 pub mod page_object;
 pub mod page_object_group;
 pub mod page_object_image;
+pub mod page_object_mark;
+pub mod page_object_marks;
 pub mod page_object_path;
 mod page_object_private; // Keep private so that the PdfPageObjectPrivate trait is not exposed.
 pub mod page_object_shading;
@@ -95,11 +108,14 @@ pub mod page_text_chars;
 pub mod page_text_search;
 pub mod page_text_segment;
 pub mod page_text_segments;
+pub mod page_text_struct_element;
+pub mod page_text_styled_chars;
 pub mod pages;
 pub mod paragraph;
 pub mod path_segment;
 pub mod path_segments;
 pub mod pdfium;
+pub mod pending_document;
 pub mod permissions;
 pub mod points;
 pub mod quad_points;
@@ -107,6 +123,8 @@ pub mod rect;
 pub mod render_config;
 pub mod signature;
 pub mod signatures;
+pub mod struct_element;
+pub mod struct_tree;
 mod transform; // Keep private so that internal macros are not exposed.
 mod utils; // Keep internal utility functions private.
 
@@ -119,25 +137,31 @@ mod utils; // Keep internal utility functions private.
 pub mod prelude {
     pub use super::{
         action::*, appearance_mode::*, attachment::*, attachments::*, bindings::*, bitmap::*,
-        bookmark::*, bookmarks::*, clip_path::*, color::*, color_space::*, destination::*,
-        document::*, error::*, font::*, font_glyph::*, font_glyphs::*, fonts::*, form::*,
-        form_field::*, form_field_button::*, form_field_checkbox::*, form_field_combo::*,
-        form_field_list::*, form_field_option::*, form_field_options::*, form_field_radio::*,
-        form_field_signature::*, form_field_text::*, form_field_unknown::*, link::*, matrix::*,
-        metadata::*, page::*, page_annotation::*, page_annotation_attachment_points::*,
-        page_annotation_circle::*, page_annotation_free_text::*, page_annotation_highlight::*,
-        page_annotation_ink::*, page_annotation_link::*, page_annotation_objects::*,
-        page_annotation_popup::*, page_annotation_redacted::*, page_annotation_square::*,
-        page_annotation_squiggly::*, page_annotation_stamp::*, page_annotation_strikeout::*,
-        page_annotation_text::*, page_annotation_underline::*, page_annotation_unsupported::*,
-        page_annotation_widget::*, page_annotation_xfa_widget::*, page_annotations::*,
-        page_boundaries::*, page_links::*, page_object::*, page_object_group::*,
-        page_object_image::*, page_object_path::*, page_object_shading::*, page_object_text::*,
+        bookmark::*, bookmarks::*, certificate::*, clip_path::*, color::*, color_space::*,
+        data_availability::*, destination::*, document::*, document_search::*, error::*, font::*,
+        font_glyph::*, font_glyphs::*, fonts::*, form::*, form_field::*, form_field_button::*,
+        form_field_checkbox::*, form_field_combo::*, form_field_list::*, form_field_option::*,
+        form_field_options::*, form_field_radio::*, form_field_signature::*, form_field_text::*,
+        form_field_unknown::*, form_fields::*, javascript_action::*, javascript_actions::*,
+        link::*, matrix::*, metadata::*, named_destinations::*, page::*, page_annotation::*,
+        page_annotation_attachment_points::*, page_annotation_circle::*,
+        page_annotation_file_attachment::*, page_annotation_free_text::*,
+        page_annotation_highlight::*, page_annotation_ink::*, page_annotation_line::*,
+        page_annotation_link::*, page_annotation_objects::*, page_annotation_polygon::*,
+        page_annotation_polyline::*, page_annotation_popup::*, page_annotation_redacted::*,
+        page_annotation_square::*, page_annotation_squiggly::*, page_annotation_stamp::*,
+        page_annotation_strikeout::*, page_annotation_text::*, page_annotation_underline::*,
+        page_annotation_unsupported::*, page_annotation_widget::*, page_annotation_xfa_widget::*,
+        page_annotations::*, page_boundaries::*, page_links::*, page_object::*,
+        page_object_group::*, page_object_image::*, page_object_mark::*, page_object_marks::*,
+        page_object_path::*, page_object_shading::*, page_object_text::*,
         page_object_unsupported::*, page_object_x_object_form::*, page_objects::*,
         page_objects_common::*, page_size::*, page_text::*, page_text_char::*, page_text_chars::*,
-        page_text_search::*, page_text_segment::*, page_text_segments::*, pages::*,
-        path_segment::*, path_segments::*, pdfium::*, permissions::*, points::*, quad_points::*,
-        rect::*, render_config::*, signature::*, signatures::*,
+        page_text_search::*, page_text_segment::*, page_text_segments::*,
+        page_text_struct_element::*, page_text_styled_chars::*, pages::*, path_segment::*,
+        path_segments::*, pdfium::*, pending_document::*, permissions::*, points::*,
+        quad_points::*, rect::*, render_config::*, signature::*, signatures::*, struct_element::*,
+        struct_tree::*,
     };
 }
 
@@ -1,10 +1,31 @@
 //! Defines the [PdfLink] struct, exposing functionality related to a single link contained
 //! within a `PdfPage`, a `PdfPageAnnotation`, or a `PdfBookmark`.
 
-use crate::action::PdfAction;
+use crate::action::{PdfAction, PdfActionType};
 use crate::bindgen::{FPDF_DOCUMENT, FPDF_LINK};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::destination::PdfDestination;
+use crate::error::PdfiumError;
+
+/// The broad category of behaviour triggered when the user interacts with a [PdfLink].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfLinkActionType {
+    /// The link opens an external URI, typically a web address.
+    Uri,
+
+    /// The link navigates to a destination in the same document.
+    GoToDestination,
+
+    /// The link launches an application or opens a file.
+    Launch,
+
+    /// The link navigates to a destination in another document, either a separate file
+    /// or a file embedded in this document.
+    RemoteGoTo,
+
+    /// The link performs an action that Pdfium does not recognize, or performs no action at all.
+    Unsupported,
+}
 
 pub struct PdfLink<'a> {
     handle: FPDF_LINK,
@@ -53,6 +74,43 @@ impl<'a> PdfLink<'a> {
         }
     }
 
+    /// Returns the [PdfLinkActionType] of this [PdfLink], indicating whether the link points to
+    /// an external URI, a destination in this or another document, or something else.
+    ///
+    /// Links without an action that nonetheless carry a destination are reported as
+    /// [PdfLinkActionType::GoToDestination].
+    pub fn action_type(&self) -> Result<PdfLinkActionType, PdfiumError> {
+        let handle = self.bindings.FPDFLink_GetAction(self.handle);
+
+        if handle.is_null() {
+            return Ok(if self.destination().is_some() {
+                PdfLinkActionType::GoToDestination
+            } else {
+                PdfLinkActionType::Unsupported
+            });
+        }
+
+        Ok(
+            match PdfActionType::from_pdfium(self.bindings.FPDFAction_GetType(handle) as u32)? {
+                PdfActionType::Uri => PdfLinkActionType::Uri,
+                PdfActionType::GoToDestinationInSameDocument => PdfLinkActionType::GoToDestination,
+                PdfActionType::GoToDestinationInRemoteDocument
+                | PdfActionType::GoToDestinationInEmbeddedDocument => PdfLinkActionType::RemoteGoTo,
+                PdfActionType::Launch => PdfLinkActionType::Launch,
+                PdfActionType::Unsupported => PdfLinkActionType::Unsupported,
+            },
+        )
+    }
+
+    /// Returns the URI targeted by this [PdfLink], or `None` if this link does not
+    /// carry a URI action.
+    pub fn uri(&self) -> Result<Option<String>, PdfiumError> {
+        match self.action() {
+            Some(PdfAction::Uri(action)) => action.uri().map(Some),
+            _ => Ok(None),
+        }
+    }
+
     /// Returns the [PdfDestination] associated with this [PdfLink], if any.
     ///
     /// The destination specifies the page and region, if any, that will be the target
@@ -71,3 +129,45 @@ impl<'a> PdfLink<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_link_action_types() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/link-actions-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut results = Vec::new();
+
+        for annotation in page.annotations().iter() {
+            let link = annotation.as_link_annotation().unwrap().link()?;
+
+            results.push((link.action_type()?, link.uri()?));
+        }
+
+        // The final link has neither an action nor a destination.
+
+        assert_eq!(
+            results,
+            vec![
+                (
+                    PdfLinkActionType::Uri,
+                    Some("https://example.com/".to_owned())
+                ),
+                (PdfLinkActionType::GoToDestination, None),
+                (PdfLinkActionType::Launch, None),
+                (PdfLinkActionType::RemoteGoTo, None),
+                (PdfLinkActionType::Unsupported, None),
+                (PdfLinkActionType::Unsupported, None),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+//! Defines the [PdfNamedDestinations] struct, a collection of all the named
+//! `PdfDestination` objects in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::destination::PdfDestination;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::{c_int, c_long, c_void};
+
+pub type PdfNamedDestinationIndex = u32;
+
+/// A single [PdfDestination] registered under a name in a `PdfDocument`.
+pub struct PdfNamedDestination<'a> {
+    name: String,
+    destination: PdfDestination<'a>,
+}
+
+impl<'a> PdfNamedDestination<'a> {
+    #[inline]
+    pub(crate) fn new(name: String, destination: PdfDestination<'a>) -> Self {
+        PdfNamedDestination { name, destination }
+    }
+
+    /// Returns the name under which this [PdfNamedDestination] is registered in the document.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the [PdfDestination] targeted by this [PdfNamedDestination].
+    #[inline]
+    pub fn destination(&self) -> &PdfDestination<'a> {
+        &self.destination
+    }
+}
+
+/// The collection of named destinations defined in a `PdfDocument`, either in the
+/// document's /Dests dictionary or in its /Dests name tree.
+pub struct PdfNamedDestinations<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfNamedDestinations<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfNamedDestinations {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of named destinations in this [PdfNamedDestinations] collection.
+    ///
+    /// The count includes names whose value is not a valid destination. Pdfium counts these,
+    /// but cannot retrieve them, so [PdfNamedDestinations::get()] returns an error for their
+    /// indices and [PdfNamedDestinations::iter()] skips over them. The number of destinations
+    /// yielded by the iterator may therefore be smaller than the value returned here.
+    #[inline]
+    pub fn len(&self) -> PdfNamedDestinationIndex {
+        self.bindings().FPDF_CountNamedDests(self.document_handle) as PdfNamedDestinationIndex
+    }
+
+    /// Returns `true` if this [PdfNamedDestinations] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of named destinations)` for this
+    /// [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfNamedDestinationIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of named destinations - 1)`
+    /// for this [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfNamedDestinationIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfNamedDestination] from this [PdfNamedDestinations] collection.
+    ///
+    /// Returns [PdfiumError::PdfiumLibraryInternalError] if the value registered under the name
+    /// at the given index is not a valid destination.
+    pub fn get(
+        &self,
+        index: PdfNamedDestinationIndex,
+    ) -> Result<PdfNamedDestination<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::NamedDestinationIndexOutOfBounds);
+        }
+
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDF_GetNamedDest() with a null buffer; this will set buffer_length to the length
+        // of the name in bytes.
+
+        // We then reserve a byte buffer of the given length and call FPDF_GetNamedDest()
+        // again with a pointer to the buffer; this will write the name to the buffer
+        // in UTF16-LE format and return the destination handle.
+
+        let mut buffer_length: c_long = 0;
+
+        self.bindings().FPDF_GetNamedDest(
+            self.document_handle,
+            index as c_int,
+            std::ptr::null_mut(),
+            &mut buffer_length,
+        );
+
+        if buffer_length <= 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let handle = self.bindings().FPDF_GetNamedDest(
+            self.document_handle,
+            index as c_int,
+            buffer.as_mut_ptr() as *mut c_void,
+            &mut buffer_length,
+        );
+
+        if handle.is_null() || buffer_length <= 0 {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            buffer.truncate(buffer_length as usize);
+
+            Ok(PdfNamedDestination::new(
+                get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default(),
+                PdfDestination::from_pdfium(self.document_handle, handle, self.bindings()),
+            ))
+        }
+    }
+
+    /// Returns the [PdfDestination] registered under the given name in this
+    /// [PdfNamedDestinations] collection, if any.
+    pub fn get_by_name(&self, name: &str) -> Option<PdfDestination<'a>> {
+        let handle = self
+            .bindings()
+            .FPDF_GetNamedDestByName(self.document_handle, name);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfDestination::from_pdfium(
+                self.document_handle,
+                handle,
+                self.bindings(),
+            ))
+        }
+    }
+
+    /// Returns an iterator over all the named destinations in this [PdfNamedDestinations] collection.
+    ///
+    /// Names whose value is not a valid destination are skipped.
+    #[inline]
+    pub fn iter(&self) -> PdfNamedDestinationsIterator {
+        PdfNamedDestinationsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfNamedDestination] objects in a [PdfNamedDestinations] collection.
+pub struct PdfNamedDestinationsIterator<'a> {
+    destinations: &'a PdfNamedDestinations<'a>,
+    next_index: PdfNamedDestinationIndex,
+}
+
+impl<'a> PdfNamedDestinationsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(destinations: &'a PdfNamedDestinations<'a>) -> Self {
+        PdfNamedDestinationsIterator {
+            destinations,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfNamedDestinationsIterator<'a> {
+    type Item = PdfNamedDestination<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.destinations.len() {
+            let next = self.destinations.get(self.next_index);
+
+            self.next_index += 1;
+
+            // Skip over any name whose value is not a valid destination, rather than
+            // ending the iteration early.
+
+            if let Ok(destination) = next {
+                return Some(destination);
+            }
+        }
+
+        None
+    }
+}
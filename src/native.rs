@@ -1,18 +1,21 @@
 use crate::bindgen::{
     size_t, FPDFANNOT_COLORTYPE, FPDF_ACTION, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE,
-    FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
+    FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_AVAIL, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
     FPDF_BYTESTRING, FPDF_CLIPPATH, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD,
     FPDF_FILEACCESS, FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO,
-    FPDF_FORMHANDLE, FPDF_GLYPHPATH, FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE,
-    FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE,
-    FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRING, FPDF_STRUCTELEMENT,
-    FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FS_FLOAT,
-    FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF,
+    FPDF_FORMHANDLE, FPDF_GLYPHPATH, FPDF_IMAGEOBJ_METADATA, FPDF_JAVASCRIPT_ACTION, FPDF_LINK,
+    FPDF_OBJECT_TYPE, FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK,
+    FPDF_PAGERANGE, FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRING,
+    FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR,
+    FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF, FX_DOWNLOADHINTS,
+    FX_FILEAVAIL, IFSDK_PAUSE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use libloading::{Library, Symbol};
 use std::ffi::CString;
-use std::os::raw::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_ulong, c_ushort, c_void};
+use std::os::raw::{
+    c_char, c_double, c_float, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void,
+};
 
 pub(crate) struct DynamicPdfiumBindings {
     library: Library,
@@ -31,6 +34,13 @@ impl DynamicPdfiumBindings {
         result.extern_FPDF_LoadDocument()?;
         result.extern_FPDF_LoadMemDocument64()?;
         result.extern_FPDF_LoadCustomDocument()?;
+        result.extern_FPDFAvail_Create()?;
+        result.extern_FPDFAvail_Destroy()?;
+        result.extern_FPDFAvail_IsDocAvail()?;
+        result.extern_FPDFAvail_GetDocument()?;
+        result.extern_FPDFAvail_GetFirstPageNum()?;
+        result.extern_FPDFAvail_IsPageAvail()?;
+        result.extern_FPDFAvail_IsLinearized()?;
         result.extern_FPDF_SaveAsCopy()?;
         result.extern_FPDF_SaveWithVersion()?;
         result.extern_FPDF_CloseDocument()?;
@@ -110,6 +120,9 @@ impl DynamicPdfiumBindings {
         result.extern_FPDFBitmap_GetStride()?;
         result.extern_FPDF_RenderPageBitmap()?;
         result.extern_FPDF_RenderPageBitmapWithMatrix()?;
+        result.extern_FPDF_RenderPageBitmap_Start()?;
+        result.extern_FPDF_RenderPage_Continue()?;
+        result.extern_FPDF_RenderPage_Close()?;
         result.extern_FPDFAnnot_IsSupportedSubtype()?;
         result.extern_FPDFPage_CreateAnnot()?;
         result.extern_FPDFPage_GetAnnotCount()?;
@@ -169,12 +182,15 @@ impl DynamicPdfiumBindings {
         result.extern_FPDFAnnot_GetFormFieldExportValue()?;
         result.extern_FPDFAnnot_SetURI()?;
         result.extern_FPDFAnnot_SetDest()?;
+        result.extern_FPDFAnnot_GetFileAttachment()?;
+        result.extern_FPDFAnnot_AddFileAttachment()?;
         result.extern_FPDFDOC_InitFormFillEnvironment()?;
         result.extern_FPDFDOC_ExitFormFillEnvironment()?;
         result.extern_FPDFDoc_GetPageMode()?;
         result.extern_FPDFPage_Flatten()?;
         result.extern_FPDF_SetFormFieldHighlightColor()?;
         result.extern_FPDF_SetFormFieldHighlightAlpha()?;
+        result.extern_FPDF_RemoveFormFieldHighlight()?;
         result.extern_FPDF_FFLDraw()?;
         result.extern_FPDFBookmark_GetFirstChild()?;
         result.extern_FPDFBookmark_GetNextSibling()?;
@@ -332,6 +348,9 @@ impl DynamicPdfiumBindings {
         result.extern_FPDF_VIEWERREF_GetPrintPageRangeElement()?;
         result.extern_FPDF_VIEWERREF_GetDuplex()?;
         result.extern_FPDF_VIEWERREF_GetName()?;
+        result.extern_FPDF_CountNamedDests()?;
+        result.extern_FPDF_GetNamedDestByName()?;
+        result.extern_FPDF_GetNamedDest()?;
         result.extern_FPDFDoc_GetAttachmentCount()?;
         result.extern_FPDFDoc_AddAttachment()?;
         result.extern_FPDFDoc_GetAttachment()?;
@@ -343,6 +362,12 @@ impl DynamicPdfiumBindings {
         result.extern_FPDFAttachment_GetStringValue()?;
         result.extern_FPDFAttachment_SetFile()?;
         result.extern_FPDFAttachment_GetFile()?;
+        result.extern_FPDFAttachment_GetSubtype()?;
+        result.extern_FPDFDoc_GetJavaScriptActionCount()?;
+        result.extern_FPDFDoc_GetJavaScriptAction()?;
+        result.extern_FPDFDoc_CloseJavaScriptAction()?;
+        result.extern_FPDFJavaScriptAction_GetName()?;
+        result.extern_FPDFJavaScriptAction_GetScript()?;
 
         Ok(result)
     }
@@ -426,6 +451,85 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_LoadCustomDocument\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_Create(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                file_avail: *mut FX_FILEAVAIL,
+                file: *mut FPDF_FILEACCESS,
+            ) -> FPDF_AVAIL,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAvail_Create\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_Destroy(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(avail: FPDF_AVAIL)>, libloading::Error> {
+        unsafe { self.library.get(b"FPDFAvail_Destroy\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_IsDocAvail(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAvail_IsDocAvail\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_GetDocument(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(avail: FPDF_AVAIL, password: FPDF_BYTESTRING) -> FPDF_DOCUMENT>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAvail_GetDocument\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_GetFirstPageNum(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(doc: FPDF_DOCUMENT) -> c_int>, libloading::Error> {
+        unsafe { self.library.get(b"FPDFAvail_GetFirstPageNum\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_IsPageAvail(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                avail: FPDF_AVAIL,
+                page_index: c_int,
+                hints: *mut FX_DOWNLOADHINTS,
+            ) -> c_int,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAvail_IsPageAvail\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAvail_IsLinearized(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(avail: FPDF_AVAIL) -> c_int>, libloading::Error> {
+        unsafe { self.library.get(b"FPDFAvail_IsLinearized\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDF_SaveAsCopy(
@@ -1527,6 +1631,50 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_RenderPageBitmapWithMatrix\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_RenderPageBitmap_Start(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                bitmap: FPDF_BITMAP,
+                page: FPDF_PAGE,
+                start_x: c_int,
+                start_y: c_int,
+                size_x: c_int,
+                size_y: c_int,
+                rotate: c_int,
+                flags: c_int,
+                pause: *mut IFSDK_PAUSE,
+            ) -> c_int,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_RenderPageBitmap_Start\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    #[allow(clippy::type_complexity)]
+    fn extern_FPDF_RenderPage_Continue(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(page: FPDF_PAGE, pause: *mut IFSDK_PAUSE) -> c_int>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_RenderPage_Continue\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDF_RenderPage_Close(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(page: FPDF_PAGE)>, libloading::Error> {
+        unsafe { self.library.get(b"FPDF_RenderPage_Close\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDFAnnot_IsSupportedSubtype(
@@ -2341,6 +2489,30 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDFAnnot_SetDest\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAnnot_GetFileAttachment(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAnnot_GetFileAttachment\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAnnot_AddFileAttachment(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(annot: FPDF_ANNOTATION, name: FPDF_WIDESTRING) -> FPDF_ATTACHMENT,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAnnot_AddFileAttachment\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDFDOC_InitFormFillEnvironment(
@@ -2407,6 +2579,14 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_SetFormFieldHighlightAlpha\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDF_RemoveFormFieldHighlight(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(handle: FPDF_FORMHANDLE)>, libloading::Error> {
+        unsafe { self.library.get(b"FPDF_RemoveFormFieldHighlight\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     #[allow(clippy::type_complexity)]
@@ -4605,6 +4785,46 @@ impl DynamicPdfiumBindings {
         unsafe { self.library.get(b"FPDF_VIEWERREF_GetName\0") }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDF_CountNamedDests(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(document: FPDF_DOCUMENT) -> FPDF_DWORD>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_CountNamedDests\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDF_GetNamedDestByName(
+        &self,
+    ) -> Result<
+        Symbol<unsafe extern "C" fn(document: FPDF_DOCUMENT, name: FPDF_BYTESTRING) -> FPDF_DEST>,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_GetNamedDestByName\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDF_GetNamedDest(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                document: FPDF_DOCUMENT,
+                index: c_int,
+                buffer: *mut c_void,
+                buflen: *mut c_long,
+            ) -> FPDF_DEST,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDF_GetNamedDest\0") }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn extern_FPDFDoc_GetAttachmentCount(
@@ -4765,6 +4985,88 @@ impl DynamicPdfiumBindings {
     > {
         unsafe { self.library.get(b"FPDFAttachment_GetFile\0") }
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFAttachment_GetSubtype(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                attachment: FPDF_ATTACHMENT,
+                buffer: *mut FPDF_WCHAR,
+                buflen: c_ulong,
+            ) -> c_ulong,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFAttachment_GetSubtype\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFDoc_GetJavaScriptActionCount(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(document: FPDF_DOCUMENT) -> c_int>, libloading::Error>
+    {
+        unsafe { self.library.get(b"FPDFDoc_GetJavaScriptActionCount\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFDoc_GetJavaScriptAction(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(document: FPDF_DOCUMENT, index: c_int) -> FPDF_JAVASCRIPT_ACTION,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFDoc_GetJavaScriptAction\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFDoc_CloseJavaScriptAction(
+        &self,
+    ) -> Result<Symbol<unsafe extern "C" fn(javascript: FPDF_JAVASCRIPT_ACTION)>, libloading::Error>
+    {
+        unsafe { self.library.get(b"FPDFDoc_CloseJavaScriptAction\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFJavaScriptAction_GetName(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                javascript: FPDF_JAVASCRIPT_ACTION,
+                buffer: *mut FPDF_WCHAR,
+                buflen: c_ulong,
+            ) -> c_ulong,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFJavaScriptAction_GetName\0") }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn extern_FPDFJavaScriptAction_GetScript(
+        &self,
+    ) -> Result<
+        Symbol<
+            unsafe extern "C" fn(
+                javascript: FPDF_JAVASCRIPT_ACTION,
+                buffer: *mut FPDF_WCHAR,
+                buflen: c_ulong,
+            ) -> c_ulong,
+        >,
+        libloading::Error,
+    > {
+        unsafe { self.library.get(b"FPDFJavaScriptAction_GetScript\0") }
+    }
 }
 
 impl PdfiumLibraryBindings for DynamicPdfiumBindings {
@@ -4833,6 +5135,59 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         unsafe { self.extern_FPDF_LoadCustomDocument().unwrap()(pFileAccess, c_password.as_ptr()) }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_Create(
+        &self,
+        file_avail: *mut FX_FILEAVAIL,
+        file: *mut FPDF_FILEACCESS,
+    ) -> FPDF_AVAIL {
+        unsafe { self.extern_FPDFAvail_Create().unwrap()(file_avail, file) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_Destroy(&self, avail: FPDF_AVAIL) {
+        unsafe { self.extern_FPDFAvail_Destroy().unwrap()(avail) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsDocAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
+        unsafe { self.extern_FPDFAvail_IsDocAvail().unwrap()(avail, hints) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_GetDocument(&self, avail: FPDF_AVAIL, password: Option<&str>) -> FPDF_DOCUMENT {
+        let c_password = CString::new(password.unwrap_or("")).unwrap();
+
+        unsafe { self.extern_FPDFAvail_GetDocument().unwrap()(avail, c_password.as_ptr()) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_GetFirstPageNum(&self, doc: FPDF_DOCUMENT) -> c_int {
+        unsafe { self.extern_FPDFAvail_GetFirstPageNum().unwrap()(doc) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsPageAvail(
+        &self,
+        avail: FPDF_AVAIL,
+        page_index: c_int,
+        hints: *mut FX_DOWNLOADHINTS,
+    ) -> c_int {
+        unsafe { self.extern_FPDFAvail_IsPageAvail().unwrap()(avail, page_index, hints) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsLinearized(&self, avail: FPDF_AVAIL) -> c_int {
+        unsafe { self.extern_FPDFAvail_IsLinearized().unwrap()(avail) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SaveAsCopy(
@@ -5642,6 +5997,39 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmap_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        unsafe {
+            self.extern_FPDF_RenderPageBitmap_Start().unwrap()(
+                bitmap, page, start_x, start_y, size_x, size_y, rotate, flags, pause,
+            )
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Continue(&self, page: FPDF_PAGE, pause: *mut IFSDK_PAUSE) -> c_int {
+        unsafe { self.extern_FPDF_RenderPage_Continue().unwrap()(page, pause) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        unsafe { self.extern_FPDF_RenderPage_Close().unwrap()(page) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
@@ -6204,6 +6592,22 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         unsafe { self.extern_FPDFAnnot_SetDest().unwrap()(annot, page_dest, x, y, z) }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_GetFileAttachment(&self, annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT {
+        unsafe { self.extern_FPDFAnnot_GetFileAttachment().unwrap()(annot) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_AddFileAttachment(
+        &self,
+        annot: FPDF_ANNOTATION,
+        name: FPDF_WIDESTRING,
+    ) -> FPDF_ATTACHMENT {
+        unsafe { self.extern_FPDFAnnot_AddFileAttachment().unwrap()(annot, name) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDOC_InitFormFillEnvironment(
@@ -6255,6 +6659,14 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RemoveFormFieldHighlight(&self, handle: FPDF_FORMHANDLE) {
+        unsafe {
+            self.extern_FPDF_RemoveFormFieldHighlight().unwrap()(handle);
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_FFLDraw(
@@ -7826,6 +8238,32 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_CountNamedDests(&self, document: FPDF_DOCUMENT) -> FPDF_DWORD {
+        unsafe { self.extern_FPDF_CountNamedDests().unwrap()(document) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDestByName(&self, document: FPDF_DOCUMENT, name: &str) -> FPDF_DEST {
+        let c_name = CString::new(name).unwrap();
+
+        unsafe { self.extern_FPDF_GetNamedDestByName().unwrap()(document, c_name.as_ptr()) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDest(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: *mut c_long,
+    ) -> FPDF_DEST {
+        unsafe { self.extern_FPDF_GetNamedDest().unwrap()(document, index, buffer, buflen) }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetAttachmentCount(&self, document: FPDF_DOCUMENT) -> c_int {
@@ -7948,4 +8386,59 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
             self.extern_FPDFAttachment_GetFile().unwrap()(attachment, buffer, buflen, out_buflen)
         }
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAttachment_GetSubtype(
+        &self,
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        unsafe { self.extern_FPDFAttachment_GetSubtype().unwrap()(attachment, buffer, buflen) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptActionCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        unsafe { self.extern_FPDFDoc_GetJavaScriptActionCount().unwrap()(document) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptAction(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+    ) -> FPDF_JAVASCRIPT_ACTION {
+        unsafe { self.extern_FPDFDoc_GetJavaScriptAction().unwrap()(document, index) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_CloseJavaScriptAction(&self, javascript: FPDF_JAVASCRIPT_ACTION) {
+        unsafe { self.extern_FPDFDoc_CloseJavaScriptAction().unwrap()(javascript) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetName(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        unsafe { self.extern_FPDFJavaScriptAction_GetName().unwrap()(javascript, buffer, buflen) }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetScript(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        unsafe { self.extern_FPDFJavaScriptAction_GetScript().unwrap()(javascript, buffer, buflen) }
+    }
 }
@@ -2,8 +2,9 @@
 //! `PdfPages` collection.
 
 use crate::bindgen::{
-    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT, FPDF_DOCUMENT, FPDF_FORMHANDLE,
-    FPDF_PAGE,
+    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_NORMALDISPLAY, FLAT_PRINT,
+    FPDF_BITMAP, FPDF_BOOL, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE, FPDF_RENDER_FAILED,
+    FPDF_RENDER_TOBECONTINUED, IFSDK_PAUSE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
@@ -11,10 +12,12 @@ use crate::create_transform_setters;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::font::PdfFont;
 use crate::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::page_annotation::{PdfPageAnnotation, PdfPageAnnotationType};
 use crate::page_annotations::PdfPageAnnotations;
 use crate::page_boundaries::PdfPageBoundaries;
 use crate::page_index_cache::PdfPageIndexCache;
 use crate::page_links::PdfPageLinks;
+use crate::page_object::PdfPageObjectCommon;
 use crate::page_objects::PdfPageObjects;
 use crate::page_objects_common::PdfPageObjectsCommon;
 use crate::page_size::PdfPagePaperSize;
@@ -22,6 +25,7 @@ use crate::page_text::PdfPageText;
 use crate::points::PdfPoints;
 use crate::rect::PdfRect;
 use crate::render_config::{PdfRenderConfig, PdfRenderSettings};
+use crate::struct_tree::PdfStructTree;
 use std::collections::{hash_map::Entry, HashMap};
 use std::f32::consts::{FRAC_PI_2, PI};
 use std::os::raw::{c_double, c_int};
@@ -47,6 +51,38 @@ impl PdfPageOrientation {
     }
 }
 
+/// The purpose for which annotations and form fields should be flattened into the content
+/// of a [PdfPage] by [PdfPage::flatten()].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PdfFlattenFlag {
+    /// Flattens annotations and form fields that would be displayed on screen.
+    NormalDisplay,
+
+    /// Flattens annotations and form fields that would be printed. Annotations without
+    /// the `PdfAnnotationFlags::Print` flag set will be discarded.
+    Print,
+}
+
+impl PdfFlattenFlag {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        (match self {
+            PdfFlattenFlag::NormalDisplay => FLAT_NORMALDISPLAY,
+            PdfFlattenFlag::Print => FLAT_PRINT,
+        }) as c_int
+    }
+}
+
+/// The outcome of a successful call to [PdfPage::flatten()].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PdfFlattenResult {
+    /// At least one annotation or form field was flattened into the page contents.
+    Flattened,
+
+    /// The page contained no annotations or form fields that could be flattened.
+    NothingToFlatten,
+}
+
 /// A rotation transformation that should be applied to a [PdfPage] when it is rendered
 /// into a [PdfBitmap].
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -346,7 +382,12 @@ impl<'a> PdfPage<'a> {
     ///         &PdfRenderConfig::thumbnail(thumbnail_desired_pixel_size)
     ///     )?; // Renders a 128 x 128 thumbnail of the page
     /// ```
-    pub fn embedded_thumbnail(&self) -> Result<PdfBitmap, PdfiumError> {
+    ///
+    /// Returns `Ok(None)` if no thumbnail was embedded for this page. Retrieving an embedded
+    /// thumbnail is considerably cheaper than rendering one, making this function well-suited
+    /// to generating grid previews of many pages. The returned [PdfBitmap] can be converted
+    /// to an image in exactly the same way as a bitmap returned by any of the rendering functions.
+    pub fn embedded_thumbnail(&self) -> Result<Option<PdfBitmap>, PdfiumError> {
         let thumbnail_handle = self
             .bindings()
             .FPDFPage_GetThumbnailAsBitmap(self.page_handle);
@@ -354,9 +395,12 @@ impl<'a> PdfPage<'a> {
         if thumbnail_handle.is_null() {
             // No thumbnail is available for this page.
 
-            Err(PdfiumError::PageMissingEmbeddedThumbnail)
+            Ok(None)
         } else {
-            Ok(PdfBitmap::from_pdfium(thumbnail_handle, self.bindings))
+            Ok(Some(PdfBitmap::from_pdfium(
+                thumbnail_handle,
+                self.bindings,
+            )))
         }
     }
 
@@ -379,6 +423,20 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns the tagged structure tree of this [PdfPage].
+    ///
+    /// The structure tree records the logical structure of the page's content, such as headings,
+    /// paragraphs, tables, and figures, together with the intended reading order.
+    ///
+    /// Returns [PdfiumError::NoStructureTree] if this [PdfPage] has no structure tree,
+    /// as is the case for untagged documents.
+    pub fn structure_tree(&self) -> Result<PdfStructTree, PdfiumError> {
+        PdfStructTree::from_pdfium(
+            self.bindings().FPDF_StructTree_GetForPage(self.page_handle),
+            self.bindings,
+        )
+    }
+
     /// Returns an immutable collection of the annotations that have been added to this [PdfPage].
     pub fn annotations(&self) -> &PdfPageAnnotations<'a> {
         if self.regeneration_strategy == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
@@ -594,7 +652,10 @@ impl<'a> PdfPage<'a> {
     /// allocates memory for it. To avoid repeated allocations, create a single [PdfBitmap] object
     /// using [PdfBitmap::empty()] and reuse it across multiple calls to
     /// [PdfPage::render_into_bitmap_with_config()].
-    pub fn render_with_config(&self, config: &PdfRenderConfig) -> Result<PdfBitmap, PdfiumError> {
+    pub fn render_with_config(
+        &self,
+        config: &PdfRenderConfig,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
         let settings = config.apply_to_page(self);
 
         let mut bitmap = PdfBitmap::empty(
@@ -689,34 +750,7 @@ impl<'a> PdfPage<'a> {
                 settings.render_flags,
             );
 
-            if let Some(form_handle) = self.form_handle {
-                // Render user-supplied form data, if any, as an overlay on top of the page.
-
-                if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
-                    for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
-                        self.bindings.FPDF_SetFormFieldHighlightColor(
-                            form_handle,
-                            *form_field_type,
-                            *color,
-                        );
-
-                        self.bindings
-                            .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
-                    }
-                }
-
-                self.bindings.FPDF_FFLDraw(
-                    form_handle,
-                    bitmap_handle,
-                    self.page_handle,
-                    0,
-                    0,
-                    settings.width,
-                    settings.height,
-                    settings.rotate,
-                    settings.render_flags,
-                );
-            }
+            self.render_form_data_into_bitmap(bitmap_handle, &settings);
         } else {
             // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
 
@@ -734,6 +768,147 @@ impl<'a> PdfPage<'a> {
         Ok(())
     }
 
+    /// Renders user-supplied form data, if any, into the given bitmap as an overlay on top
+    /// of the page content.
+    fn render_form_data_into_bitmap(
+        &self,
+        bitmap_handle: FPDF_BITMAP,
+        settings: &PdfRenderSettings,
+    ) {
+        if let Some(form_handle) = self.form_handle {
+            if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
+                // Clear any highlighting applied during an earlier render, so that only
+                // the highlight colors in the current settings take effect.
+
+                self.bindings.FPDF_RemoveFormFieldHighlight(form_handle);
+
+                for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
+                    self.bindings.FPDF_SetFormFieldHighlightColor(
+                        form_handle,
+                        *form_field_type,
+                        *color,
+                    );
+
+                    self.bindings
+                        .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
+                }
+            }
+
+            self.bindings.FPDF_FFLDraw(
+                form_handle,
+                bitmap_handle,
+                self.page_handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+            );
+        }
+    }
+
+    /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, page rotation settings,
+    /// and rendering options configured in the given [PdfRenderConfig], yielding control back to
+    /// the given `should_continue` callback periodically while rendering is in progress.
+    ///
+    /// If the callback returns `false`, rendering is abandoned and
+    /// [PdfiumError::RenderCancelled] is returned. This allows long-running renders of complex
+    /// pages to be cancelled, for instance when the user scrolls the page out of view.
+    ///
+    /// Progressive rendering always renders the whole page at the target dimensions and rotation;
+    /// any custom transformation matrix or clipping rectangle set in the given [PdfRenderConfig]
+    /// is ignored.
+    ///
+    /// When compiling to WASM, Pdfium cannot pause rendering, so the page will be rendered
+    /// in its entirety without the callback being invoked.
+    pub fn render_progressive(
+        &self,
+        config: &PdfRenderConfig,
+        mut should_continue: impl FnMut() -> bool,
+    ) -> Result<PdfBitmap, PdfiumError> {
+        // Asks Pdfium to yield after every rendering step, so that we can check the
+        // caller's callback as often as possible.
+
+        extern "C" fn need_to_pause_now(_pause: *mut IFSDK_PAUSE) -> FPDF_BOOL {
+            1
+        }
+
+        let settings = config.apply_to_page(self);
+
+        let mut bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::from_pdfium(settings.format as u32)
+                .unwrap_or_else(|_| PdfBitmapFormat::default()),
+            self.bindings,
+        )?;
+
+        let bitmap_handle = *bitmap.handle();
+
+        if settings.do_clear_bitmap_before_rendering {
+            // Clear the bitmap buffer by setting every pixel to a known color.
+
+            self.bindings().FPDFBitmap_FillRect(
+                bitmap_handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.clear_color,
+            );
+        }
+
+        let mut pause = IFSDK_PAUSE {
+            version: 1,
+            NeedToPauseNow: Some(need_to_pause_now),
+            user: std::ptr::null_mut(),
+        };
+
+        let mut status = self.bindings.FPDF_RenderPageBitmap_Start(
+            bitmap_handle,
+            self.page_handle,
+            0,
+            0,
+            settings.width,
+            settings.height,
+            settings.rotate,
+            settings.render_flags,
+            &mut pause,
+        );
+
+        while status == FPDF_RENDER_TOBECONTINUED as c_int {
+            if !should_continue() {
+                // Pdfium's progressive renderer holds a reference to the bitmap, so we must
+                // release it before the bitmap is dropped.
+
+                self.bindings.FPDF_RenderPage_Close(self.page_handle);
+
+                return Err(PdfiumError::RenderCancelled);
+            }
+
+            status = self
+                .bindings
+                .FPDF_RenderPage_Continue(self.page_handle, &mut pause);
+        }
+
+        self.bindings.FPDF_RenderPage_Close(self.page_handle);
+
+        if status == FPDF_RENDER_FAILED as c_int {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        if settings.do_render_form_data {
+            self.render_form_data_into_bitmap(bitmap_handle, &settings);
+        }
+
+        bitmap.set_byte_order_from_render_settings(&settings);
+
+        Ok(bitmap)
+    }
+
     // TODO: AJRC - 29/7/22 - remove deprecated PdfPage::get_bitmap_*() functions in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, rotation settings,
@@ -849,21 +1024,7 @@ impl<'a> PdfPage<'a> {
             // transformation to take effect. For more information, see:
             // https://github.com/ajrcarey/pdfium-render/issues/93
 
-            if let Some(page_index) =
-                PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
-            {
-                self.drop_impl();
-
-                self.page_handle = self
-                    .bindings
-                    .FPDF_LoadPage(self.document_handle, page_index as c_int);
-
-                PdfPageIndexCache::set_index_for_page(
-                    self.document_handle,
-                    self.page_handle,
-                    page_index,
-                );
-            }
+            self.reload();
 
             Ok(())
         } else {
@@ -873,6 +1034,47 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Clips the content of this [PdfPage] to the given rectangle, expressed in page
+    /// coordinates. Only the parts of the page's objects that lie inside the rectangle
+    /// will be painted. Calling this function more than once intersects the new clipping
+    /// rectangle with any existing clipping region.
+    ///
+    /// Pdfium writes the clipping rectangle directly into the page's content stream rather than
+    /// attaching it to individual page objects. Any pending content regeneration is therefore
+    /// performed first, irrespective of this page's content regeneration strategy, and the page
+    /// is then reloaded so that its objects pick up the new clipping region. The clipping region
+    /// of each object can be inspected afterwards using the
+    /// `PdfPageObjectCommon::clip_paths()` function.
+    ///
+    /// Because the page is reloaded, any `PdfPageObject` or `PdfPageAnnotation` previously
+    /// retrieved from this [PdfPage] must not be used after calling this function. To ensure
+    /// an object is clipped, add it to the page before calling this function; whether objects
+    /// added afterwards are clipped depends on how Pdfium regenerates the page's content.
+    pub fn insert_clip_rect(&mut self, rect: PdfRect) -> Result<(), PdfiumError> {
+        self.regenerate_content()?;
+
+        let clip_path = self.bindings.FPDF_CreateClipPath(
+            rect.left.value,
+            rect.bottom.value,
+            rect.right.value,
+            rect.top.value,
+        );
+
+        if clip_path.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        self.bindings
+            .FPDFPage_InsertClipPath(self.page_handle, clip_path);
+        self.bindings.FPDF_DestroyClipPath(clip_path);
+
+        self.reload();
+
+        Ok(())
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -905,26 +1107,104 @@ impl<'a> PdfPage<'a> {
         unreachable!();
     }
 
-    /// Flattens all annotations and form fields on this [PdfPage] into the page contents.
-    pub fn flatten(&mut self) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 28/5/22 - consider allowing the caller to set the FLAT_NORMALDISPLAY or FLAT_PRINT flag.
-        let flag = FLAT_PRINT;
-
+    /// Flattens all annotations and form fields on this [PdfPage] into the page contents,
+    /// so that their appearances are displayed even by viewers that do not render annotations
+    /// or form fields. The given [PdfFlattenFlag] controls whether the appearances used for
+    /// on-screen display or for printing are flattened.
+    ///
+    /// Returns [PdfFlattenResult::Flattened] if flattening succeeded,
+    /// [PdfFlattenResult::NothingToFlatten] if there was nothing on the page to flatten,
+    /// and [PdfiumError::PageFlattenFailure] if Pdfium reported that flattening failed.
+    ///
+    /// The flattened annotations are removed from the page immediately, but Pdfium does not
+    /// reload the page's objects from the updated page contents. Save and reload the document
+    /// to render, or otherwise inspect, the flattened appearances as page objects.
+    pub fn flatten(&mut self, flag: PdfFlattenFlag) -> Result<PdfFlattenResult, PdfiumError> {
         match self
             .bindings()
-            .FPDFPage_Flatten(self.page_handle, flag as c_int) as u32
+            .FPDFPage_Flatten(self.page_handle, flag.as_pdfium()) as u32
         {
             FLATTEN_SUCCESS => {
                 self.is_content_regeneration_required = true;
 
                 self.regenerate_content()
+                    .map(|()| PdfFlattenResult::Flattened)
             }
-            FLATTEN_NOTHINGTODO => Ok(()),
+            FLATTEN_NOTHINGTODO => Ok(PdfFlattenResult::NothingToFlatten),
             FLATTEN_FAIL => Err(PdfiumError::PageFlattenFailure),
             _ => Err(PdfiumError::PageFlattenFailure),
         }
     }
 
+    /// Applies every redaction annotation on this [PdfPage], permanently removing all
+    /// page objects - text, images, paths, and so on - that intersect any region marked
+    /// for redaction.
+    ///
+    /// Page objects that only partially intersect a redacted region are removed in their
+    /// entirety. Once the page objects have been removed, each redacted region is filled with
+    /// its redaction annotation's interior color, if any, the redaction annotations themselves
+    /// (and any popups attached to them) are removed from the page, and the page's content
+    /// is regenerated.
+    ///
+    /// Returns the number of page objects that were removed.
+    pub fn apply_redactions(&mut self) -> Result<usize, PdfiumError> {
+        let mut regions = Vec::new();
+
+        for annotation in self.annotations().iter() {
+            if let PdfPageAnnotation::Redacted(annotation) = annotation {
+                let fill_color = annotation.interior_color()?;
+
+                for region in annotation.regions()? {
+                    regions.push((region, fill_color));
+                }
+            }
+        }
+
+        if regions.is_empty() {
+            return Ok(0);
+        }
+
+        // Remove intersecting page objects in reverse order, so that removing an object
+        // does not shift the index of any object we have yet to visit.
+
+        let mut removed = 0;
+
+        for index in self.objects().as_range().rev() {
+            let bounds = self.objects().get(index)?.bounds()?;
+
+            if regions
+                .iter()
+                .any(|(region, _)| bounds.does_overlap(region))
+            {
+                self.objects_mut().remove_object_at_index(index)?;
+
+                removed += 1;
+            }
+        }
+
+        for (region, fill_color) in regions {
+            if let Some(fill_color) = fill_color {
+                self.objects_mut()
+                    .create_path_object_rect(region, None, None, Some(fill_color))?;
+            }
+        }
+
+        // Removing a redaction annotation along with its popup may shift the index of other
+        // redaction annotations, so we search for the next one afresh each time.
+
+        while let Some(index) = self
+            .annotations()
+            .iter()
+            .position(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Redacted)
+        {
+            let annotation = self.annotations().get(index)?;
+
+            self.annotations_mut().delete_with_popup(annotation)?;
+        }
+
+        self.regenerate_content().map(|()| removed)
+    }
+
     /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
     pub fn delete(self) -> Result<(), PdfiumError> {
         let index = PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
@@ -1025,6 +1305,41 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Closes and reloads this [PdfPage] so that Pdfium re-parses the page's content stream,
+    /// recreating all child containers against the reloaded page.
+    fn reload(&mut self) {
+        if let Some(page_index) =
+            PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
+        {
+            self.drop_impl();
+
+            self.page_handle = self
+                .bindings
+                .FPDF_LoadPage(self.document_handle, page_index as c_int);
+
+            PdfPageIndexCache::set_index_for_page(
+                self.document_handle,
+                self.page_handle,
+                page_index,
+            );
+
+            self.is_content_regeneration_required = false;
+            self.annotations = PdfPageAnnotations::from_pdfium(
+                self.document_handle,
+                self.page_handle,
+                self.form_handle,
+                self.bindings,
+            );
+            self.boundaries = PdfPageBoundaries::from_pdfium(self.page_handle, self.bindings);
+            self.links =
+                PdfPageLinks::from_pdfium(self.page_handle, self.document_handle, self.bindings);
+            self.objects =
+                PdfPageObjects::from_pdfium(self.page_handle, self.document_handle, self.bindings);
+
+            self.set_content_regeneration_strategy(self.regeneration_strategy);
+        }
+    }
+
     fn drop_impl(&mut self) {
         if self.regeneration_strategy != PdfPageContentRegenerationStrategy::Manual
             && self.is_content_regeneration_required
@@ -1054,7 +1369,9 @@ impl<'a> Drop for PdfPage<'a> {
 mod tests {
     use crate::bitmap::{PdfBitmap, PdfBitmapFormat};
     use crate::error::PdfiumError;
-    use crate::page::PdfPageRenderRotation;
+    use crate::page::{PdfFlattenFlag, PdfPageRenderRotation};
+    use crate::page_annotation::PdfPageAnnotationType;
+    use crate::prelude::*;
     use crate::render_config::PdfRenderConfig;
     use crate::utils::test::test_bind_to_pdfium;
     use image::GenericImageView;
@@ -1113,4 +1430,483 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_render_clipped_to_rect() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        // Returns the RGBA color of the pixel at the given coordinates in the given bitmap.
+
+        let pixel = |bitmap: &PdfBitmap, x: Pixels, y: Pixels| {
+            let offset = ((y * bitmap.width() + x) * 4) as usize;
+
+            bitmap.as_rgba_bytes()[offset..offset + 4].to_vec()
+        };
+
+        let red = vec![255, 0, 0, 255];
+
+        let white = vec![255, 255, 255, 255];
+
+        // A rectangle entirely inside the filled square should render as solid red,
+        // into a bitmap sized to fit the rectangle.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .clip_to_rect(PdfRect::new_from_values(120.0, 120.0, 180.0, 180.0)),
+        )?;
+
+        assert_eq!(bitmap.width(), 60);
+        assert_eq!(bitmap.height(), 60);
+        assert!(bitmap
+            .as_rgba_bytes()
+            .chunks_exact(4)
+            .all(|pixel| pixel == red.as_slice()));
+
+        // A rectangle overlapping the top left corner of the filled square should render
+        // white above and left of the square, and red inside it. The target width applies
+        // to the rectangle rather than to the page.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .clip_to_rect(PdfRect::new_from_values(150.0, 50.0, 250.0, 150.0))
+                .set_target_width(200),
+        )?;
+
+        assert_eq!(bitmap.width(), 200);
+        assert_eq!(bitmap.height(), 200);
+        assert_eq!(pixel(&bitmap, 50, 50), white);
+        assert_eq!(pixel(&bitmap, 150, 50), white);
+        assert_eq!(pixel(&bitmap, 50, 150), white);
+        assert_eq!(pixel(&bitmap, 150, 150), red);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_clip_rect() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        assert!(page.objects().get(0)?.clip_paths()?.is_empty());
+
+        page.insert_clip_rect(PdfRect::new_from_values(50.0, 50.0, 150.0, 100.0))?;
+
+        // The reloaded object should now carry the clipping rectangle.
+
+        assert_eq!(page.objects().len(), 1);
+        assert_eq!(page.objects().get(0)?.clip_paths()?.len(), 1);
+
+        // Returns the RGBA color of the pixel at the given coordinates in the given bitmap.
+
+        let pixel = |bitmap: &PdfBitmap, x: Pixels, y: Pixels| {
+            let offset = ((y * bitmap.width() + x) * 4) as usize;
+
+            bitmap.as_rgba_bytes()[offset..offset + 4].to_vec()
+        };
+
+        let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_size(200, 200))?;
+
+        // The square covers the whole page, but only the part inside the clipping rectangle
+        // should be painted.
+
+        assert_eq!(pixel(&bitmap, 75, 100), vec![255, 0, 0, 255]);
+        assert_eq!(pixel(&bitmap, 150, 100), vec![255, 255, 255, 255]);
+        assert_eq!(pixel(&bitmap, 75, 20), vec![255, 255, 255, 255]);
+        assert_eq!(pixel(&bitmap, 75, 180), vec![255, 255, 255, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_without_antialiasing() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // A rotated square has diagonal edges, which are smoothed when anti-aliasing is enabled.
+
+        page.objects_mut()
+            .create_path_object_rect(
+                PdfRect::new_from_values(300.0, 200.0, 400.0, 300.0),
+                None,
+                None,
+                Some(PdfColor::RED),
+            )?
+            .rotate_clockwise_degrees(30.0)?;
+
+        let red = [255, 0, 0, 255];
+
+        let white = [255, 255, 255, 255];
+
+        let is_red_or_white = |bitmap: &PdfBitmap| {
+            bitmap
+                .as_rgba_bytes()
+                .chunks_exact(4)
+                .all(|pixel| pixel == red || pixel == white)
+        };
+
+        let bitmap = page.render_with_config(&PdfRenderConfig::new())?;
+
+        assert!(!is_red_or_white(&bitmap));
+
+        let bitmap =
+            page.render_with_config(&PdfRenderConfig::new().disable_path_antialiasing(true))?;
+
+        assert!(is_red_or_white(&bitmap));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_form_field_highlight() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let unhighlighted = page.render_with_config(&PdfRenderConfig::new())?;
+
+        let highlighted = page.render_with_config(
+            &PdfRenderConfig::new().set_form_field_highlight(Some(PdfColor::RED)),
+        )?;
+
+        assert_ne!(unhighlighted.as_rgba_bytes(), highlighted.as_rgba_bytes());
+
+        // Disabling the highlight clears the highlight applied by the previous render.
+
+        let cleared =
+            page.render_with_config(&PdfRenderConfig::new().set_form_field_highlight(None))?;
+
+        assert_eq!(unhighlighted.as_rgba_bytes(), cleared.as_rgba_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_grayscale() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/path-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        // An odd target width ensures each scanline of the grayscale bitmap is padded.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(101)
+                .render_grayscale(true),
+        )?;
+
+        assert_eq!(bitmap.format()?, PdfBitmapFormat::Gray);
+        assert_eq!(
+            bitmap.as_rgba_bytes().len(),
+            (bitmap.width() * bitmap.height()) as usize
+        );
+
+        assert_eq!(
+            bitmap.as_image().as_luma8().map(|image| image.width()),
+            Some(101)
+        );
+
+        // Disabling grayscale rendering restores the default color format.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .render_grayscale(true)
+                .render_grayscale(false),
+        )?;
+
+        assert_eq!(bitmap.format()?, PdfBitmapFormat::BGRA);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedded_thumbnail_missing() -> Result<(), PdfiumError> {
+        // None of the pages in the test PDF file have an embedded thumbnail.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        for page in document.pages().iter() {
+            assert!(!page.has_embedded_thumbnail());
+            assert!(page.embedded_thumbnail()?.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_flattening() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        for mut page in document.pages().iter() {
+            page.flatten(PdfFlattenFlag::Print)?;
+
+            // Once flattened, there is nothing left to flatten.
+
+            assert_eq!(
+                page.flatten(PdfFlattenFlag::NormalDisplay)?,
+                PdfFlattenResult::NothingToFlatten
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flattened_page_renders_identically() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for (bounds, content) in [
+            (
+                PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0),
+                "0 0 1 rg 100 400 200 100 re f",
+            ),
+            (
+                PdfRect::new_from_values(200.0, 300.0, 300.0, 400.0),
+                "1 0 0 RG 4 w 310 210 80 80 re S",
+            ),
+        ]
+        .iter()
+        {
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_bounds(*bounds)?;
+            annotation.set_appearance_stream(PdfAppearanceMode::Normal, Some(content))?;
+        }
+
+        let render_config = PdfRenderConfig::new().set_target_width(page.width().value as Pixels);
+
+        let before = page.render_with_config(&render_config)?.as_rgba_bytes();
+
+        assert_eq!(
+            page.flatten(PdfFlattenFlag::NormalDisplay)?,
+            PdfFlattenResult::Flattened
+        );
+        assert!(page.annotations().is_empty());
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().first()?;
+
+        assert!(page.annotations().is_empty());
+
+        let after = page.render_with_config(&render_config)?.as_rgba_bytes();
+
+        assert!(before == after);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_redactions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The test document contains a single redaction annotation covering the first
+        // of two lines of text.
+
+        let document = pdfium.load_pdf_from_file("./test/redaction-test.pdf", None)?;
+
+        let mut page = document.pages().first()?;
+
+        assert!(page.text()?.all().contains("12345"));
+
+        assert_eq!(page.apply_redactions()?, 1);
+
+        let text = page.text()?.all();
+
+        assert!(!text.contains("12345"));
+        assert!(text.contains("public"));
+        assert!(page
+            .annotations()
+            .iter()
+            .all(|annotation| annotation.annotation_type() != PdfPageAnnotationType::Redacted));
+
+        // Once applied, there is nothing left to redact.
+
+        assert_eq!(page.apply_redactions()?, 0);
+
+        drop(page);
+
+        // The redacted text must not survive a round trip through a saved document.
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let text = document.pages().first()?.text()?.all();
+
+        assert!(!text.contains("12345"));
+        assert!(text.contains("public"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_progressive_rendering() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(500)
+            .set_maximum_height(500);
+
+        let page = document.pages().get(0)?;
+
+        let rendered_page = page.render_progressive(&render_config, || true)?.as_image();
+
+        let (width, _height) = rendered_page.dimensions();
+
+        assert_eq!(width, 500);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_progressive_rendering_cancelled() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Pdfium checks whether to pause after rendering each batch of a hundred page objects,
+        // so a page with several hundred objects is guaranteed to pause at least once.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.set_content_regeneration_strategy(PdfPageContentRegenerationStrategy::Manual);
+
+        for index in 0..500 {
+            let offset = (index % 50) as f32 * 10.0;
+
+            page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(offset, offset, offset + 50.0, offset + 50.0),
+                None,
+                None,
+                Some(PdfColor::new(0, 0, 255, 255)),
+            )?;
+        }
+
+        page.regenerate_content()?;
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(500)
+            .set_maximum_height(500);
+
+        let mut times_asked_to_continue = 0;
+
+        let result = page.render_progressive(&render_config, || {
+            times_asked_to_continue += 1;
+
+            false
+        });
+
+        // Cancelling at the first opportunity should abandon the render immediately.
+
+        assert_eq!(times_asked_to_continue, 1);
+        assert!(matches!(result, Err(PdfiumError::RenderCancelled)));
+
+        // The page must still be renderable after a cancelled progressive render.
+
+        page.render_with_config(&render_config)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_structure_tree() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/structure-tree-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let tree = page.structure_tree()?;
+
+        assert_eq!(tree.len(), 1);
+
+        let root = tree.get(0)?;
+
+        assert_eq!(root.element_type().as_deref(), Some("Document"));
+        assert_eq!(root.marked_content_id(), None);
+        assert_eq!(root.len(), 3);
+
+        let children = root.children().collect::<Vec<_>>();
+
+        assert_eq!(
+            children
+                .iter()
+                .map(|element| element.element_type().unwrap_or_default())
+                .collect::<Vec<_>>(),
+            vec!["H1", "P", "P"]
+        );
+        assert_eq!(
+            children
+                .iter()
+                .map(|element| element.marked_content_id())
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(0)]
+        );
+
+        // Untagged documents have no structure tree.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert!(matches!(
+            page.structure_tree(),
+            Err(PdfiumError::NoStructureTree)
+        ));
+
+        Ok(())
+    }
 }
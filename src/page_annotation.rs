@@ -1,25 +1,35 @@
 //! Defines the [PdfPageAnnotation] struct, exposing functionality related to a single annotation.
 
+use crate::appearance_mode::PdfAppearanceMode;
 use crate::bindgen::{
     FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_CARET, FPDF_ANNOT_CIRCLE,
-    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
+    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE,
+    FPDF_ANNOT_FLAG_LOCKED, FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW,
+    FPDF_ANNOT_FLAG_NOZOOM, FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY,
+    FPDF_ANNOT_FLAG_TOGGLENOVIEW, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
     FPDF_ANNOT_LINE, FPDF_ANNOT_LINK, FPDF_ANNOT_MOVIE, FPDF_ANNOT_POLYGON, FPDF_ANNOT_POLYLINE,
     FPDF_ANNOT_POPUP, FPDF_ANNOT_PRINTERMARK, FPDF_ANNOT_REDACT, FPDF_ANNOT_RICHMEDIA,
     FPDF_ANNOT_SCREEN, FPDF_ANNOT_SOUND, FPDF_ANNOT_SQUARE, FPDF_ANNOT_SQUIGGLY, FPDF_ANNOT_STAMP,
     FPDF_ANNOT_STRIKEOUT, FPDF_ANNOT_TEXT, FPDF_ANNOT_THREED, FPDF_ANNOT_TRAPNET,
     FPDF_ANNOT_UNDERLINE, FPDF_ANNOT_UNKNOWN, FPDF_ANNOT_WATERMARK, FPDF_ANNOT_WIDGET,
-    FPDF_ANNOT_XFAWIDGET, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE,
+    FPDF_ANNOT_XFAWIDGET, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_OBJECT_ARRAY, FPDF_OBJECT_BOOLEAN,
+    FPDF_OBJECT_DICTIONARY, FPDF_OBJECT_NAME, FPDF_OBJECT_NULLOBJ, FPDF_OBJECT_NUMBER,
+    FPDF_OBJECT_REFERENCE, FPDF_OBJECT_STREAM, FPDF_OBJECT_STRING, FPDF_OBJECT_TYPE, FPDF_PAGE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::color::PdfColor;
 use crate::error::PdfiumError;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_circle::PdfPageCircleAnnotation;
+use crate::page_annotation_file_attachment::PdfPageFileAttachmentAnnotation;
 use crate::page_annotation_free_text::PdfPageFreeTextAnnotation;
 use crate::page_annotation_highlight::PdfPageHighlightAnnotation;
 use crate::page_annotation_ink::PdfPageInkAnnotation;
+use crate::page_annotation_line::PdfPageLineAnnotation;
 use crate::page_annotation_link::PdfPageLinkAnnotation;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
+use crate::page_annotation_polygon::PdfPagePolygonAnnotation;
+use crate::page_annotation_polyline::PdfPagePolylineAnnotation;
 use crate::page_annotation_popup::PdfPagePopupAnnotation;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 use crate::page_annotation_redacted::PdfPageRedactedAnnotation;
@@ -35,18 +45,136 @@ use crate::page_annotation_xfa_widget::PdfPageXfaWidgetAnnotation;
 use crate::points::PdfPoints;
 use crate::prelude::PdfFormField;
 use crate::rect::PdfRect;
+use bitflags::bitflags;
 use chrono::prelude::*;
 
+bitflags! {
+    /// The flags controlling the visibility and behaviour of a single [PdfPageAnnotation],
+    /// as defined in table 8.16 of the PDF Reference, version 1.7, on page 608.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct PdfAnnotationFlags: u32 {
+        /// Do not display the annotation if it uses an unsupported annotation type.
+        const Invisible = FPDF_ANNOT_FLAG_INVISIBLE;
+
+        /// Do not display or print the annotation, regardless of its annotation type.
+        const Hidden = FPDF_ANNOT_FLAG_HIDDEN;
+
+        /// Print the annotation when the page is printed.
+        const Print = FPDF_ANNOT_FLAG_PRINT;
+
+        /// Do not scale the annotation's appearance to match the magnification of the page.
+        const NoZoom = FPDF_ANNOT_FLAG_NOZOOM;
+
+        /// Do not rotate the annotation's appearance to match the rotation of the page.
+        const NoRotate = FPDF_ANNOT_FLAG_NOROTATE;
+
+        /// Do not display the annotation on screen, but allow it to be printed.
+        const NoView = FPDF_ANNOT_FLAG_NOVIEW;
+
+        /// Do not allow the user to interact with the annotation.
+        const ReadOnly = FPDF_ANNOT_FLAG_READONLY;
+
+        /// Do not allow the annotation to be deleted or its properties to be modified.
+        const Locked = FPDF_ANNOT_FLAG_LOCKED;
+
+        /// Invert the interpretation of the `NoView` flag for certain events.
+        const ToggleNoView = FPDF_ANNOT_FLAG_TOGGLENOVIEW;
+
+        /// Do not allow the contents of the annotation to be modified by the user.
+        const LockedContents = 512;
+    }
+}
+
+/// The border drawn around a single [PdfPageAnnotation], as defined by the /Border entry
+/// in table 8.15 of the PDF Reference, version 1.7, on page 606.
+///
+/// Pdfium does not support reading or setting dash patterns for annotation borders.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfAnnotationBorder {
+    /// The horizontal corner radius of the border.
+    pub horizontal_radius: PdfPoints,
+
+    /// The vertical corner radius of the border.
+    pub vertical_radius: PdfPoints,
+
+    /// The width of the border. A width of zero means no border is drawn.
+    pub width: PdfPoints,
+}
+
+impl PdfAnnotationBorder {
+    /// The border applied to annotations that do not explicitly specify a border:
+    /// square corners and a solid border one point wide.
+    pub const DEFAULT: PdfAnnotationBorder =
+        PdfAnnotationBorder::new(PdfPoints::ZERO, PdfPoints::ZERO, PdfPoints::new(1.0));
+
+    /// A border with square corners and zero width, i.e. no visible border at all.
+    pub const NONE: PdfAnnotationBorder =
+        PdfAnnotationBorder::new(PdfPoints::ZERO, PdfPoints::ZERO, PdfPoints::ZERO);
+
+    /// Creates a new [PdfAnnotationBorder] from the given values.
+    #[inline]
+    pub const fn new(
+        horizontal_radius: PdfPoints,
+        vertical_radius: PdfPoints,
+        width: PdfPoints,
+    ) -> Self {
+        PdfAnnotationBorder {
+            horizontal_radius,
+            vertical_radius,
+            width,
+        }
+    }
+}
+
+impl Default for PdfAnnotationBorder {
+    #[inline]
+    fn default() -> Self {
+        PdfAnnotationBorder::DEFAULT
+    }
+}
+
+/// The type of a value stored in the annotation dictionary of a single [PdfPageAnnotation],
+/// as defined in section 3.2 of the PDF Reference, version 1.7, on page 51.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfAnnotationValueType {
+    Boolean,
+    Number,
+    String,
+    Name,
+    Array,
+    Dictionary,
+    Stream,
+    Null,
+    Reference,
+}
+
+impl PdfAnnotationValueType {
+    pub(crate) fn from_pdfium(value: FPDF_OBJECT_TYPE) -> Option<PdfAnnotationValueType> {
+        match value as u32 {
+            FPDF_OBJECT_BOOLEAN => Some(PdfAnnotationValueType::Boolean),
+            FPDF_OBJECT_NUMBER => Some(PdfAnnotationValueType::Number),
+            FPDF_OBJECT_STRING => Some(PdfAnnotationValueType::String),
+            FPDF_OBJECT_NAME => Some(PdfAnnotationValueType::Name),
+            FPDF_OBJECT_ARRAY => Some(PdfAnnotationValueType::Array),
+            FPDF_OBJECT_DICTIONARY => Some(PdfAnnotationValueType::Dictionary),
+            FPDF_OBJECT_STREAM => Some(PdfAnnotationValueType::Stream),
+            FPDF_OBJECT_NULLOBJ => Some(PdfAnnotationValueType::Null),
+            FPDF_OBJECT_REFERENCE => Some(PdfAnnotationValueType::Reference),
+            _ => None,
+        }
+    }
+}
+
 /// The type of a single [PdfPageAnnotation], as defined in table 8.20 of the PDF Reference,
 /// version 1.7, on page 615.
 ///
 /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-/// currently support embedded sound or movie file annotations, embedded 3D animations, or
-/// annotations containing embedded file attachments.
+/// currently support embedded sound or movie file annotations or embedded 3D animations.
 ///
 /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
 ///
 /// * [PdfPageAnnotationType::Circle]
+/// * [PdfPageAnnotationType::FileAttachment]
 /// * [PdfPageAnnotationType::FreeText]
 /// * [PdfPageAnnotationType::Highlight]
 /// * [PdfPageAnnotationType::Ink]
@@ -176,10 +304,14 @@ impl PdfPageAnnotationType {
 /// A single user annotation on a `PdfPage`.
 pub enum PdfPageAnnotation<'a> {
     Circle(PdfPageCircleAnnotation<'a>),
+    FileAttachment(PdfPageFileAttachmentAnnotation<'a>),
     FreeText(PdfPageFreeTextAnnotation<'a>),
     Highlight(PdfPageHighlightAnnotation<'a>),
     Ink(PdfPageInkAnnotation<'a>),
+    Line(PdfPageLineAnnotation<'a>),
     Link(PdfPageLinkAnnotation<'a>),
+    Polygon(PdfPagePolygonAnnotation<'a>),
+    Polyline(PdfPagePolylineAnnotation<'a>),
     Popup(PdfPagePopupAnnotation<'a>),
     Square(PdfPageSquareAnnotation<'a>),
     Squiggly(PdfPageSquigglyAnnotation<'a>),
@@ -218,6 +350,14 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::FileAttachment => {
+                PdfPageAnnotation::FileAttachment(PdfPageFileAttachmentAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::FreeText => {
                 PdfPageAnnotation::FreeText(PdfPageFreeTextAnnotation::from_pdfium(
                     document_handle,
@@ -242,6 +382,14 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::Line => {
+                PdfPageAnnotation::Line(PdfPageLineAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::Link => {
                 PdfPageAnnotation::Link(PdfPageLinkAnnotation::from_pdfium(
                     document_handle,
@@ -250,6 +398,22 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::Polygon => {
+                PdfPageAnnotation::Polygon(PdfPagePolygonAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
+            PdfPageAnnotationType::Polyline => {
+                PdfPageAnnotation::Polyline(PdfPagePolylineAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::Popup => {
                 PdfPageAnnotation::Popup(PdfPagePopupAnnotation::from_pdfium(
                     document_handle,
@@ -346,10 +510,14 @@ impl<'a> PdfPageAnnotation<'a> {
     pub(crate) fn unwrap_as_trait(&self) -> &dyn PdfPageAnnotationPrivate<'a> {
         match self {
             PdfPageAnnotation::Circle(annotation) => annotation,
+            PdfPageAnnotation::FileAttachment(annotation) => annotation,
             PdfPageAnnotation::FreeText(annotation) => annotation,
             PdfPageAnnotation::Highlight(annotation) => annotation,
             PdfPageAnnotation::Ink(annotation) => annotation,
+            PdfPageAnnotation::Line(annotation) => annotation,
             PdfPageAnnotation::Link(annotation) => annotation,
+            PdfPageAnnotation::Polygon(annotation) => annotation,
+            PdfPageAnnotation::Polyline(annotation) => annotation,
             PdfPageAnnotation::Popup(annotation) => annotation,
             PdfPageAnnotation::Square(annotation) => annotation,
             PdfPageAnnotation::Squiggly(annotation) => annotation,
@@ -368,10 +536,14 @@ impl<'a> PdfPageAnnotation<'a> {
     pub(crate) fn unwrap_as_trait_mut(&mut self) -> &mut dyn PdfPageAnnotationPrivate<'a> {
         match self {
             PdfPageAnnotation::Circle(annotation) => annotation,
+            PdfPageAnnotation::FileAttachment(annotation) => annotation,
             PdfPageAnnotation::FreeText(annotation) => annotation,
             PdfPageAnnotation::Highlight(annotation) => annotation,
             PdfPageAnnotation::Ink(annotation) => annotation,
+            PdfPageAnnotation::Line(annotation) => annotation,
             PdfPageAnnotation::Link(annotation) => annotation,
+            PdfPageAnnotation::Polygon(annotation) => annotation,
+            PdfPageAnnotation::Polyline(annotation) => annotation,
             PdfPageAnnotation::Popup(annotation) => annotation,
             PdfPageAnnotation::Square(annotation) => annotation,
             PdfPageAnnotation::Squiggly(annotation) => annotation,
@@ -389,12 +561,12 @@ impl<'a> PdfPageAnnotation<'a> {
     /// The type of this [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
     ///
     /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
     ///
     /// * [PdfPageAnnotationType::Circle]
+    /// * [PdfPageAnnotationType::FileAttachment]
     /// * [PdfPageAnnotationType::FreeText]
     /// * [PdfPageAnnotationType::Highlight]
     /// * [PdfPageAnnotationType::Ink]
@@ -413,10 +585,14 @@ impl<'a> PdfPageAnnotation<'a> {
     pub fn annotation_type(&self) -> PdfPageAnnotationType {
         match self {
             PdfPageAnnotation::Circle(_) => PdfPageAnnotationType::Circle,
+            PdfPageAnnotation::FileAttachment(_) => PdfPageAnnotationType::FileAttachment,
             PdfPageAnnotation::FreeText(_) => PdfPageAnnotationType::FreeText,
             PdfPageAnnotation::Highlight(_) => PdfPageAnnotationType::Highlight,
             PdfPageAnnotation::Ink(_) => PdfPageAnnotationType::Ink,
+            PdfPageAnnotation::Line(_) => PdfPageAnnotationType::Line,
             PdfPageAnnotation::Link(_) => PdfPageAnnotationType::Link,
+            PdfPageAnnotation::Polygon(_) => PdfPageAnnotationType::Polygon,
+            PdfPageAnnotation::Polyline(_) => PdfPageAnnotationType::Polyline,
             PdfPageAnnotation::Popup(_) => PdfPageAnnotationType::Popup,
             PdfPageAnnotation::Square(_) => PdfPageAnnotationType::Square,
             PdfPageAnnotation::Squiggly(_) => PdfPageAnnotationType::Squiggly,
@@ -435,12 +611,12 @@ impl<'a> PdfPageAnnotation<'a> {
     /// [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
     ///
     /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
     ///
     /// * [PdfPageAnnotationType::Circle]
+    /// * [PdfPageAnnotationType::FileAttachment]
     /// * [PdfPageAnnotationType::FreeText]
     /// * [PdfPageAnnotationType::Highlight]
     /// * [PdfPageAnnotationType::Ink]
@@ -464,12 +640,12 @@ impl<'a> PdfPageAnnotation<'a> {
     /// [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
     ///
     /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
     ///
     /// * [PdfPageAnnotationType::Circle]
+    /// * [PdfPageAnnotationType::FileAttachment]
     /// * [PdfPageAnnotationType::FreeText]
     /// * [PdfPageAnnotationType::Highlight]
     /// * [PdfPageAnnotationType::Ink]
@@ -511,6 +687,30 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPageFileAttachmentAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::FileAttachment].
+    #[inline]
+    pub fn as_file_attachment_annotation(&self) -> Option<&PdfPageFileAttachmentAnnotation> {
+        match self {
+            PdfPageAnnotation::FileAttachment(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPageFileAttachmentAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::FileAttachment].
+    #[inline]
+    pub fn as_file_attachment_annotation_mut(
+        &mut self,
+    ) -> Option<&mut PdfPageFileAttachmentAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::FileAttachment(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPageFreeTextAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::FreeText].
@@ -577,6 +777,28 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPageLineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Line].
+    #[inline]
+    pub fn as_line_annotation(&self) -> Option<&PdfPageLineAnnotation> {
+        match self {
+            PdfPageAnnotation::Line(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPageLineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Line].
+    #[inline]
+    pub fn as_line_annotation_mut(&mut self) -> Option<&mut PdfPageLineAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Line(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPageLinkAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::Link].
@@ -599,6 +821,50 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPagePolygonAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polygon].
+    #[inline]
+    pub fn as_polygon_annotation(&self) -> Option<&PdfPagePolygonAnnotation> {
+        match self {
+            PdfPageAnnotation::Polygon(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPagePolygonAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polygon].
+    #[inline]
+    pub fn as_polygon_annotation_mut(&mut self) -> Option<&mut PdfPagePolygonAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Polygon(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns an immutable reference to the underlying [PdfPagePolylineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polyline].
+    #[inline]
+    pub fn as_polyline_annotation(&self) -> Option<&PdfPagePolylineAnnotation> {
+        match self {
+            PdfPageAnnotation::Polyline(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPagePolylineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polyline].
+    #[inline]
+    pub fn as_polyline_annotation_mut(&mut self) -> Option<&mut PdfPagePolylineAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Polyline(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPagePopupAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::Popup].
@@ -854,6 +1120,13 @@ pub trait PdfPageAnnotationCommon {
     /// this annotation among all the annotations attached to the containing page.
     fn name(&self) -> Option<String>;
 
+    /// Sets the name of this [PdfPageAnnotation], stored in the annotation's /NM entry.
+    ///
+    /// The PDF specification expects the name to be unique among all the annotations on
+    /// the containing page, but does not enforce this, and neither does this function.
+    /// An empty name is rejected with [PdfiumError::EmptyAnnotationName].
+    fn set_name(&mut self, name: &str) -> Result<(), PdfiumError>;
+
     /// Returns `true` if this [PdfPageAnnotation] supports applying text markup to the page
     /// by setting the annotation contents using the [PdfPageAnnotationCommon::set_contents()]
     /// function.
@@ -863,6 +1136,21 @@ pub trait PdfPageAnnotationCommon {
     /// visually associate it with a `PdfPageObject`.
     fn has_attachment_points(&self) -> bool;
 
+    /// Returns the popup annotation associated with this [PdfPageAnnotation], if any.
+    /// A popup annotation displays the contents of its parent markup annotation in a
+    /// separate window, such as a comment bubble.
+    ///
+    /// Returns `None` if this [PdfPageAnnotation] is not a markup annotation, or if it
+    /// has no associated popup.
+    fn popup(&self) -> Option<PdfPageAnnotation>;
+
+    /// Returns the annotation this [PdfPageAnnotation] was written in reply to, if any.
+    /// Replies form threaded discussions in the comment lists of PDF viewers.
+    ///
+    /// The annotation being replied to cannot be set; see
+    /// [PdfPageAnnotationCommon::set_string_value()].
+    fn in_reply_to(&self) -> Option<PdfPageAnnotation>;
+
     /// Returns the bounding box of this [PdfPageAnnotation].
     fn bounds(&self) -> Result<PdfRect, PdfiumError>;
 
@@ -902,18 +1190,53 @@ pub trait PdfPageAnnotationCommon {
     /// form for providing accessibility to users with disabilities or for other purposes.
     fn set_contents(&mut self, contents: &str) -> Result<(), PdfiumError>;
 
+    // TODO: remove deprecated PdfPageAnnotationCommon::creator() function in 0.9.0
+    // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Returns the name of the creator of this [PdfPageAnnotation], if any.
+    #[deprecated(
+        since = "0.8.18",
+        note = "This function has been renamed. Use the PdfPageAnnotationCommon::author() function instead."
+    )]
+    #[doc(hidden)]
     fn creator(&self) -> Option<String>;
 
+    /// Returns the name of the author of this [PdfPageAnnotation], if any.
+    fn author(&self) -> Option<String>;
+
+    /// Sets the name of the author of this [PdfPageAnnotation].
+    fn set_author(&mut self, author: &str) -> Result<(), PdfiumError>;
+
+    /// Returns the subject of this [PdfPageAnnotation], if any. The subject is a short
+    /// description of the annotation's purpose, typically shown in the comment lists
+    /// of PDF viewers.
+    fn subject(&self) -> Option<String>;
+
+    /// Sets the subject of this [PdfPageAnnotation].
+    fn set_subject(&mut self, subject: &str) -> Result<(), PdfiumError>;
+
     /// Returns the date and time when this [PdfPageAnnotation] was originally created, if any.
     fn creation_date(&self) -> Option<String>;
 
+    /// Returns the date and time when this [PdfPageAnnotation] was originally created, if any,
+    /// parsed from the annotation's PDF date string.
+    ///
+    /// Returns [PdfiumError::ParsePdfDateStringError] if the annotation's creation date
+    /// is not a valid PDF date string.
+    fn creation_date_time(&self) -> Result<Option<DateTime<FixedOffset>>, PdfiumError>;
+
     /// Sets the date and time when this [PdfPageAnnotation] was originally created.
     fn set_creation_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError>;
 
     /// Returns the date and time when this [PdfPageAnnotation] was last modified, if any.
     fn modification_date(&self) -> Option<String>;
 
+    /// Returns the date and time when this [PdfPageAnnotation] was last modified, if any,
+    /// parsed from the annotation's PDF date string.
+    ///
+    /// Returns [PdfiumError::ParsePdfDateStringError] if the annotation's modification date
+    /// is not a valid PDF date string.
+    fn modification_date_time(&self) -> Result<Option<DateTime<FixedOffset>>, PdfiumError>;
+
     /// Sets the date and time when this [PdfPageAnnotation] was last modified.
     fn set_modification_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError>;
 
@@ -929,6 +1252,91 @@ pub trait PdfPageAnnotationCommon {
     /// Sets the color of any stroked paths in this [PdfPageAnnotation].
     fn set_stroke_color(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError>;
 
+    /// Returns the constant opacity of this [PdfPageAnnotation], in the range `0.0..=1.0`.
+    ///
+    /// If the annotation does not explicitly specify an opacity, the PDF default of `1.0`
+    /// (fully opaque) is returned.
+    fn opacity(&self) -> Result<f32, PdfiumError>;
+
+    /// Sets the constant opacity of this [PdfPageAnnotation]. Values outside the range
+    /// `0.0..=1.0` are clamped to that range; a value of `NaN` is rejected with
+    /// [PdfiumError::AnnotationOpacityIsNaN].
+    ///
+    /// Pdfium stores the opacity with a precision of 1/255. Pdfium cannot change the opacity
+    /// of an annotation that already has an appearance stream; in that case, an error is returned.
+    fn set_opacity(&mut self, opacity: f32) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfAnnotationBorder] drawn around this [PdfPageAnnotation].
+    ///
+    /// If the annotation does not explicitly specify a border, the PDF default of
+    /// [PdfAnnotationBorder::DEFAULT] is returned.
+    fn border(&self) -> PdfAnnotationBorder;
+
+    /// Sets the [PdfAnnotationBorder] drawn around this [PdfPageAnnotation].
+    /// Setting a border with a zero width removes any visible border.
+    fn set_border(&mut self, border: PdfAnnotationBorder) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfAnnotationFlags] currently set for this [PdfPageAnnotation].
+    fn flags(&self) -> PdfAnnotationFlags;
+
+    /// Replaces the [PdfAnnotationFlags] set for this [PdfPageAnnotation] with the given flags.
+    ///
+    /// Pdfium will not render an annotation that has the [PdfAnnotationFlags::Hidden] flag set.
+    fn set_flags(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError>;
+
+    /// Returns `true` if the annotation dictionary of this [PdfPageAnnotation] contains
+    /// the given key.
+    fn has_key(&self, key: &str) -> bool;
+
+    /// Returns the [PdfAnnotationValueType] of the value associated with the given key in the
+    /// annotation dictionary of this [PdfPageAnnotation], or `None` if the key does not exist.
+    fn value_type(&self, key: &str) -> Option<PdfAnnotationValueType>;
+
+    /// Returns the value associated with the given key in the annotation dictionary of this
+    /// [PdfPageAnnotation], if the key exists and its value is a non-empty string or name.
+    ///
+    /// This function, along with [PdfPageAnnotationCommon::get_number_value()] and
+    /// [PdfPageAnnotationCommon::set_string_value()], provides access to dictionary entries
+    /// not otherwise exposed by this crate, such as vendor-specific keys.
+    fn get_string_value(&self, key: &str) -> Option<String>;
+
+    /// Returns the value associated with the given key in the annotation dictionary of this
+    /// [PdfPageAnnotation], if the key exists and its value is a number.
+    fn get_number_value(&self, key: &str) -> Option<f32>;
+
+    /// Sets the value associated with the given key in the annotation dictionary of this
+    /// [PdfPageAnnotation] to the given string, replacing any existing value. The annotation's
+    /// modification date is updated at the same time.
+    ///
+    /// Pdfium only supports writing string values into an annotation dictionary; it does not
+    /// provide any way to write number, name, boolean, array, or object reference values.
+    /// Dictionary entries holding those types of values, such as the /IRT reference of a reply
+    /// or the vertices of a polygon, can be read but not set. Pdfium also treats
+    /// the given value as null-terminated, so any text after an embedded null character
+    /// will not be stored.
+    fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError>;
+
+    /// Returns the content of the appearance stream used to draw this [PdfPageAnnotation]
+    /// in the given [PdfAppearanceMode], if any.
+    ///
+    /// If the annotation's appearance dictionary contains several appearance states for
+    /// the given mode, the stream for the annotation's current /AS appearance state is returned.
+    fn appearance_stream(&self, appearance_mode: PdfAppearanceMode) -> Option<String>;
+
+    /// Replaces the appearance stream used to draw this [PdfPageAnnotation] in the given
+    /// [PdfAppearanceMode] with a stream containing the given content, or removes the
+    /// appearance stream if `None` is given.
+    ///
+    /// The stream's bounding box is set to the bounds of the annotation, so the content
+    /// is drawn in page coordinates. Removing the [PdfAppearanceMode::Normal] appearance
+    /// stream removes all the annotation's appearance streams; Pdfium will then generate
+    /// an appearance from the annotation's properties where it can.
+    fn set_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+        content: Option<&str>,
+    ) -> Result<(), PdfiumError>;
+
     /// Returns an immutable collection of all the page objects in this [PdfPageAnnotation].
     ///
     /// Page objects can be retrieved from any type of [PdfPageAnnotation], but Pdfium currently
@@ -971,6 +1379,11 @@ where
         self.name_impl()
     }
 
+    #[inline]
+    fn set_name(&mut self, name: &str) -> Result<(), PdfiumError> {
+        self.set_name_impl(name)
+    }
+
     #[inline]
     fn is_markup_annotation(&self) -> bool {
         self.is_markup_annotation_impl()
@@ -981,6 +1394,16 @@ where
         self.has_attachment_points_impl()
     }
 
+    #[inline]
+    fn popup(&self) -> Option<PdfPageAnnotation> {
+        self.popup_impl()
+    }
+
+    #[inline]
+    fn in_reply_to(&self) -> Option<PdfPageAnnotation> {
+        self.in_reply_to_impl()
+    }
+
     #[inline]
     fn bounds(&self) -> Result<PdfRect, PdfiumError> {
         self.bounds_impl()
@@ -1018,7 +1441,27 @@ where
 
     #[inline]
     fn creator(&self) -> Option<String> {
-        self.creator_impl()
+        self.author_impl()
+    }
+
+    #[inline]
+    fn author(&self) -> Option<String> {
+        self.author_impl()
+    }
+
+    #[inline]
+    fn set_author(&mut self, author: &str) -> Result<(), PdfiumError> {
+        self.set_author_impl(author)
+    }
+
+    #[inline]
+    fn subject(&self) -> Option<String> {
+        self.subject_impl()
+    }
+
+    #[inline]
+    fn set_subject(&mut self, subject: &str) -> Result<(), PdfiumError> {
+        self.set_subject_impl(subject)
     }
 
     #[inline]
@@ -1026,6 +1469,11 @@ where
         self.creation_date_impl()
     }
 
+    #[inline]
+    fn creation_date_time(&self) -> Result<Option<DateTime<FixedOffset>>, PdfiumError> {
+        self.creation_date_time_impl()
+    }
+
     #[inline]
     fn set_creation_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
         self.set_creation_date_impl(date)
@@ -1036,6 +1484,11 @@ where
         self.modification_date_impl()
     }
 
+    #[inline]
+    fn modification_date_time(&self) -> Result<Option<DateTime<FixedOffset>>, PdfiumError> {
+        self.modification_date_time_impl()
+    }
+
     #[inline]
     fn set_modification_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
         self.set_modification_date_impl(date)
@@ -1061,6 +1514,75 @@ where
         self.set_stroke_color_impl(stroke_color)
     }
 
+    #[inline]
+    fn opacity(&self) -> Result<f32, PdfiumError> {
+        self.opacity_impl()
+    }
+
+    #[inline]
+    fn set_opacity(&mut self, opacity: f32) -> Result<(), PdfiumError> {
+        self.set_opacity_impl(opacity)
+    }
+
+    #[inline]
+    fn border(&self) -> PdfAnnotationBorder {
+        self.border_impl()
+    }
+
+    #[inline]
+    fn set_border(&mut self, border: PdfAnnotationBorder) -> Result<(), PdfiumError> {
+        self.set_border_impl(border)
+    }
+
+    #[inline]
+    fn flags(&self) -> PdfAnnotationFlags {
+        self.flags_impl()
+    }
+
+    #[inline]
+    fn set_flags(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError> {
+        self.set_flags_impl(flags)
+    }
+
+    #[inline]
+    fn has_key(&self, key: &str) -> bool {
+        self.has_key_impl(key)
+    }
+
+    #[inline]
+    fn value_type(&self, key: &str) -> Option<PdfAnnotationValueType> {
+        self.value_type_impl(key)
+    }
+
+    #[inline]
+    fn get_string_value(&self, key: &str) -> Option<String> {
+        self.get_string_value_impl(key)
+    }
+
+    #[inline]
+    fn get_number_value(&self, key: &str) -> Option<f32> {
+        self.get_number_value_impl(key)
+    }
+
+    #[inline]
+    fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        self.set_string_value_impl(key, value)
+    }
+
+    #[inline]
+    fn appearance_stream(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
+        self.appearance_stream_impl(appearance_mode)
+    }
+
+    #[inline]
+    fn set_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+        content: Option<&str>,
+    ) -> Result<(), PdfiumError> {
+        self.set_appearance_stream_impl(appearance_mode, content)
+    }
+
     #[inline]
     fn objects(&self) -> &PdfPageAnnotationObjects {
         self.objects_impl()
@@ -1103,11 +1625,3 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageAnnotation<'a> {
         self.unwrap_as_trait_mut().attachment_points_mut_impl()
     }
 }
-
-impl<'a> Drop for PdfPageAnnotation<'a> {
-    /// Closes this [PdfPageAnnotation], releasing held memory.
-    #[inline]
-    fn drop(&mut self) {
-        self.bindings().FPDFPage_CloseAnnot(self.handle());
-    }
-}
@@ -5,6 +5,7 @@
 use crate::bindgen::FPDF_ANNOTATION;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page_text_segment::PdfPageTextSegment;
 use crate::quad_points::PdfQuadPoints;
 use crate::rect::PdfRect;
 use std::ops::{Range, RangeInclusive};
@@ -15,6 +16,11 @@ pub type PdfPageAnnotationAttachmentPointIndex = usize;
 
 /// A set of all the attachment points that visually connect a `PdfPageAnnotation` object
 /// to one or more `PdfPageObject` objects on a `PdfPage`.
+///
+/// Pdfium can only append attachment points or replace existing ones; it provides no way
+/// to remove an attachment point. Functions that replace every attachment point in the
+/// collection return [PdfiumError::PageAnnotationAttachmentPointsCannotShrink] if given
+/// fewer attachment points than the collection already contains.
 pub struct PdfPageAnnotationAttachmentPoints<'a> {
     annotation_handle: FPDF_ANNOTATION,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -124,11 +130,22 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
 
     /// Creates a new attachment point from the given set of [PdfQuadPoints],
     /// and appends it to the end of this [PdfPageAnnotationAttachmentPoints] collection.
-    #[inline]
+    ///
+    /// Returns [PdfiumError::PageAnnotationAttachmentPointsNotSupported] if the containing
+    /// annotation does not support attachment points, or
+    /// [PdfiumError::PageAnnotationAttachmentPointAppendFailed] if Pdfium otherwise
+    /// refused to append the attachment point.
     pub fn create_attachment_point_at_end(
         &mut self,
         attachment_point: PdfQuadPoints,
     ) -> Result<(), PdfiumError> {
+        if !self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_HasAttachmentPoints(self.annotation_handle),
+        ) {
+            return Err(PdfiumError::PageAnnotationAttachmentPointsNotSupported);
+        }
+
         if self
             .bindings
             .is_true(self.bindings.FPDFAnnot_AppendAttachmentPoints(
@@ -138,10 +155,53 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
-            ))
+            Err(PdfiumError::PageAnnotationAttachmentPointAppendFailed)
+        }
+    }
+
+    /// Appends the given set of [PdfQuadPoints] to the end of this
+    /// [PdfPageAnnotationAttachmentPoints] collection.
+    ///
+    /// This is equivalent to calling
+    /// [PdfPageAnnotationAttachmentPoints::create_attachment_point_at_end()].
+    #[inline]
+    pub fn push(&mut self, attachment_point: PdfQuadPoints) -> Result<(), PdfiumError> {
+        self.create_attachment_point_at_end(attachment_point)
+    }
+
+    /// Inserts the given set of [PdfQuadPoints] into this [PdfPageAnnotationAttachmentPoints]
+    /// collection at the given index, shifting all attachment points after it towards the end.
+    ///
+    /// The index is checked, and every attachment point that needs to be shifted is read,
+    /// before the collection is changed, so an invalid index or unreadable attachment point
+    /// leaves the collection untouched.
+    pub fn insert(
+        &mut self,
+        index: PdfPageAnnotationAttachmentPointIndex,
+        attachment_point: PdfQuadPoints,
+    ) -> Result<(), PdfiumError> {
+        let len = self.len();
+
+        if index > len {
+            return Err(PdfiumError::PageAnnotationAttachmentPointIndexOutOfBounds);
+        }
+
+        if index == len {
+            return self.create_attachment_point_at_end(attachment_point);
+        }
+
+        // Pdfium can only append attachment points or replace existing ones, so we rewrite
+        // every attachment point from the insertion index onwards, one position further along.
+
+        let mut attachment_points = Vec::with_capacity(len - index + 1);
+
+        attachment_points.push(attachment_point);
+
+        for shift_index in index..len {
+            attachment_points.push(self.get(shift_index)?);
         }
+
+        self.write_from_index(index, attachment_points.as_slice())
     }
 
     /// Replaces the attachment at the given index in this [PdfPageAnnotationAttachmentPoints]
@@ -167,6 +227,159 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
         }
     }
 
+    /// Replaces all existing attachment points in this [PdfPageAnnotationAttachmentPoints]
+    /// collection with the given attachment points.
+    ///
+    /// Returns [PdfiumError::PageAnnotationAttachmentPointsNotSupported] if the containing
+    /// annotation does not support attachment points, or
+    /// [PdfiumError::PageAnnotationAttachmentPointsCannotShrink] if the collection already
+    /// contains more attachment points than were given. Both conditions are checked before
+    /// the collection is changed, so either error leaves the collection untouched.
+    #[inline]
+    pub fn set_all(&mut self, attachment_points: &[PdfQuadPoints]) -> Result<(), PdfiumError> {
+        self.overwrite_all(attachment_points)
+    }
+
+    /// Replaces all existing attachment points in this [PdfPageAnnotationAttachmentPoints]
+    /// collection with one attachment point for each of the given rectangles.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty, or
+    /// [PdfiumError::PageAnnotationAttachmentPointsCannotShrink] if the collection already
+    /// contains more attachment points than there are rectangles.
+    pub(crate) fn set_from_rects(&mut self, rects: &[PdfRect]) -> Result<(), PdfiumError> {
+        if rects.is_empty() {
+            return Err(PdfiumError::NoRectsForAttachmentPoints);
+        }
+
+        self.overwrite_all(
+            rects
+                .iter()
+                .map(|rect| PdfQuadPoints::from_rect(*rect))
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )
+    }
+
+    /// Replaces all existing attachment points in this [PdfPageAnnotationAttachmentPoints]
+    /// collection with a single attachment point enclosing the characters in the given
+    /// [PdfPageTextSegment].
+    ///
+    /// The bounds of a text segment are always axis-aligned, so the attachment point is
+    /// instead derived from the bounds of the individual characters in the segment, aligned
+    /// to the rotation angle of the segment's first character. This ensures the attachment
+    /// point follows rotated text rather than enclosing it in an oversized axis-aligned box.
+    pub(crate) fn set_from_text_segment(
+        &mut self,
+        segment: &PdfPageTextSegment,
+    ) -> Result<(), PdfiumError> {
+        let chars = segment.chars()?;
+
+        let mut angle = 0.0;
+
+        let mut rects = Vec::with_capacity(chars.len());
+
+        for (index, char) in chars.iter().enumerate() {
+            if index == 0 {
+                angle = char.angle_radians()?;
+            }
+
+            rects.push(char.loose_bounds()?);
+        }
+
+        let attachment_point = match PdfQuadPoints::from_rects_at_angle(rects.as_slice(), angle) {
+            Some(attachment_point) => attachment_point,
+            None => {
+                // The segment contains no characters Pdfium can measure; fall back to
+                // the segment's own bounds.
+
+                PdfQuadPoints::from_rect(segment.bounds())
+            }
+        };
+
+        self.overwrite_all(&[attachment_point])
+    }
+
+    /// Overwrites the attachment points in this [PdfPageAnnotationAttachmentPoints] collection
+    /// with the given attachment points, appending any that do not replace an existing one.
+    ///
+    /// Returns [PdfiumError::PageAnnotationAttachmentPointsCannotShrink] without changing
+    /// the collection if it already contains more attachment points than were given.
+    fn overwrite_all(&mut self, attachment_points: &[PdfQuadPoints]) -> Result<(), PdfiumError> {
+        if attachment_points.len() < self.len() {
+            return Err(PdfiumError::PageAnnotationAttachmentPointsCannotShrink);
+        }
+
+        self.write_from_index(0, attachment_points)
+    }
+
+    /// Writes the given attachment points into this [PdfPageAnnotationAttachmentPoints]
+    /// collection, starting at the given index, replacing existing attachment points and
+    /// appending any that extend beyond the end of the collection.
+    ///
+    /// Support for attachment points is checked before anything is written. Pdfium performs
+    /// no further validation of the attachment points it is given, so once that check has
+    /// passed, writes to valid indices do not fail.
+    fn write_from_index(
+        &mut self,
+        start_index: PdfPageAnnotationAttachmentPointIndex,
+        attachment_points: &[PdfQuadPoints],
+    ) -> Result<(), PdfiumError> {
+        if !self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_HasAttachmentPoints(self.annotation_handle),
+        ) {
+            return Err(PdfiumError::PageAnnotationAttachmentPointsNotSupported);
+        }
+
+        let len = self.len();
+
+        for (offset, attachment_point) in attachment_points.iter().enumerate() {
+            let index = start_index + offset;
+
+            if index < len {
+                self.set_attachment_point_at_index(index, *attachment_point)?;
+            } else {
+                self.create_attachment_point_at_end(*attachment_point)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns all the attachment points in this [PdfPageAnnotationAttachmentPoints] collection.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<PdfQuadPoints> {
+        self.iter().collect()
+    }
+
+    /// Returns the smallest axis-aligned [PdfRect] that encloses every attachment point
+    /// in this [PdfPageAnnotationAttachmentPoints] collection.
+    ///
+    /// All four vertices of each attachment point are considered, so the bounds also
+    /// enclose attachment points that are rotated or skewed.
+    #[inline]
+    pub fn bounds(&self) -> Result<PdfRect, PdfiumError> {
+        Self::bounds_of(self.iter()).ok_or(PdfiumError::NoAttachmentPointsInPageAnnotation)
+    }
+
+    /// Returns the smallest axis-aligned [PdfRect] that encloses every one of the given
+    /// attachment points, or `None` if no attachment points are given.
+    pub(crate) fn bounds_of(
+        attachment_points: impl IntoIterator<Item = PdfQuadPoints>,
+    ) -> Option<PdfRect> {
+        attachment_points
+            .into_iter()
+            .map(|attachment_point| attachment_point.to_rect())
+            .reduce(|a, b| {
+                PdfRect::new_from_values(
+                    a.bottom.value.min(b.bottom.value),
+                    a.left.value.min(b.left.value),
+                    a.top.value.max(b.top.value),
+                    a.right.value.max(b.right.value),
+                )
+            })
+    }
+
     /// Returns an iterator over all the attachment points in this [PdfPageAnnotationAttachmentPoints] collection.
     #[inline]
     pub fn iter(&self) -> PdfPageAnnotationAttachmentPointsIterator {
@@ -0,0 +1,196 @@
+//! Defines the [PdfPageFileAttachmentAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::FileAttachment`.
+
+use crate::attachment::PdfAttachment;
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::page_annotation_objects::PdfPageAnnotationObjects;
+use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use std::os::raw::{c_ulong, c_void};
+
+/// The icon a conforming PDF viewer should use to display a [PdfPageFileAttachmentAnnotation],
+/// as defined in table 8.35 of the PDF Reference, version 1.7, on page 637.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfFileAttachmentIcon {
+    Graph,
+    PushPin,
+    Paperclip,
+    Tag,
+}
+
+impl PdfFileAttachmentIcon {
+    #[inline]
+    pub(crate) fn from_pdf_name(name: &str) -> Option<Self> {
+        match name {
+            "Graph" => Some(PdfFileAttachmentIcon::Graph),
+            "PushPin" => Some(PdfFileAttachmentIcon::PushPin),
+            "Paperclip" => Some(PdfFileAttachmentIcon::Paperclip),
+            "Tag" => Some(PdfFileAttachmentIcon::Tag),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_pdf_name(&self) -> &'static str {
+        match self {
+            PdfFileAttachmentIcon::Graph => "Graph",
+            PdfFileAttachmentIcon::PushPin => "PushPin",
+            PdfFileAttachmentIcon::Paperclip => "Paperclip",
+            PdfFileAttachmentIcon::Tag => "Tag",
+        }
+    }
+}
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FileAttachment`.
+///
+/// A file attachment annotation embeds a file in the document and displays an icon on the page,
+/// typically a paperclip or push pin, that the user can click to open the file. Unlike the
+/// document-level attachments available through `PdfDocument::attachments()`, the embedded file
+/// is associated with a specific location on a page.
+///
+/// Pdfium does not generate appearance streams for file attachment annotations, so newly
+/// created annotations are not visible when a page is rendered by Pdfium itself. Other PDF
+/// viewers draw the icon named by the annotation.
+pub struct PdfPageFileAttachmentAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    document_handle: FPDF_DOCUMENT,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageFileAttachmentAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageFileAttachmentAnnotation {
+            handle: annotation_handle,
+            document_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfAttachment] embedded in this [PdfPageFileAttachmentAnnotation], if any.
+    pub fn attachment(&self) -> Option<PdfAttachment<'a>> {
+        let handle = self.bindings.FPDFAnnot_GetFileAttachment(self.handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfAttachment::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Embeds the given byte data in this [PdfPageFileAttachmentAnnotation] under the given
+    /// file name, replacing any file previously embedded in the annotation.
+    ///
+    /// The embedded file is only reachable through this annotation; it is not added to the
+    /// document-level `PdfAttachments` collection.
+    pub fn set_attachment(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<PdfAttachment<'a>, PdfiumError> {
+        // Embedding the file is a two step operation. First, we create a new FPDF_ATTACHMENT
+        // file specification in the annotation using the given name. Then, we add the given
+        // byte data to the FPDF_ATTACHMENT.
+
+        let handle = self
+            .bindings
+            .FPDFAnnot_AddFileAttachment_str(self.handle, name);
+
+        if handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        if self.bindings.is_true(self.bindings.FPDFAttachment_SetFile(
+            handle,
+            self.document_handle,
+            bytes.as_ptr() as *const c_void,
+            bytes.len() as c_ulong,
+        )) {
+            Ok(PdfAttachment::from_pdfium(handle, self.bindings))
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns the icon a conforming PDF viewer should use to display this
+    /// [PdfPageFileAttachmentAnnotation], if one is set and recognized.
+    ///
+    /// Viewers display a push pin icon when no icon is set.
+    pub fn icon(&self) -> Option<PdfFileAttachmentIcon> {
+        self.get_string_value_impl("Name")
+            .and_then(|name| PdfFileAttachmentIcon::from_pdf_name(name.as_str()))
+    }
+
+    /// Sets the icon a conforming PDF viewer should use to display this
+    /// [PdfPageFileAttachmentAnnotation].
+    ///
+    /// Pdfium can only write string values into an annotation dictionary, so the icon is stored
+    /// as a string rather than as a name object. Viewers that strictly require a name object
+    /// will display their default icon instead.
+    #[inline]
+    pub fn set_icon(&mut self, icon: PdfFileAttachmentIcon) -> Result<(), PdfiumError> {
+        self.set_string_value_impl("Name", icon.as_pdf_name())
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFileAttachmentAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
+
+impl<'a> Drop for PdfPageFileAttachmentAnnotation<'a> {
+    /// Closes this [PdfPageFileAttachmentAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
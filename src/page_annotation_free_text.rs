@@ -1,11 +1,18 @@
 //! Defines the [PdfPageFreeTextAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::FreeText`.
 
+use crate::appearance_mode::PdfAppearanceMode;
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::font::PdfFont;
+use crate::page_annotation::PdfAnnotationBorder;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::points::PdfPoints;
+use crate::utils::content::format_pdf_number;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FreeText`.
 pub struct PdfPageFreeTextAnnotation<'a> {
@@ -37,6 +44,357 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the default appearance string of this [PdfPageFreeTextAnnotation], if any.
+    /// The default appearance string specifies the font, font size, and text color
+    /// used to display the annotation's text.
+    #[inline]
+    pub fn default_appearance(&self) -> Option<String> {
+        self.get_string_value_impl("DA")
+    }
+
+    /// Returns the font size used to display the text of this [PdfPageFreeTextAnnotation],
+    /// as given in the annotation's default appearance string, if any.
+    #[inline]
+    pub fn font_size(&self) -> Option<PdfPoints> {
+        self.parse_default_appearance()
+            .font_size
+            .map(PdfPoints::new)
+    }
+
+    /// Returns the color used to display the text of this [PdfPageFreeTextAnnotation],
+    /// as given in the annotation's default appearance string, if any.
+    #[inline]
+    pub fn text_color(&self) -> Option<PdfColor> {
+        self.parse_default_appearance().color
+    }
+
+    /// Sets the font, font size, and text color used to display the text of this
+    /// [PdfPageFreeTextAnnotation], and regenerates the annotation's appearance stream
+    /// from its current contents and bounds.
+    ///
+    /// The annotation's bounds should be set before calling this function, since the
+    /// appearance stream is clipped to the annotation's bounds.
+    ///
+    /// Pdfium offers no way to attach font resources to an appearance stream, so while other
+    /// PDF viewers that regenerate appearances from the default appearance string will use the
+    /// given font, Pdfium itself may substitute a standard font when rendering the annotation.
+    #[inline]
+    pub fn set_default_appearance(
+        &mut self,
+        font: &PdfFont,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Result<(), PdfiumError> {
+        self.set_default_appearance_values(
+            escape_pdf_name(font.name().as_str()).as_str(),
+            font_size,
+            color,
+        )
+    }
+
+    /// Sets the font size used to display the text of this [PdfPageFreeTextAnnotation],
+    /// keeping the font and text color given in the annotation's default appearance string,
+    /// and regenerates the annotation's appearance stream.
+    ///
+    /// If the annotation has no default appearance string, the text is displayed
+    /// in black Helvetica.
+    pub fn set_font_size(&mut self, font_size: PdfPoints) -> Result<(), PdfiumError> {
+        let appearance = self.parse_default_appearance();
+
+        self.set_default_appearance_values(
+            appearance.font_name().as_str(),
+            font_size,
+            appearance.color(),
+        )
+    }
+
+    /// Sets the color used to display the text of this [PdfPageFreeTextAnnotation],
+    /// keeping the font and font size given in the annotation's default appearance string,
+    /// and regenerates the annotation's appearance stream.
+    ///
+    /// If the annotation has no default appearance string, the text is displayed
+    /// in 12 point Helvetica.
+    pub fn set_text_color(&mut self, color: PdfColor) -> Result<(), PdfiumError> {
+        let appearance = self.parse_default_appearance();
+
+        self.set_default_appearance_values(
+            appearance.font_name().as_str(),
+            appearance.font_size(),
+            color,
+        )
+    }
+
+    /// Draws a rectangular border of the given width and color around the text of this
+    /// [PdfPageFreeTextAnnotation], and regenerates the annotation's appearance stream.
+    /// A width of zero removes the border.
+    pub fn set_border_appearance(
+        &mut self,
+        width: PdfPoints,
+        color: PdfColor,
+    ) -> Result<(), PdfiumError> {
+        // Pdfium refuses to set the color of an annotation that has an appearance stream,
+        // so the existing appearance stream must be removed first.
+
+        self.remove_appearance()?;
+        self.set_border_impl(PdfAnnotationBorder::new(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            width,
+        ))?;
+        self.set_stroke_color_impl(color)?;
+        self.regenerate_appearance()
+    }
+
+    /// Regenerates the appearance stream of this [PdfPageFreeTextAnnotation] from its
+    /// current contents, bounds, default appearance string, and border.
+    ///
+    /// The appearance stream is regenerated automatically by the other appearance setters of
+    /// this annotation. Call this function after changing the annotation's contents or bounds
+    /// so that other PDF viewers, which display the appearance stream rather than
+    /// the annotation's contents, show the change.
+    pub fn regenerate_appearance(&mut self) -> Result<(), PdfiumError> {
+        // Pdfium does not generate appearance streams for free text annotations,
+        // so we must generate one ourselves for the text to be rendered.
+
+        // Pdfium only reports the color of an annotation that has no appearance stream,
+        // so the existing appearance stream must be removed before the border color is read.
+
+        self.remove_appearance()?;
+
+        let appearance = self.parse_default_appearance();
+
+        let font_size = appearance.font_size().value;
+
+        let bounds = self.bounds_impl()?;
+
+        let mut stream = String::from("q");
+
+        // Annotations created by Pdfium have no /Border entry, so a border is only drawn
+        // if one has been explicitly set.
+
+        let border_width = if self.has_key_impl("Border") {
+            self.border_impl().width.value
+        } else {
+            0.0
+        };
+
+        if border_width > 0.0 {
+            let border_color = self.stroke_color_impl().unwrap_or(PdfColor::BLACK);
+
+            stream.push_str(
+                format!(
+                    " {} {} w {} {} {} {} re S",
+                    rgb_color_operator(border_color, "RG"),
+                    format_pdf_number(border_width),
+                    format_pdf_number(bounds.left.value + border_width / 2.0),
+                    format_pdf_number(bounds.bottom.value + border_width / 2.0),
+                    format_pdf_number(bounds.width().value - border_width),
+                    format_pdf_number(bounds.height().value - border_width),
+                )
+                .as_str(),
+            );
+        }
+
+        stream.push_str(
+            format!(
+                " {} BT /{} {} Tf {} TL {} {} Td",
+                rgb_color_operator(appearance.color(), "rg"),
+                appearance.font_name(),
+                format_pdf_number(font_size),
+                format_pdf_number(font_size * 1.2),
+                format_pdf_number(bounds.left.value + border_width + 2.0),
+                format_pdf_number(bounds.top.value - border_width - font_size - 2.0),
+            )
+            .as_str(),
+        );
+
+        for (index, line) in self.contents_impl().unwrap_or_default().lines().enumerate() {
+            if index > 0 {
+                stream.push_str(" T*");
+            }
+
+            stream.push_str(format!(" ({}) Tj", escape_pdf_string(line)).as_str());
+        }
+
+        stream.push_str(" ET Q");
+
+        if self.bindings.is_true(self.bindings.FPDFAnnot_SetAP_str(
+            self.handle,
+            PdfAppearanceMode::Normal.as_pdfium(),
+            stream.as_str(),
+        )) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Removes the normal appearance stream of this [PdfPageFreeTextAnnotation], if any.
+    #[inline]
+    fn remove_appearance(&mut self) -> Result<(), PdfiumError> {
+        self.set_appearance_stream_impl(PdfAppearanceMode::Normal, None)
+    }
+
+    /// Sets the default appearance string of this [PdfPageFreeTextAnnotation] from the given
+    /// values, then regenerates the annotation's appearance stream. The font name must already
+    /// be escaped for use as the body of a PDF name object.
+    fn set_default_appearance_values(
+        &mut self,
+        font_name: &str,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Result<(), PdfiumError> {
+        self.set_string_value_impl(
+            "DA",
+            format!(
+                "/{} {} Tf {}",
+                font_name,
+                format_pdf_number(font_size.value),
+                rgb_color_operator(color, "rg")
+            )
+            .as_str(),
+        )?;
+
+        self.regenerate_appearance()
+    }
+
+    /// Parses the font, font size, and text color from the default appearance string
+    /// of this [PdfPageFreeTextAnnotation].
+    #[inline]
+    fn parse_default_appearance(&self) -> DefaultAppearance {
+        DefaultAppearance::parse(self.default_appearance().unwrap_or_default().as_str())
+    }
+}
+
+/// The font, font size, and text color given in the default appearance string
+/// of a [PdfPageFreeTextAnnotation].
+#[derive(Debug, Default, PartialEq)]
+struct DefaultAppearance {
+    font_name: Option<String>,
+    font_size: Option<f32>,
+    color: Option<PdfColor>,
+}
+
+impl DefaultAppearance {
+    /// Parses the operators in the given default appearance string. Operators other than
+    /// `Tf`, `g`, `rg`, and `k` are ignored; if an operator appears more than once,
+    /// the last occurrence takes effect.
+    fn parse(appearance: &str) -> Self {
+        let mut result = DefaultAppearance::default();
+
+        let mut operands: Vec<&str> = Vec::new();
+
+        let component = |value: &str| {
+            (value.parse::<f32>().unwrap_or(0.0).clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+
+        for token in appearance.split_whitespace() {
+            match (token, operands.as_slice()) {
+                ("Tf", [.., name, size]) if name.starts_with('/') => {
+                    result.font_name = Some(name[1..].to_owned());
+                    result.font_size = size.parse().ok();
+                }
+                ("g", [.., gray]) => {
+                    let gray = component(gray);
+
+                    result.color = Some(PdfColor::new(gray, gray, gray, 255));
+                }
+                ("rg", [.., red, green, blue]) => {
+                    result.color = Some(PdfColor::new(
+                        component(red),
+                        component(green),
+                        component(blue),
+                        255,
+                    ));
+                }
+                ("k", [.., cyan, magenta, yellow, black]) => {
+                    let black = 255 - component(black) as u16;
+
+                    let channel =
+                        |value: &str| ((255 - component(value) as u16) * black / 255) as u8;
+
+                    result.color = Some(PdfColor::new(
+                        channel(cyan),
+                        channel(magenta),
+                        channel(yellow),
+                        255,
+                    ));
+                }
+                _ => {}
+            }
+
+            if token.starts_with('/') || token.parse::<f32>().is_ok() {
+                operands.push(token);
+            } else {
+                operands.clear();
+            }
+        }
+
+        result
+    }
+
+    /// Returns the escaped font name, defaulting to Helvetica.
+    #[inline]
+    fn font_name(&self) -> String {
+        self.font_name
+            .clone()
+            .unwrap_or_else(|| "Helvetica".to_owned())
+    }
+
+    /// Returns the font size, defaulting to 12 points.
+    #[inline]
+    fn font_size(&self) -> PdfPoints {
+        PdfPoints::new(self.font_size.unwrap_or(12.0))
+    }
+
+    /// Returns the text color, defaulting to black.
+    #[inline]
+    fn color(&self) -> PdfColor {
+        self.color.unwrap_or(PdfColor::BLACK)
+    }
+}
+
+/// Returns a PDF content stream operator setting the given RGB color, using the given
+/// operator name: `rg` for the fill color, or `RG` for the stroke color.
+fn rgb_color_operator(color: PdfColor, operator: &str) -> String {
+    format!(
+        "{} {} {} {}",
+        format_pdf_number(color.red() as f32 / 255.0),
+        format_pdf_number(color.green() as f32 / 255.0),
+        format_pdf_number(color.blue() as f32 / 255.0),
+        operator,
+    )
+}
+
+/// Encodes the given font name as the body of a PDF name object, escaping delimiters,
+/// whitespace, and non-printable characters using `#xx` hexadecimal notation.
+fn escape_pdf_name(name: &str) -> String {
+    name.bytes()
+        .map(|byte| match byte {
+            b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%' | b'#' => {
+                format!("#{:02X}", byte)
+            }
+            0x21..=0x7E => (byte as char).to_string(),
+            _ => format!("#{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Encodes the given text as the body of a PDF literal string, escaping parentheses and
+/// backslashes. Characters outside the printable ASCII range are written as octal escapes;
+/// characters that cannot be represented in a single byte are replaced with `?`.
+fn escape_pdf_string(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '(' | ')' | '\\' => format!("\\{}", character),
+            ' '..='~' => character.to_string(),
+            '\u{0}'..='\u{FF}' => format!("\\{:03o}", character as u32),
+            _ => "?".to_owned(),
+        })
+        .collect()
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
@@ -70,3 +428,62 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageFreeTextAnnotation<'a> {
+    /// Closes this [PdfPageFreeTextAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_pdf_name() {
+        assert_eq!(escape_pdf_name("Helvetica"), "Helvetica");
+        assert_eq!(escape_pdf_name("My Font (Bold)"), "My#20Font#20#28Bold#29");
+        assert_eq!(escape_pdf_name("A/B#C"), "A#2FB#23C");
+    }
+
+    #[test]
+    fn test_parse_default_appearance() {
+        assert_eq!(
+            DefaultAppearance::parse("/Helvetica 36 Tf 1 0 0 rg"),
+            DefaultAppearance {
+                font_name: Some("Helvetica".to_owned()),
+                font_size: Some(36.0),
+                color: Some(PdfColor::RED),
+            }
+        );
+
+        assert_eq!(
+            DefaultAppearance::parse("0.5 g /My#20Font 9.5 Tf"),
+            DefaultAppearance {
+                font_name: Some("My#20Font".to_owned()),
+                font_size: Some(9.5),
+                color: Some(PdfColor::new(128, 128, 128, 255)),
+            }
+        );
+
+        assert_eq!(
+            DefaultAppearance::parse("0 1 0 0 k"),
+            DefaultAppearance {
+                font_name: None,
+                font_size: None,
+                color: Some(PdfColor::new(255, 0, 255, 255)),
+            }
+        );
+
+        assert_eq!(DefaultAppearance::parse(""), DefaultAppearance::default());
+    }
+
+    #[test]
+    fn test_escape_pdf_string() {
+        assert_eq!(escape_pdf_string("Hello"), "Hello");
+        assert_eq!(escape_pdf_string("(a) \\ b"), "\\(a\\) \\\\ b");
+        assert_eq!(escape_pdf_string("caf\u{e9}"), "caf\\351");
+    }
+}
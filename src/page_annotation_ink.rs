@@ -1,11 +1,15 @@
 //! Defines the [PdfPageInkAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::Ink`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{size_t, FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FS_POINTF};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::points::PdfPoints;
+use crate::rect::PdfRect;
+use std::os::raw::c_ulong;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Ink`.
 pub struct PdfPageInkAnnotation<'a> {
@@ -43,6 +47,133 @@ impl<'a> PdfPageInkAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Returns the number of freehand strokes in the ink list of this [PdfPageInkAnnotation].
+    #[inline]
+    pub fn strokes_len(&self) -> usize {
+        self.bindings.FPDFAnnot_GetInkListCount(self.handle) as usize
+    }
+
+    /// Returns the freehand strokes in the ink list of this [PdfPageInkAnnotation]. Each stroke
+    /// is returned as a list of `(x, y)` points in page coordinates.
+    pub fn strokes(&self) -> Vec<Vec<(PdfPoints, PdfPoints)>> {
+        (0..self.strokes_len())
+            .map(|index| self.stroke(index))
+            .collect()
+    }
+
+    /// Returns the points in the freehand stroke at the given index in the ink list of
+    /// this [PdfPageInkAnnotation].
+    fn stroke(&self, index: usize) -> Vec<(PdfPoints, PdfPoints)> {
+        // Retrieving the points in a stroke from Pdfium is a two-step operation. First, we call
+        // FPDFAnnot_GetInkListPath() with a null buffer; this will retrieve the number of
+        // points in the stroke. If the number is non-zero, then we reserve a buffer of
+        // the given length and call FPDFAnnot_GetInkListPath() again to fill the buffer.
+
+        let point_count = self.bindings.FPDFAnnot_GetInkListPath(
+            self.handle,
+            index as c_ulong,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if point_count == 0 {
+            return Vec::new();
+        }
+
+        let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; point_count as usize];
+
+        let result = self.bindings.FPDFAnnot_GetInkListPath(
+            self.handle,
+            index as c_ulong,
+            buffer.as_mut_ptr(),
+            point_count,
+        );
+
+        assert_eq!(result, point_count);
+
+        buffer
+            .iter()
+            .map(|point| (PdfPoints::new(point.x), PdfPoints::new(point.y)))
+            .collect()
+    }
+
+    /// Adds a new freehand stroke passing through the given `(x, y)` points, expressed
+    /// in page coordinates, to the ink list of this [PdfPageInkAnnotation].
+    ///
+    /// The bounds of this [PdfPageInkAnnotation] will be expanded to enclose every stroke
+    /// in the ink list, so the entire stroke remains visible.
+    pub fn add_stroke(&mut self, points: &[(PdfPoints, PdfPoints)]) -> Result<(), PdfiumError> {
+        if points.is_empty() {
+            return Err(PdfiumError::NoPointsInInkStroke);
+        }
+
+        let buffer = points
+            .iter()
+            .map(|(x, y)| FS_POINTF {
+                x: x.value,
+                y: y.value,
+            })
+            .collect::<Vec<_>>();
+
+        let result = self.bindings.FPDFAnnot_AddInkStroke(
+            self.handle,
+            buffer.as_ptr(),
+            buffer.len() as size_t,
+        );
+
+        if result < 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        self.update_bounds_from_strokes()
+    }
+
+    /// Removes all freehand strokes from the ink list of this [PdfPageInkAnnotation].
+    pub fn clear_strokes(&mut self) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFAnnot_RemoveInkList(self.handle))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the bounds of this [PdfPageInkAnnotation] to the smallest rectangle enclosing
+    /// every stroke in its ink list, padded by half the annotation's border width so that
+    /// the outer edges of each stroke are not clipped.
+    fn update_bounds_from_strokes(&mut self) -> Result<(), PdfiumError> {
+        let mut points = self.strokes().into_iter().flatten();
+
+        let (x, y) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+
+        let (mut left, mut right, mut bottom, mut top) = (x.value, x.value, y.value, y.value);
+
+        for (x, y) in points {
+            left = left.min(x.value);
+            right = right.max(x.value);
+            bottom = bottom.min(y.value);
+            top = top.max(y.value);
+        }
+
+        let padding = self.border_impl().width.value / 2.0;
+
+        self.set_bounds_impl(PdfRect::new_from_values(
+            bottom - padding,
+            left - padding,
+            top + padding,
+            right + padding,
+        ))
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
@@ -76,3 +207,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageInkAnnotation<'a> {
+    /// Closes this [PdfPageInkAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
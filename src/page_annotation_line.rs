@@ -0,0 +1,129 @@
+//! Defines the [PdfPageLineAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::Line`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FS_POINTF};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::page_annotation_objects::PdfPageAnnotationObjects;
+use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::points::PdfPoints;
+
+/// The `(x, y)` position, in page coordinates, of one end of the line drawn by
+/// a [PdfPageLineAnnotation].
+pub type PdfPageLineAnnotationEndpoint = (PdfPoints, PdfPoints);
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Line`.
+///
+/// Pdfium does not currently support creating new line annotations. The endpoints and
+/// line ending styles of an existing line can be read but not set; see
+/// `PdfPageAnnotationCommon::set_string_value()`. The stroke color and border width of
+/// the line can be set using the functions in the `PdfPageAnnotationCommon` trait.
+pub struct PdfPageLineAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageLineAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageLineAnnotation {
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the starting point of the line drawn by this [PdfPageLineAnnotation],
+    /// in page coordinates.
+    #[inline]
+    pub fn start(&self) -> Result<PdfPageLineAnnotationEndpoint, PdfiumError> {
+        self.line().map(|(start, _)| start)
+    }
+
+    /// Returns the ending point of the line drawn by this [PdfPageLineAnnotation],
+    /// in page coordinates.
+    #[inline]
+    pub fn end(&self) -> Result<PdfPageLineAnnotationEndpoint, PdfiumError> {
+        self.line().map(|(_, end)| end)
+    }
+
+    /// Returns the starting and ending points of the line drawn by this [PdfPageLineAnnotation],
+    /// in page coordinates.
+    pub fn line(
+        &self,
+    ) -> Result<(PdfPageLineAnnotationEndpoint, PdfPageLineAnnotationEndpoint), PdfiumError> {
+        let mut start = FS_POINTF { x: 0.0, y: 0.0 };
+
+        let mut end = FS_POINTF { x: 0.0, y: 0.0 };
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_GetLine(self.handle, &mut start, &mut end),
+        ) {
+            Ok((
+                (PdfPoints::new(start.x), PdfPoints::new(start.y)),
+                (PdfPoints::new(end.x), PdfPoints::new(end.y)),
+            ))
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLineAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
+
+impl<'a> Drop for PdfPageLineAnnotation<'a> {
+    /// Closes this [PdfPageLineAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
@@ -3,13 +3,37 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::destination::PdfDestination;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::link::PdfLink;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 use crate::page_objects_private::internal::PdfPageObjectsPrivate;
+use crate::page_text_segment::PdfPageTextSegment;
+use crate::pages::PdfPageIndex;
 use crate::points::PdfPoints;
+use crate::rect::PdfRect;
+
+/// The target of a new [PdfPageLinkAnnotation] created by
+/// `PdfPageAnnotations::create_link_annotation_at_end()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfLinkTarget {
+    /// An external URI, typically a web address, that will be opened when the user clicks
+    /// the link in a PDF viewer.
+    Uri(String),
+
+    /// A location on a page in the same document that a PDF viewer will navigate to when
+    /// the user clicks the link. The `x` and `y` coordinates give the point on the target
+    /// page that should be positioned at the top left of the viewer's window, and `zoom`
+    /// gives the magnification factor the viewer should apply.
+    PageDestination {
+        page_index: PdfPageIndex,
+        x: PdfPoints,
+        y: PdfPoints,
+        zoom: f32,
+    },
+}
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Link`.
 pub struct PdfPageLinkAnnotation<'a> {
@@ -59,6 +83,16 @@ impl<'a> PdfPageLinkAnnotation<'a> {
         }
     }
 
+    /// Returns the [PdfDestination] targeted by this [PdfPageLinkAnnotation], if any.
+    ///
+    /// The target page index and view settings of the destination can be retrieved using the
+    /// [PdfDestination::page_index()] and [PdfDestination::view_settings()] functions.
+    /// Returns `Ok(None)` if the link does not point to a destination in this document,
+    /// for instance if it carries a URI action instead.
+    pub fn dest(&self) -> Result<Option<PdfDestination>, PdfiumError> {
+        self.link().map(|link| link.destination())
+    }
+
     /// Sets the [PdfLink] associated with this [PdfPageLinkAnnotation] to the given URI.
     pub fn set_link(&mut self, uri: &str) -> Result<(), PdfiumError> {
         if self
@@ -91,6 +125,32 @@ impl<'a> PdfPageLinkAnnotation<'a> {
     pub fn attachment_points_mut(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
         &mut self.attachment_points
     }
+
+    /// Replaces all existing attachment points in this [PdfPageLinkAnnotation] with one attachment
+    /// point for each of the given rectangles, for instance one rectangle per line of
+    /// wrapped text.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    #[inline]
+    pub fn set_attachment_points_from_rects(
+        &mut self,
+        rects: &[PdfRect],
+    ) -> Result<(), PdfiumError> {
+        self.attachment_points.set_from_rects(rects)
+    }
+
+    /// Replaces all existing attachment points in this [PdfPageLinkAnnotation] with a single
+    /// attachment point enclosing the characters in the given [PdfPageTextSegment].
+    ///
+    /// If the text in the segment is rotated, the attachment point will be rotated to match,
+    /// rather than being aligned to the page axes.
+    #[inline]
+    pub fn set_attachment_points_from_text_selection(
+        &mut self,
+        segment: &PdfPageTextSegment,
+    ) -> Result<(), PdfiumError> {
+        self.attachment_points.set_from_text_segment(segment)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLinkAnnotation<'a> {
@@ -124,3 +184,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLinkAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageLinkAnnotation<'a> {
+    /// Closes this [PdfPageLinkAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
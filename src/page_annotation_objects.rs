@@ -58,6 +58,13 @@ impl<'a> PdfPageAnnotationObjects<'a> {
         &self.annotation_handle
     }
 
+    /// Returns the internal `FPDF_PAGE` handle for the page containing this
+    /// [PdfPageAnnotationObjects] collection.
+    #[inline]
+    pub(crate) fn get_page_handle(&self) -> FPDF_PAGE {
+        self.page_handle
+    }
+
     /// Sets whether or not this [PdfPageAnnotationObjects] collection should trigger
     /// content regeneration on its containing [PdfPage] when the collection is mutated.
     #[inline]
@@ -0,0 +1,104 @@
+//! Defines the [PdfPagePolygonAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::Polygon`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::page_annotation_objects::PdfPageAnnotationObjects;
+use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::points::PdfPoints;
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Polygon`.
+///
+/// Pdfium does not currently support creating new polygon annotations. The vertices of an
+/// existing polygon can be read but not set; see `PdfPageAnnotationCommon::set_string_value()`.
+pub struct PdfPagePolygonAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPagePolygonAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPagePolygonAnnotation {
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the `(x, y)` vertices of this [PdfPagePolygonAnnotation], in page coordinates.
+    #[inline]
+    pub fn vertices(&self) -> Vec<(PdfPoints, PdfPoints)> {
+        self.vertices_impl()
+    }
+
+    /// Returns `true` if the first and last vertices of this [PdfPagePolygonAnnotation]
+    /// coincide, explicitly closing the polygon.
+    ///
+    /// PDF viewers close polygons automatically when drawing them, so this is a property
+    /// of how the vertices were recorded rather than of the rendered shape.
+    pub fn is_closed(&self) -> bool {
+        let vertices = self.vertices();
+
+        match (vertices.first(), vertices.last()) {
+            (Some(first), Some(last)) => vertices.len() > 1 && first == last,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPagePolygonAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
+
+impl<'a> Drop for PdfPagePolygonAnnotation<'a> {
+    /// Closes this [PdfPagePolygonAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the [PdfPagePolylineAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::Polyline`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::page_annotation_objects::PdfPageAnnotationObjects;
+use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::points::PdfPoints;
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Polyline`.
+///
+/// Pdfium does not currently support creating new polyline annotations. The vertices of an
+/// existing polyline can be read but not set; see `PdfPageAnnotationCommon::set_string_value()`.
+pub struct PdfPagePolylineAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPagePolylineAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPagePolylineAnnotation {
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the `(x, y)` vertices of this [PdfPagePolylineAnnotation], in page coordinates.
+    #[inline]
+    pub fn vertices(&self) -> Vec<(PdfPoints, PdfPoints)> {
+        self.vertices_impl()
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPagePolylineAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
+
+impl<'a> Drop for PdfPagePolylineAnnotation<'a> {
+    /// Closes this [PdfPagePolylineAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
@@ -3,11 +3,20 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::page_annotation::{PdfAnnotationValueType, PdfPageAnnotation};
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Popup`.
+///
+/// A popup annotation displays the text of its parent markup annotation in a separate window.
+/// The parent of an existing popup can be retrieved using the [PdfPagePopupAnnotation::parent()]
+/// function, and the popup of a markup annotation using the `PdfPageAnnotationCommon::popup()`
+/// function.
+///
+/// Neither the parent of a newly created popup nor its open state can be set; see
+/// `PdfPageAnnotationCommon::set_string_value()`.
 pub struct PdfPagePopupAnnotation<'a> {
     handle: FPDF_ANNOTATION,
     objects: PdfPageAnnotationObjects<'a>,
@@ -37,6 +46,21 @@ impl<'a> PdfPagePopupAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the markup annotation that is the parent of this [PdfPagePopupAnnotation], if any.
+    #[inline]
+    pub fn parent(&self) -> Option<PdfPageAnnotation> {
+        self.get_linked_annotation("Parent")
+    }
+
+    /// Returns `true` if this [PdfPagePopupAnnotation] should initially be displayed open.
+    ///
+    /// Returns `false` if the popup's dictionary does not specify an open state, since popups
+    /// are initially closed by default.
+    pub fn is_open(&self) -> bool {
+        self.value_type_impl("Open") == Some(PdfAnnotationValueType::Boolean)
+            && self.read_string_value("Open").as_deref() == Some("true")
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPagePopupAnnotation<'a> {
@@ -70,3 +94,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPagePopupAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPagePopupAnnotation<'a> {
+    /// Closes this [PdfPagePopupAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
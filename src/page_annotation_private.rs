@@ -7,24 +7,29 @@ pub(crate) mod internal {
     // Instead of making the PdfPageAnnotationPrivate trait private, we leave it public but place it
     // inside this pub(crate) module in order to prevent it from being visible outside the crate.
 
+    use crate::appearance_mode::PdfAppearanceMode;
     use crate::bindgen::{
         FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
-        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_OBJECT_STRING,
-        FPDF_PAGEOBJECT, FPDF_WCHAR, FS_RECTF,
+        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_PAGEOBJECT,
+        FPDF_WCHAR, FS_POINTF, FS_RECTF,
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::color::PdfColor;
     use crate::error::{PdfiumError, PdfiumInternalError};
-    use crate::page_annotation::{PdfPageAnnotationCommon, PdfPageAnnotationType};
+    use crate::page_annotation::{
+        PdfAnnotationBorder, PdfAnnotationFlags, PdfAnnotationValueType, PdfPageAnnotation,
+        PdfPageAnnotationCommon, PdfPageAnnotationType,
+    };
     use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
     use crate::page_annotation_objects::PdfPageAnnotationObjects;
+    use crate::page_objects_private::internal::PdfPageObjectsPrivate;
     use crate::points::PdfPoints;
     use crate::rect::PdfRect;
-    use crate::utils::dates::date_time_to_pdf_string;
+    use crate::utils::dates::{date_time_to_pdf_string, pdf_string_to_date_time};
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use chrono::prelude::*;
-    use std::os::raw::c_uint;
+    use std::os::raw::{c_float, c_int, c_uint};
 
     /// Internal crate-specific functionality common to all [PdfPageAnnotation] objects.
     pub trait PdfPageAnnotationPrivate<'a>: PdfPageAnnotationCommon {
@@ -40,30 +45,47 @@ pub(crate) mod internal {
                 .unwrap_or(PdfPageAnnotationType::Unknown)
         }
 
-        /// Returns the string value associated with the given key in the annotation dictionary
-        /// of this [PdfPageAnnotation], if any.
-        fn get_string_value(&self, key: &str) -> Option<String> {
-            if !self
-                .bindings()
+        /// Internal implementation of [PdfPageAnnotationCommon::has_key()].
+        #[inline]
+        fn has_key_impl(&self, key: &str) -> bool {
+            self.bindings()
                 .is_true(self.bindings().FPDFAnnot_HasKey(self.handle(), key))
-            {
-                // The key does not exist.
+        }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::value_type()].
+        fn value_type_impl(&self, key: &str) -> Option<PdfAnnotationValueType> {
+            if !self.has_key_impl(key) {
                 return None;
             }
 
-            if self.bindings().FPDFAnnot_GetValueType(self.handle(), key) as u32
-                != FPDF_OBJECT_STRING
-            {
-                // The key exists, but the value associated with the key is not a string.
+            PdfAnnotationValueType::from_pdfium(
+                self.bindings().FPDFAnnot_GetValueType(self.handle(), key),
+            )
+        }
 
-                return None;
+        /// Internal implementation of [PdfPageAnnotationCommon::get_string_value()].
+        fn get_string_value_impl(&self, key: &str) -> Option<String> {
+            match self.value_type_impl(key) {
+                Some(PdfAnnotationValueType::String) | Some(PdfAnnotationValueType::Name) => {}
+                _ => {
+                    // The key does not exist, or the value associated with the key
+                    // is not a string.
+
+                    return None;
+                }
             }
 
+            self.read_string_value(key)
+        }
+
+        /// Retrieves the value associated with the given key in this annotation's dictionary
+        /// as a string, without first checking the type of the value. Pdfium converts
+        /// non-string values into their string representations; for instance, boolean values
+        /// are returned as either `"true"` or `"false"`.
+        fn read_string_value(&self, key: &str) -> Option<String> {
             // Retrieving the string value from Pdfium is a two-step operation. First, we call
             // FPDFAnot_GetStringValue() with a null buffer; this will retrieve the length of
-            // the value in bytes, assuming the key exists. If the length is zero, then there
-            // is no such key, or the key's value is not a string.
+            // the value in bytes, including the trailing null terminator.
 
             // If the length is non-zero, then we reserve a byte buffer of the given
             // length and call FPDFAnot_GetStringValue() again with a pointer to the buffer;
@@ -94,12 +116,32 @@ pub(crate) mod internal {
 
             assert_eq!(result, buffer_length);
 
+            // The buffer length reported by Pdfium, rather than the position of the first
+            // null character, determines the extent of the string, so any null characters
+            // embedded in the value are preserved.
+
             Some(get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default())
         }
 
-        /// Sets the string value associated with the given key in the annotation dictionary
-        /// of this [PdfPageAnnotation].
-        fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        /// Internal implementation of [PdfPageAnnotationCommon::get_number_value()].
+        fn get_number_value_impl(&self, key: &str) -> Option<f32> {
+            let mut value: c_float = 0.0;
+
+            if self
+                .bindings()
+                .is_true(
+                    self.bindings()
+                        .FPDFAnnot_GetNumberValue(self.handle(), key, &mut value),
+                )
+            {
+                Some(value)
+            } else {
+                None
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_string_value()].
+        fn set_string_value_impl(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
             // Attempt to update the modification date first, before we apply the given value update.
             // That way, if updating the date fails, we can fail early.
 
@@ -108,7 +150,7 @@ pub(crate) mod internal {
             // Don't update the modification date if the key we have been given to update
             // is itself the modification date!
             {
-                self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))?;
+                self.set_string_value_impl("M", &date_time_to_pdf_string(Utc::now()))?;
             }
 
             // With the modification date updated, we can now update the key and value
@@ -129,10 +171,85 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::appearance_stream()].
+        fn appearance_stream_impl(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
+            // Retrieving the appearance stream from Pdfium is a two-step operation. First, we call
+            // FPDFAnnot_GetAP() with a null buffer; this will retrieve the length of the
+            // appearance stream content in bytes, including the trailing null terminator.
+
+            // If the length is non-zero, then we reserve a byte buffer of the given
+            // length and call FPDFAnnot_GetAP() again with a pointer to the buffer;
+            // this will write the appearance stream content into the buffer in UTF16LE format.
+
+            let buffer_length = self.bindings().FPDFAnnot_GetAP(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                std::ptr::null_mut(),
+                0,
+            );
+
+            if buffer_length <= 2 {
+                // A buffer length of 2 indicates that the appearance stream for the given mode
+                // is either missing or empty, so there is no point in retrieving it.
+
+                return None;
+            }
+
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings().FPDFAnnot_GetAP(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+                buffer_length,
+            );
+
+            assert_eq!(result, buffer_length);
+
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_appearance_stream()].
+        fn set_appearance_stream_impl(
+            &mut self,
+            appearance_mode: PdfAppearanceMode,
+            content: Option<&str>,
+        ) -> Result<(), PdfiumError> {
+            let result = match content {
+                Some(content) => self.bindings().FPDFAnnot_SetAP_str(
+                    self.handle(),
+                    appearance_mode.as_pdfium(),
+                    content,
+                ),
+                None => self.bindings().FPDFAnnot_SetAP(
+                    self.handle(),
+                    appearance_mode.as_pdfium(),
+                    std::ptr::null(),
+                ),
+            };
+
+            if self.bindings().is_true(result) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::name()].
         #[inline]
         fn name_impl(&self) -> Option<String> {
-            self.get_string_value("NM")
+            self.get_string_value_impl("NM")
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_name()].
+        fn set_name_impl(&mut self, name: &str) -> Result<(), PdfiumError> {
+            if name.is_empty() {
+                return Err(PdfiumError::EmptyAnnotationName);
+            }
+
+            self.set_string_value_impl("NM", name)
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::bounds()].
@@ -157,7 +274,7 @@ pub(crate) mod internal {
                 self.bindings()
                     .FPDFAnnot_SetRect(self.handle(), &bounds.as_pdfium()),
             ) {
-                self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+                self.set_string_value_impl("M", &date_time_to_pdf_string(Utc::now()))
             } else {
                 Err(PdfiumError::PdfiumLibraryInternalError(
                     PdfiumInternalError::Unknown,
@@ -213,49 +330,79 @@ pub(crate) mod internal {
         /// Internal implementation of [PdfPageAnnotationCommon::contents()].
         #[inline]
         fn contents_impl(&self) -> Option<String> {
-            self.get_string_value("Contents")
+            self.get_string_value_impl("Contents")
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::set_contents()].
         #[inline]
         fn set_contents_impl(&mut self, contents: &str) -> Result<(), PdfiumError> {
-            self.set_string_value("Contents", contents)
+            self.set_string_value_impl("Contents", contents)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::author()].
+        #[inline]
+        fn author_impl(&self) -> Option<String> {
+            self.get_string_value_impl("T")
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_author()].
+        #[inline]
+        fn set_author_impl(&mut self, author: &str) -> Result<(), PdfiumError> {
+            self.set_string_value_impl("T", author)
         }
 
-        /// Internal implementation of [PdfPageAnnotationCommon::creator()].
+        /// Internal implementation of [PdfPageAnnotationCommon::subject()].
         #[inline]
-        fn creator_impl(&self) -> Option<String> {
-            self.get_string_value("T")
+        fn subject_impl(&self) -> Option<String> {
+            self.get_string_value_impl("Subj")
         }
 
-        /// Internal implementation of [PdfPageAnnotationCommon::set_creator()].
+        /// Internal implementation of [PdfPageAnnotationCommon::set_subject()].
         #[inline]
-        fn set_creator(&mut self, creator: &str) -> Result<(), PdfiumError> {
-            self.set_string_value("T", creator)
+        fn set_subject_impl(&mut self, subject: &str) -> Result<(), PdfiumError> {
+            self.set_string_value_impl("Subj", subject)
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::creation_date()].
         #[inline]
         fn creation_date_impl(&self) -> Option<String> {
-            self.get_string_value("CreationDate")
+            self.get_string_value_impl("CreationDate")
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::creation_date_time()].
+        #[inline]
+        fn creation_date_time_impl(&self) -> Result<Option<DateTime<FixedOffset>>, PdfiumError> {
+            self.creation_date_impl()
+                .map(|date| pdf_string_to_date_time(date.as_str()))
+                .transpose()
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::set_creation_date()].
         #[inline]
         fn set_creation_date_impl(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
-            self.set_string_value("CreationDate", &date_time_to_pdf_string(date))
+            self.set_string_value_impl("CreationDate", &date_time_to_pdf_string(date))
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::modification_date()].
         #[inline]
         fn modification_date_impl(&self) -> Option<String> {
-            self.get_string_value("M")
+            self.get_string_value_impl("M")
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::modification_date_time()].
+        #[inline]
+        fn modification_date_time_impl(
+            &self,
+        ) -> Result<Option<DateTime<FixedOffset>>, PdfiumError> {
+            self.modification_date_impl()
+                .map(|date| pdf_string_to_date_time(date.as_str()))
+                .transpose()
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::set_modification_date()].
         #[inline]
         fn set_modification_date_impl(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
-            self.set_string_value("M", &date_time_to_pdf_string(date))
+            self.set_string_value_impl("M", &date_time_to_pdf_string(date))
         }
 
         /// Internal implementation of [PdfPageAnnotationCommon::is_markup_annotation()].
@@ -269,6 +416,75 @@ pub(crate) mod internal {
                 && self.get_annotation_type() != PdfPageAnnotationType::Link
         }
 
+        /// Returns the annotation referenced by the given key in this annotation's dictionary,
+        /// if any. Pdfium will only follow references to annotations on the same page.
+        fn get_linked_annotation(&self, key: &str) -> Option<PdfPageAnnotation> {
+            let handle = self.bindings().FPDFAnnot_GetLinkedAnnot(self.handle(), key);
+
+            if handle.is_null() {
+                None
+            } else {
+                let objects = self.objects_impl();
+
+                Some(PdfPageAnnotation::from_pdfium(
+                    objects.document_handle(),
+                    objects.get_page_handle(),
+                    handle,
+                    None,
+                    objects.bindings(),
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::popup()].
+        #[inline]
+        fn popup_impl(&self) -> Option<PdfPageAnnotation> {
+            if self.is_markup_annotation_impl() {
+                self.get_linked_annotation("Popup")
+            } else {
+                None
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::in_reply_to()].
+        #[inline]
+        fn in_reply_to_impl(&self) -> Option<PdfPageAnnotation> {
+            self.get_linked_annotation("IRT")
+        }
+
+        /// Returns the `(x, y)` vertices, in page coordinates, of this polygon or
+        /// polyline annotation. Used by the `vertices()` functions of the
+        /// `PdfPagePolygonAnnotation` and `PdfPagePolylineAnnotation` types.
+        fn vertices_impl(&self) -> Vec<(PdfPoints, PdfPoints)> {
+            // Retrieving the vertices from Pdfium is a two-step operation. First, we call
+            // FPDFAnnot_GetVertices() with a null buffer; this will retrieve the number of
+            // vertices. If the number is non-zero, then we reserve a buffer of the given
+            // length and call FPDFAnnot_GetVertices() again to fill the buffer.
+
+            let vertex_count =
+                self.bindings()
+                    .FPDFAnnot_GetVertices(self.handle(), std::ptr::null_mut(), 0);
+
+            if vertex_count == 0 {
+                return Vec::new();
+            }
+
+            let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; vertex_count as usize];
+
+            let result = self.bindings().FPDFAnnot_GetVertices(
+                self.handle(),
+                buffer.as_mut_ptr(),
+                vertex_count,
+            );
+
+            assert_eq!(result, vertex_count);
+
+            buffer
+                .iter()
+                .map(|vertex| (PdfPoints::new(vertex.x), PdfPoints::new(vertex.y)))
+                .collect()
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::has_attachment_points()].
         #[inline]
         fn has_attachment_points_impl(&self) -> bool {
@@ -358,6 +574,47 @@ pub(crate) mod internal {
             }
         }
 
+        /// Returns the interior color of this [PdfPageAnnotation], if any.
+        /// Only square, circle, line, polygon, polyline, and redaction annotations have interior colors.
+        fn interior_color_impl(&self) -> Result<Option<PdfColor>, PdfiumError> {
+            // FPDFAnnot_GetColor() returns a default color if the annotation has no /IC entry,
+            // so we must check for the entry's presence ourselves. An annotation without
+            // an interior color is not filled.
+
+            if self.has_key_impl("IC") {
+                self.fill_color_impl().map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+
+        /// Sets the interior color of this [PdfPageAnnotation]. Used by the `set_interior_color()`
+        /// functions of the annotation types that support an interior color.
+        fn set_interior_color_impl(&mut self, interior_color: PdfColor) -> Result<(), PdfiumError> {
+            // FPDFAnnot_SetColor() refuses to set the color of annotations with appearance
+            // streams. Rather than discarding a caller's appearance stream, we report
+            // the problem and leave the caller to decide what to do.
+
+            if self.has_key_impl("AP") {
+                return Err(PdfiumError::PageAnnotationHasAppearanceStream);
+            }
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetColor(
+                self.handle(),
+                FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor,
+                interior_color.red() as c_uint,
+                interior_color.green() as c_uint,
+                interior_color.blue() as c_uint,
+                interior_color.alpha() as c_uint,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::stroke_color()].
         #[inline]
         fn stroke_color_impl(&self) -> Result<PdfColor, PdfiumError> {
@@ -440,6 +697,149 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::opacity()].
+        fn opacity_impl(&self) -> Result<f32, PdfiumError> {
+            if !self.has_key_impl("CA") {
+                // The PDF specification states that annotations are fully opaque by default.
+
+                return Ok(1.0);
+            }
+
+            let mut opacity: c_float = 0.0;
+
+            if self
+                .bindings()
+                .is_true(self.bindings().FPDFAnnot_GetNumberValue(
+                    self.handle(),
+                    "CA",
+                    &mut opacity,
+                ))
+            {
+                Ok(opacity)
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_opacity()].
+        fn set_opacity_impl(&mut self, opacity: f32) -> Result<(), PdfiumError> {
+            if opacity.is_nan() {
+                return Err(PdfiumError::AnnotationOpacityIsNaN);
+            }
+
+            // Pdfium does not provide a function for setting arbitrary number values in an
+            // annotation dictionary, but FPDFAnnot_SetColor() writes the alpha component of
+            // the given color to the /CA entry. We retrieve the current color (or the default
+            // color Pdfium would use when generating the annotation's appearance stream) and
+            // set it again with the requested alpha value.
+
+            let mut r: c_uint = 0;
+
+            let mut g: c_uint = 0;
+
+            let mut b: c_uint = 0;
+
+            let mut a: c_uint = 0;
+
+            // Both functions return false if the annotation is using appearance streams.
+
+            if !self.bindings().is_true(self.bindings().FPDFAnnot_GetColor(
+                self.handle(),
+                FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetColor(
+                self.handle(),
+                FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+                r,
+                g,
+                b,
+                (opacity.clamp(0.0, 1.0) * 255.0).round() as c_uint,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::border()].
+        fn border_impl(&self) -> PdfAnnotationBorder {
+            let mut horizontal_radius: c_float = 0.0;
+
+            let mut vertical_radius: c_float = 0.0;
+
+            let mut width: c_float = 0.0;
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_GetBorder(
+                self.handle(),
+                &mut horizontal_radius,
+                &mut vertical_radius,
+                &mut width,
+            )) {
+                PdfAnnotationBorder::new(
+                    PdfPoints::new(horizontal_radius),
+                    PdfPoints::new(vertical_radius),
+                    PdfPoints::new(width),
+                )
+            } else {
+                // FPDFAnnot_GetBorder() fails if the annotation has no valid /Border entry,
+                // in which case the PDF specification says the default border applies.
+
+                PdfAnnotationBorder::DEFAULT
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_border()].
+        fn set_border_impl(&mut self, border: PdfAnnotationBorder) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetBorder(
+                self.handle(),
+                border.horizontal_radius.value,
+                border.vertical_radius.value,
+                border.width.value,
+            )) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::flags()].
+        #[inline]
+        fn flags_impl(&self) -> PdfAnnotationFlags {
+            PdfAnnotationFlags::from_bits_truncate(
+                self.bindings().FPDFAnnot_GetFlags(self.handle()) as u32,
+            )
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_flags()].
+        #[inline]
+        fn set_flags_impl(&mut self, flags: PdfAnnotationFlags) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(
+                self.bindings()
+                    .FPDFAnnot_SetFlags(self.handle(), flags.bits() as c_int),
+            ) {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::objects()].
         fn objects_impl(&self) -> &PdfPageAnnotationObjects;
 
@@ -3,11 +3,21 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
+use crate::error::PdfiumError;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::rect::PdfRect;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Redacted`.
+///
+/// A redaction annotation marks a region of a page for content removal. The removal itself
+/// does not happen until `PdfPage::apply_redactions()` is called; until then, the content
+/// underneath the annotation is still present in the document.
+///
+/// Pdfium does not currently support creating new redaction annotations, but the
+/// overlay text and fill color of existing redaction annotations can be changed.
 pub struct PdfPageRedactedAnnotation<'a> {
     handle: FPDF_ANNOTATION,
     objects: PdfPageAnnotationObjects<'a>,
@@ -37,6 +47,52 @@ impl<'a> PdfPageRedactedAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the regions of the page marked for redaction by this [PdfPageRedactedAnnotation].
+    ///
+    /// The regions are taken from the annotation's attachment points, if any; otherwise,
+    /// the annotation's bounding box is used.
+    pub fn regions(&self) -> Result<Vec<PdfRect>, PdfiumError> {
+        let attachment_points = self.attachment_points_impl();
+
+        if attachment_points.is_empty() {
+            Ok(vec![self.bounds_impl()?])
+        } else {
+            attachment_points
+                .as_range()
+                .map(|index| attachment_points.get(index).map(|quad| quad.to_rect()))
+                .collect()
+        }
+    }
+
+    /// Returns the color used to fill each redacted region once redactions are applied,
+    /// if any. If no fill color is set, redacted regions are left empty.
+    #[inline]
+    pub fn interior_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.interior_color_impl()
+    }
+
+    /// Sets the color used to fill each redacted region once redactions are applied.
+    ///
+    /// Returns [PdfiumError::PageAnnotationHasAppearanceStream] without changing the color
+    /// if the annotation has an appearance stream.
+    #[inline]
+    pub fn set_interior_color(&mut self, interior_color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_interior_color_impl(interior_color)
+    }
+
+    /// Returns the overlay text that a PDF viewer should display over each redacted region,
+    /// if any.
+    #[inline]
+    pub fn overlay_text(&self) -> Option<String> {
+        self.get_string_value_impl("OverlayText")
+    }
+
+    /// Sets the overlay text that a PDF viewer should display over each redacted region.
+    #[inline]
+    pub fn set_overlay_text(&mut self, text: &str) -> Result<(), PdfiumError> {
+        self.set_string_value_impl("OverlayText", text)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageRedactedAnnotation<'a> {
@@ -70,3 +126,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageRedactedAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageRedactedAnnotation<'a> {
+    /// Closes this [PdfPageRedactedAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
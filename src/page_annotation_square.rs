@@ -3,6 +3,8 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::color::PdfColor;
+use crate::error::PdfiumError;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
@@ -37,6 +39,25 @@ impl<'a> PdfPageSquareAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the interior color used to fill the rectangle drawn by this [PdfPageSquareAnnotation],
+    /// or `None` if the rectangle is not filled.
+    #[inline]
+    pub fn interior_color(&self) -> Result<Option<PdfColor>, PdfiumError> {
+        self.interior_color_impl()
+    }
+
+    /// Sets the interior color used to fill the rectangle drawn by this [PdfPageSquareAnnotation].
+    ///
+    /// The alpha component of the given color sets the opacity of the entire annotation,
+    /// including its border. Pdfium draws the annotation using the new color if it has no
+    /// appearance stream. Returns [PdfiumError::PageAnnotationHasAppearanceStream] without
+    /// changing the color if the annotation has an appearance stream; remove it using
+    /// `PdfPageAnnotationCommon::set_appearance_stream()` first.
+    #[inline]
+    pub fn set_interior_color(&mut self, interior_color: PdfColor) -> Result<(), PdfiumError> {
+        self.set_interior_color_impl(interior_color)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageSquareAnnotation<'a> {
@@ -70,3 +91,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageSquareAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageSquareAnnotation<'a> {
+    /// Closes this [PdfPageSquareAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
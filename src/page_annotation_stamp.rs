@@ -1,11 +1,22 @@
 //! Defines the [PdfPageStampAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::Stamp`.
 
+use crate::appearance_mode::PdfAppearanceMode;
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::bitmap::PdfBitmap;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::page_object::PdfPageObjectType;
+use crate::page_object_image::PdfPageImageObject;
+use crate::page_objects_common::PdfPageObjectsCommon;
+use crate::page_objects_private::internal::PdfPageObjectsPrivate;
+use crate::rect::PdfRect;
+
+#[cfg(feature = "image")]
+use image::DynamicImage;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Stamp`.
 pub struct PdfPageStampAnnotation<'a> {
@@ -43,6 +54,85 @@ impl<'a> PdfPageStampAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Sets the content of this [PdfPageStampAnnotation] to the given image, scaled to fill
+    /// the given bounds. The bounds of the annotation itself are also set to the given bounds.
+    ///
+    /// Any image objects already in this [PdfPageStampAnnotation] are removed, so calling
+    /// this function again replaces the existing image.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn set_image(&mut self, image: &DynamicImage, bounds: PdfRect) -> Result<(), PdfiumError> {
+        let mut object =
+            PdfPageImageObject::new_from_handle(self.objects.document_handle(), self.bindings)?;
+
+        object.set_image(image)?;
+
+        self.replace_image_object(object, bounds)
+    }
+
+    /// Sets the content of this [PdfPageStampAnnotation] to the given [PdfBitmap], scaled to fill
+    /// the given bounds. The bounds of the annotation itself are also set to the given bounds.
+    ///
+    /// Any image objects already in this [PdfPageStampAnnotation] are removed, so calling
+    /// this function again replaces the existing image.
+    pub fn set_bitmap(&mut self, bitmap: &PdfBitmap, bounds: PdfRect) -> Result<(), PdfiumError> {
+        let mut object =
+            PdfPageImageObject::new_from_handle(self.objects.document_handle(), self.bindings)?;
+
+        object.set_bitmap(bitmap)?;
+
+        self.replace_image_object(object, bounds)
+    }
+
+    /// Removes all image objects from this [PdfPageStampAnnotation]. Any other page objects
+    /// in the annotation are retained.
+    pub fn remove_image(&mut self) -> Result<(), PdfiumError> {
+        // Iterate in reverse so that removing an object does not affect the indices
+        // of objects we have yet to visit.
+
+        for index in self.objects.as_range().rev() {
+            if self.objects.get(index)?.object_type() == PdfPageObjectType::Image {
+                self.objects.remove_object_at_index(index)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces any existing image objects in this [PdfPageStampAnnotation] with the given
+    /// image object, scaled and positioned to fill the given bounds.
+    fn replace_image_object(
+        &mut self,
+        mut object: PdfPageImageObject<'a>,
+        bounds: PdfRect,
+    ) -> Result<(), PdfiumError> {
+        self.remove_image()?;
+
+        self.set_bounds_impl(bounds)?;
+
+        if self.objects.is_empty() {
+            // Pdfium creates the annotation's appearance stream when the first object is added,
+            // sizing it to the annotation's bounds at that time. Clearing any existing
+            // appearance stream ensures a new one is created that matches the new bounds.
+
+            if !self.bindings.is_true(self.bindings.FPDFAnnot_SetAP(
+                self.handle,
+                PdfAppearanceMode::Normal.as_pdfium(),
+                std::ptr::null(),
+            )) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+        }
+
+        object.scale(bounds.width().value, bounds.height().value)?;
+        object.translate(bounds.left, bounds.bottom)?;
+
+        self.objects.add_image_object(object).map(|_| ())
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStampAnnotation<'a> {
@@ -76,3 +166,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStampAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageStampAnnotation<'a> {
+    /// Closes this [PdfPageStampAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
@@ -3,9 +3,12 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
+use crate::page_text_segment::PdfPageTextSegment;
+use crate::rect::PdfRect;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Strikeout`.
 pub struct PdfPageStrikeoutAnnotation<'a> {
@@ -43,6 +46,32 @@ impl<'a> PdfPageStrikeoutAnnotation<'a> {
     pub fn attachment_points_mut(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
         &mut self.attachment_points
     }
+
+    /// Replaces all existing attachment points in this [PdfPageStrikeoutAnnotation] with one attachment
+    /// point for each of the given rectangles, for instance one rectangle per line of
+    /// wrapped text.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    #[inline]
+    pub fn set_attachment_points_from_rects(
+        &mut self,
+        rects: &[PdfRect],
+    ) -> Result<(), PdfiumError> {
+        self.attachment_points.set_from_rects(rects)
+    }
+
+    /// Replaces all existing attachment points in this [PdfPageStrikeoutAnnotation] with a single
+    /// attachment point enclosing the characters in the given [PdfPageTextSegment].
+    ///
+    /// If the text in the segment is rotated, the attachment point will be rotated to match,
+    /// rather than being aligned to the page axes.
+    #[inline]
+    pub fn set_attachment_points_from_text_selection(
+        &mut self,
+        segment: &PdfPageTextSegment,
+    ) -> Result<(), PdfiumError> {
+        self.attachment_points.set_from_text_segment(segment)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStrikeoutAnnotation<'a> {
@@ -76,3 +105,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStrikeoutAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageStrikeoutAnnotation<'a> {
+    /// Closes this [PdfPageStrikeoutAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
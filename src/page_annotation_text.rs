@@ -70,3 +70,11 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageTextAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+impl<'a> Drop for PdfPageTextAnnotation<'a> {
+    /// Closes this [PdfPageTextAnnotation], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFPage_CloseAnnot(self.handle());
+    }
+}
@@ -1,12 +1,28 @@
 //! Defines the [PdfPageAnnotations] struct, exposing functionality related to the
 //! annotations that have been added to a single `PdfPage`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE};
+use crate::appearance_mode::PdfAppearanceMode;
+use crate::bindgen::{
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+    FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_ANNOT_FLAG_PRINT,
+    FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE, FS_POINTF,
+};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::bitmap::PdfBitmap;
 use crate::color::PdfColor;
+use crate::destination::PdfDestinationViewSettings;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::page_annotation::{PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType};
+use crate::font::PdfFont;
+use crate::matrix::PdfMatrix;
+use crate::page_annotation::{
+    PdfAnnotationBorder, PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType,
+};
+use crate::page_annotation_attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::page_annotation_file_attachment::{
+    PdfFileAttachmentIcon, PdfPageFileAttachmentAnnotation,
+};
 use crate::page_annotation_free_text::PdfPageFreeTextAnnotation;
+use crate::page_annotation_link::PdfLinkTarget;
 use crate::page_annotation_popup::PdfPagePopupAnnotation;
 use crate::page_annotation_private::internal::PdfPageAnnotationPrivate;
 use crate::page_annotation_square::PdfPageSquareAnnotation;
@@ -15,12 +31,19 @@ use crate::page_annotation_stamp::PdfPageStampAnnotation;
 use crate::page_annotation_strikeout::PdfPageStrikeoutAnnotation;
 use crate::page_annotation_text::PdfPageTextAnnotation;
 use crate::page_annotation_underline::PdfPageUnderlineAnnotation;
+use crate::page_annotation_widget::PdfPageWidgetAnnotation;
 use crate::page_object::{PdfPageObject, PdfPageObjectCommon};
+use crate::page_object_private::internal::PdfPageObjectPrivate;
+use crate::page_objects_common::PdfPageObjectsCommon;
+use crate::page_text_chars::{PdfPageTextCharIndex, PdfPageTextChars};
+use crate::pages::PdfPageIndex;
 use crate::prelude::{PdfPageHighlightAnnotation, PdfPageInkAnnotation, PdfPageLinkAnnotation, PdfPage, PdfPoints};
 use crate::quad_points::PdfQuadPoints;
+use crate::rect::PdfRect;
+use crate::utils::content::format_pdf_number;
 use chrono::prelude::*;
 use std::ops::Range;
-use std::os::raw::c_int;
+use std::os::raw::{c_int, c_uint};
 
 pub type PdfPageAnnotationIndex = usize;
 
@@ -138,12 +161,157 @@ impl<'a> PdfPageAnnotations<'a> {
         }
     }
 
+    /// Returns the first annotation in this [PdfPageAnnotations] collection with the given name,
+    /// if any.
+    ///
+    /// The PDF specification does not guarantee that annotation names are unique;
+    /// use [PdfPageAnnotations::find_all_by_name()] to retrieve every matching annotation.
+    pub fn find_by_name(&self, name: &str) -> Option<PdfPageAnnotation<'a>> {
+        self.as_range()
+            .filter_map(|index| self.get(index).ok())
+            .find(|annotation| annotation.name().as_deref() == Some(name))
+    }
+
+    /// Returns all the annotations in this [PdfPageAnnotations] collection with the given name.
+    pub fn find_all_by_name(&self, name: &str) -> Vec<PdfPageAnnotation<'a>> {
+        self.as_range()
+            .filter_map(|index| self.get(index).ok())
+            .filter(|annotation| annotation.name().as_deref() == Some(name))
+            .collect()
+    }
+
+    /// Returns the [PdfPageWidgetAnnotation] of the interactive form field at the given
+    /// point on the containing `PdfPage`, if any.
+    ///
+    /// If several form fields overlap at the given point, the topmost one is returned.
+    /// `None` is always returned if the containing `PdfDocument` has no form.
+    pub fn widget_at_point(
+        &self,
+        x: PdfPoints,
+        y: PdfPoints,
+    ) -> Option<PdfPageWidgetAnnotation<'a>> {
+        let form_handle = self.form_handle?;
+
+        let point = FS_POINTF {
+            x: x.value,
+            y: y.value,
+        };
+
+        let annotation_handle =
+            self.bindings()
+                .FPDFAnnot_GetFormFieldAtPoint(form_handle, self.page_handle, &point);
+
+        if annotation_handle.is_null() {
+            None
+        } else {
+            Some(PdfPageWidgetAnnotation::from_pdfium(
+                self.document_handle,
+                self.page_handle,
+                annotation_handle,
+                self.form_handle,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns all the annotations in this [PdfPageAnnotations] collection that lie under the
+    /// given point on the containing `PdfPage`, ordered topmost-first. Annotations later in the
+    /// collection are drawn over earlier ones, so are returned first.
+    ///
+    /// Annotations with attachment points, such as text markup annotations, are hit-tested
+    /// against their attachment points rather than their looser bounding box. All other
+    /// annotations are hit-tested against their bounds. Points lying exactly on an edge
+    /// are considered to be inside.
+    ///
+    /// The given point is expressed in the page coordinate system, the same coordinate system
+    /// used by [PdfPageAnnotationCommon::bounds()]. This coordinate system is not affected by
+    /// the page's rotation, and its origin need not lie at the bottom left corner of the page.
+    /// To hit-test a position in a rendered bitmap, first convert it to page coordinates
+    /// using the `PdfPage::pixels_to_points()` function, which accounts for both.
+    pub fn annotations_at_point(&self, x: PdfPoints, y: PdfPoints) -> Vec<PdfPageAnnotation<'a>> {
+        self.as_range()
+            .rev()
+            .filter_map(|index| self.get(index).ok())
+            .filter(|annotation| {
+                if annotation.has_attachment_points() && !annotation.attachment_points().is_empty()
+                {
+                    annotation
+                        .attachment_points()
+                        .iter()
+                        .any(|quad_points| quad_points.contains(x, y))
+                } else {
+                    annotation
+                        .bounds()
+                        .map(|bounds| bounds.contains(x, y))
+                        .unwrap_or(false)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over all the annotations in this [PdfPageAnnotations] collection
+    /// that were written in reply to the given [PdfPageAnnotation].
+    #[inline]
+    pub fn replies(&self, annotation: &PdfPageAnnotation) -> PdfPageAnnotationRepliesIterator {
+        PdfPageAnnotationRepliesIterator::new(
+            self,
+            self.bindings
+                .FPDFPage_GetAnnotIndex(self.page_handle, annotation.handle()),
+        )
+    }
+
     /// Returns an iterator over all the annotations in this [PdfPageAnnotations] collection.
     #[inline]
     pub fn iter(&self) -> PdfPageAnnotationsIterator {
         PdfPageAnnotationsIterator::new(self)
     }
 
+    /// Returns an iterator over all the annotations of the given [PdfPageAnnotationType]
+    /// in this [PdfPageAnnotations] collection.
+    #[inline]
+    pub fn of_type(
+        &self,
+        annotation_type: PdfPageAnnotationType,
+    ) -> PdfPageAnnotationsOfTypeIterator {
+        PdfPageAnnotationsOfTypeIterator::new(self, annotation_type)
+    }
+
+    /// Returns an iterator over all the link annotations in this [PdfPageAnnotations] collection.
+    #[inline]
+    pub fn links(&self) -> PdfPageAnnotationsFilterIterator<PdfPageLinkAnnotation> {
+        PdfPageAnnotationsFilterIterator::new(self, |annotation| match annotation {
+            PdfPageAnnotation::Link(annotation) => Some(annotation),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all the highlight annotations in this [PdfPageAnnotations] collection.
+    #[inline]
+    pub fn highlights(&self) -> PdfPageAnnotationsFilterIterator<PdfPageHighlightAnnotation> {
+        PdfPageAnnotationsFilterIterator::new(self, |annotation| match annotation {
+            PdfPageAnnotation::Highlight(annotation) => Some(annotation),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all the widget annotations in this [PdfPageAnnotations] collection.
+    #[inline]
+    pub fn widgets(&self) -> PdfPageAnnotationsFilterIterator<PdfPageWidgetAnnotation> {
+        PdfPageAnnotationsFilterIterator::new(self, |annotation| match annotation {
+            PdfPageAnnotation::Widget(annotation) => Some(annotation),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all the text annotations in this [PdfPageAnnotations] collection.
+    #[inline]
+    pub fn text_annotations(&self) -> PdfPageAnnotationsFilterIterator<PdfPageTextAnnotation> {
+        PdfPageAnnotationsFilterIterator::new(self, |annotation| match annotation {
+            PdfPageAnnotation::Text(annotation) => Some(annotation),
+            _ => None,
+        })
+    }
+
     // Regenerates the content of the containing [PdfPage] if necessary after this
     // [PdfPageAnnotations] collection has been mutated.
     fn regenerate_content(&self) -> Result<(), PdfiumError> {
@@ -202,15 +370,60 @@ impl<'a> PdfPageAnnotations<'a> {
         }
     }
 
-    /// Creates a new [PdfPageFreeTextAnnotation] containing the given text in this
-    /// [PdfPageAnnotations] collection, returning the newly created annotation.
+    /// Creates a new [PdfPageFileAttachmentAnnotation] annotation in this [PdfPageAnnotations]
+    /// collection, returning the newly created annotation.
     ///
     /// If the containing `PdfPage` has a content regeneration strategy of
     /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
     /// will be triggered on the page.
     #[inline]
+    pub fn create_file_attachment_annotation(
+        &mut self,
+    ) -> Result<PdfPageFileAttachmentAnnotation<'a>, PdfiumError> {
+        self.create_annotation(
+            PdfPageAnnotationType::FileAttachment,
+            PdfPageFileAttachmentAnnotation::from_pdfium,
+        )
+    }
+
+    /// Creates a new [PdfPageFileAttachmentAnnotation] covering the given bounds and embedding
+    /// the given byte data under the given file name at the end of this [PdfPageAnnotations]
+    /// collection, returning the newly created annotation. The annotation is displayed by
+    /// conforming PDF viewers using the given [PdfFileAttachmentIcon].
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_file_attachment_annotation_at_end(
+        &mut self,
+        bounds: PdfRect,
+        icon: PdfFileAttachmentIcon,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<PdfPageFileAttachmentAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_file_attachment_annotation()?;
+
+        annotation.set_bounds(bounds)?;
+        annotation.set_icon(icon)?;
+        annotation.set_attachment(name, bytes)?;
+
+        self.regenerate_content().map(|()| annotation)
+    }
+
+    /// Creates a new [PdfPageFreeTextAnnotation] covering the given bounds and containing
+    /// the given text in this [PdfPageAnnotations] collection, returning the newly
+    /// created annotation.
+    ///
+    /// An appearance stream is generated so that the text, in 12 point black Helvetica, is
+    /// rendered by Pdfium and by other PDF viewers. Use the setters on the returned annotation
+    /// to change the font size, text color, or border.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
     pub fn create_free_text_annotation(
         &mut self,
+        bounds: PdfRect,
         text: &str,
     ) -> Result<PdfPageFreeTextAnnotation<'a>, PdfiumError> {
         let mut annotation = self.create_annotation(
@@ -219,8 +432,36 @@ impl<'a> PdfPageAnnotations<'a> {
         )?;
 
         annotation.set_contents(text)?;
+        annotation.set_bounds(bounds)?;
+        annotation.regenerate_appearance()?;
 
-        Ok(annotation)
+        self.regenerate_content().map(|()| annotation)
+    }
+
+    /// Creates a new [PdfPageFreeTextAnnotation] covering the given bounds and displaying the
+    /// given text in the given font, font size, and text color at the end of this
+    /// [PdfPageAnnotations] collection, returning the newly created annotation.
+    ///
+    /// The annotation's default appearance string is set from the given font, font size,
+    /// and color, and an appearance stream is generated so that the text is rendered
+    /// when the page is rendered with annotations enabled.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_free_text_annotation_at_end(
+        &mut self,
+        bounds: PdfRect,
+        text: &str,
+        font: &PdfFont,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Result<PdfPageFreeTextAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_free_text_annotation(bounds, text)?;
+
+        annotation.set_default_appearance(font, font_size, color)?;
+
+        self.regenerate_content().map(|()| annotation)
     }
 
     /// Creates a new [PdfPageHighlightAnnotation] annotation in this [PdfPageAnnotations] collection,
@@ -296,6 +537,101 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageLinkAnnotation] covering the given bounds and pointing to the
+    /// given [PdfLinkTarget] at the end of this [PdfPageAnnotations] collection, returning the
+    /// newly created annotation.
+    ///
+    /// The annotation is flagged as printable and is given a zero-width border, so that
+    /// PDF viewers honor the clickable area without drawing a visible box around it.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_link_annotation_at_end(
+        &mut self,
+        bounds: PdfRect,
+        target: PdfLinkTarget,
+    ) -> Result<PdfPageLinkAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_annotation(
+            PdfPageAnnotationType::Link,
+            PdfPageLinkAnnotation::from_pdfium,
+        )?;
+
+        annotation.set_bounds(bounds)?;
+
+        match target {
+            PdfLinkTarget::Uri(uri) => annotation.set_link(uri.as_str())?,
+            PdfLinkTarget::PageDestination {
+                page_index,
+                x,
+                y,
+                zoom,
+            } => self.set_annotation_dest(annotation.handle(), page_index, x, y, zoom)?,
+        }
+
+        // Link annotations without the print flag are ignored by some viewers, and
+        // link annotations without an explicit border are drawn with a one point
+        // black border by default.
+
+        let handle = annotation.handle();
+
+        if !self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_SetFlags(handle, FPDF_ANNOT_FLAG_PRINT as c_int),
+        ) {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFAnnot_SetBorder(handle, 0.0, 0.0, 0.0))
+        {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        self.regenerate_content().map(|()| annotation)
+    }
+
+    // Points the link annotation with the given handle at the given location on the
+    // page with the given index in the containing document.
+    fn set_annotation_dest(
+        &self,
+        annotation_handle: FPDF_ANNOTATION,
+        page_index: PdfPageIndex,
+        x: PdfPoints,
+        y: PdfPoints,
+        zoom: f32,
+    ) -> Result<(), PdfiumError> {
+        // FPDFAnnot_SetDest() requires a handle to the target page. We load the page
+        // only for as long as it takes to set the destination.
+
+        let page_handle = self
+            .bindings
+            .FPDF_LoadPage(self.document_handle, page_index as c_int);
+
+        if page_handle.is_null() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        let result =
+            self.bindings
+                .FPDFAnnot_SetDest(annotation_handle, page_handle, x.value, y.value, zoom);
+
+        self.bindings.FPDF_ClosePage(page_handle);
+
+        if self.bindings.is_true(result) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
     /// Creates a new [PdfPagePopupAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -577,61 +913,3422 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
-    /// Removes the given [PdfPageAnnotation] from this [PdfPageAnnotations] collection,
-    /// consuming the [PdfPageAnnotation].
+    /// Creates a new [PdfPageHighlightAnnotation] covering exactly the characters in the given
+    /// range of character indices in the containing `PdfPage`, coloring it with the given [PdfColor].
+    ///
+    /// One attachment point is created for each line of text spanned by the range, following
+    /// the rotation of the characters on that line, so a range that runs past the end of one
+    /// line and onto the next is highlighted correctly. The annotation's opacity is set to 0.4,
+    /// its contents are set to the highlighted text, and an appearance stream is generated
+    /// in the same way as [PdfPageAnnotations::create_highlight_annotation_at_end()].
+    ///
+    /// The range of characters matching a text search can be retrieved from each search result
+    /// using the `PdfPageTextSegments::char_range()` function.
     ///
     /// If the containing `PdfPage` has a content regeneration strategy of
     /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
     /// will be triggered on the page.
-    pub fn delete_annotation(
+    pub fn create_highlight_annotation_over_text(
         &mut self,
-        annotation: PdfPageAnnotation<'a>,
-    ) -> Result<(), PdfiumError> {
-        let index = self
-            .bindings
-            .FPDFPage_GetAnnotIndex(self.page_handle, annotation.handle());
+        chars: Range<PdfPageTextCharIndex>,
+        color: PdfColor,
+    ) -> Result<PdfPageHighlightAnnotation<'a>, PdfiumError> {
+        let text_page_handle = self.bindings().FPDFText_LoadPage(self.page_handle);
 
-        if index == -1 {
-            return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+        if text_page_handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
         }
 
-        if self
-            .bindings
-            .is_true(self.bindings.FPDFPage_RemoveAnnot(self.page_handle, index))
-        {
-            self.regenerate_content()
+        let char_count = self.bindings().FPDFText_CountChars(text_page_handle).max(0);
+
+        let result = if chars.is_empty() || chars.end > char_count as PdfPageTextCharIndex {
+            Err(PdfiumError::CharIndexOutOfBounds)
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
-            ))
-        }
+            let chars = PdfPageTextChars::new(
+                text_page_handle,
+                chars.start as i32,
+                chars.len() as i32,
+                self.bindings(),
+            );
+
+            // Line breaks generated by Pdfium between lines of text are replaced by spaces.
+
+            let mut text = String::new();
+
+            for char in chars.iter() {
+                match char.unicode_char() {
+                    Some('\r') | Some('\n') if !text.ends_with(' ') => text.push(' '),
+                    Some('\r') | Some('\n') => {}
+                    Some(value) => text.push(value),
+                    None => {}
+                }
+            }
+
+            Ok((text.trim().to_owned(), chars.quad_points_by_line()))
+        };
+
+        self.bindings().FPDFText_ClosePage(text_page_handle);
+
+        let (text, attachment_points) = result?;
+
+        self.create_text_markup_annotation_at_end(
+            PdfPageAnnotationType::Highlight,
+            PdfPageHighlightAnnotation::from_pdfium,
+            attachment_points.as_slice(),
+            color,
+            None,
+            Some(text.as_str()),
+        )
     }
-}
 
-/// An iterator over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection.
-pub struct PdfPageAnnotationsIterator<'a> {
-    annotations: &'a PdfPageAnnotations<'a>,
-    next_index: PdfPageAnnotationIndex,
-}
+    /// Creates a new [PdfPageHighlightAnnotation] covering the given attachment points, for
+    /// instance the quad points of each line of a text search result, coloring it with the
+    /// given [PdfColor].
+    ///
+    /// Attachment points must be expressed in page coordinates, with the vertices of each
+    /// attachment point ordered top left, top right, bottom left, bottom right relative to the
+    /// text they enclose, as returned by [PdfQuadPoints::from_rect()] and by the character
+    /// bounds functions of `PdfPageText`. Use [PdfQuadPoints::from_rect()] to highlight
+    /// rectangles, such as the bounds of each line of a `PdfPageTextSegment`.
+    ///
+    /// One attachment point is created for each set of quad points. If an author is supplied,
+    /// it is recorded in the annotation. The annotation's opacity is set to 0.4, and
+    /// an appearance stream filling each attachment point is generated so that the highlight
+    /// is rendered consistently by Pdfium and by other PDF viewers.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn create_highlight_annotation_at_end(
+        &mut self,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+    ) -> Result<PdfPageHighlightAnnotation<'a>, PdfiumError> {
+        self.create_text_markup_annotation_at_end(
+            PdfPageAnnotationType::Highlight,
+            PdfPageHighlightAnnotation::from_pdfium,
+            quad_points,
+            color,
+            author,
+            None,
+        )
+    }
 
-impl<'a> PdfPageAnnotationsIterator<'a> {
+    /// Creates a new [PdfPageUnderlineAnnotation] underlining the given attachment points,
+    /// coloring it with the given [PdfColor].
+    ///
+    /// Attachment points must be expressed in page coordinates and ordered as described in
+    /// [PdfPageAnnotations::create_highlight_annotation_at_end()]; the underline is drawn
+    /// along the bottom edge of each attachment point, following its rotation. If an author
+    /// is supplied, it is recorded in the annotation. An appearance stream is generated so that
+    /// the underline is rendered consistently by Pdfium and by other PDF viewers.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
     #[inline]
-    pub(crate) fn new(annotations: &'a PdfPageAnnotations<'a>) -> Self {
-        PdfPageAnnotationsIterator {
-            annotations,
-            next_index: 0,
-        }
+    pub fn create_underline_annotation_at_end(
+        &mut self,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+    ) -> Result<PdfPageUnderlineAnnotation<'a>, PdfiumError> {
+        self.create_text_markup_annotation_at_end(
+            PdfPageAnnotationType::Underline,
+            PdfPageUnderlineAnnotation::from_pdfium,
+            quad_points,
+            color,
+            author,
+            None,
+        )
     }
-}
 
-impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
-    type Item = PdfPageAnnotation<'a>;
+    /// Creates a new [PdfPageStrikeoutAnnotation] striking through the given attachment points,
+    /// coloring it with the given [PdfColor].
+    ///
+    /// Attachment points must be expressed in page coordinates and ordered as described in
+    /// [PdfPageAnnotations::create_highlight_annotation_at_end()]. If an author is supplied,
+    /// it is recorded in the annotation. An appearance stream is generated so that
+    /// the strikeout is rendered consistently by Pdfium and by other PDF viewers.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn create_strikeout_annotation_at_end(
+        &mut self,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+    ) -> Result<PdfPageStrikeoutAnnotation<'a>, PdfiumError> {
+        self.create_text_markup_annotation_at_end(
+            PdfPageAnnotationType::Strikeout,
+            PdfPageStrikeoutAnnotation::from_pdfium,
+            quad_points,
+            color,
+            author,
+            None,
+        )
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let next = self.annotations.get(self.next_index);
+    /// Creates a new [PdfPageSquigglyAnnotation] drawing a wavy line underneath the given
+    /// attachment points, coloring it with the given [PdfColor].
+    ///
+    /// Attachment points must be expressed in page coordinates and ordered as described in
+    /// [PdfPageAnnotations::create_highlight_annotation_at_end()]. If an author is supplied,
+    /// it is recorded in the annotation. An appearance stream containing the wavy line is
+    /// generated so that the annotation is rendered consistently by Pdfium and by other
+    /// PDF viewers.
+    ///
+    /// Returns [PdfiumError::NoRectsForAttachmentPoints] if the given slice is empty.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn create_squiggly_annotation_at_end(
+        &mut self,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+    ) -> Result<PdfPageSquigglyAnnotation<'a>, PdfiumError> {
+        self.create_text_markup_annotation_at_end(
+            PdfPageAnnotationType::Squiggly,
+            PdfPageSquigglyAnnotation::from_pdfium,
+            quad_points,
+            color,
+            author,
+            None,
+        )
+    }
 
-        self.next_index += 1;
+    /// Creates a new [PdfPageInkAnnotation] containing the given freehand strokes, for instance
+    /// a captured signature, drawing each stroke with the given [PdfColor] and line width.
+    ///
+    /// Each stroke is a continuous list of `(x, y)` points expressed in page coordinates.
+    /// The bounds of the annotation are set to enclose every stroke, and an appearance stream
+    /// is generated so that the ink is rendered consistently by Pdfium and by other PDF viewers.
+    ///
+    /// Returns [PdfiumError::NoPointsInInkStroke] if the given slice is empty or if any
+    /// stroke contains no points; no annotation is created in that case.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_ink_annotation_at_end(
+        &mut self,
+        strokes: &[Vec<(PdfPoints, PdfPoints)>],
+        color: PdfColor,
+        width: PdfPoints,
+    ) -> Result<PdfPageInkAnnotation<'a>, PdfiumError> {
+        if strokes.is_empty() || strokes.iter().any(|stroke| stroke.is_empty()) {
+            return Err(PdfiumError::NoPointsInInkStroke);
+        }
 
-        next.ok()
+        let mut annotation = self.create_ink_annotation()?;
+
+        // The border width must be set before any strokes are added, since the bounds of
+        // the annotation are padded by half the border width.
+
+        annotation.set_border(PdfAnnotationBorder::new(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            width,
+        ))?;
+        annotation.set_stroke_color(color)?;
+
+        for stroke in strokes {
+            annotation.add_stroke(stroke)?;
+        }
+
+        // The appearance stream must be set last, since Pdfium will not update the
+        // annotation's color once an appearance stream is present.
+
+        let stream = ink_appearance_stream(strokes, color, width);
+
+        if !self.bindings().is_true(self.bindings().FPDFAnnot_SetAP_str(
+            annotation.handle(),
+            PdfAppearanceMode::Normal.as_pdfium(),
+            stream.as_str(),
+        )) {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        self.regenerate_content().map(|()| annotation)
+    }
+
+    /// Creates a new [PdfPageStampAnnotation] covering the given bounds and displaying the
+    /// given [PdfBitmap] at the end of this [PdfPageAnnotations] collection, returning the
+    /// newly created annotation.
+    ///
+    /// The bitmap is scaled to fill the given bounds, without preserving its aspect ratio.
+    /// Unlike an image page object, the stamp is an annotation rather than part of the
+    /// page's content, so it can later be removed on its own by calling
+    /// [PdfPageAnnotations::delete_annotation()].
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_stamp_annotation_at_end(
+        &mut self,
+        bounds: PdfRect,
+        bitmap: &PdfBitmap,
+    ) -> Result<PdfPageStampAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_stamp_annotation()?;
+
+        annotation.set_bitmap(bitmap, bounds)?;
+
+        self.regenerate_content().map(|()| annotation)
+    }
+
+    // Creates a new highlight, underline, strikeout, or squiggly annotation covering the given
+    // attachment points, setting its color, author, contents, and attachment points and
+    // generating its appearance stream. If any of these cannot be set, the partially
+    // configured annotation is removed again.
+    fn create_text_markup_annotation_at_end<T: PdfPageAnnotationPrivate<'a>>(
+        &mut self,
+        annotation_type: PdfPageAnnotationType,
+        constructor: fn(
+            FPDF_DOCUMENT,
+            FPDF_PAGE,
+            FPDF_ANNOTATION,
+            &'a dyn PdfiumLibraryBindings,
+        ) -> T,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+        contents: Option<&str>,
+    ) -> Result<T, PdfiumError> {
+        let bounds = PdfPageAnnotationAttachmentPoints::bounds_of(quad_points.iter().copied())
+            .ok_or(PdfiumError::NoRectsForAttachmentPoints)?;
+
+        let index = self.len();
+
+        let mut annotation = self.create_annotation(annotation_type, constructor)?;
+
+        match self.configure_text_markup_annotation(
+            &mut annotation,
+            annotation_type,
+            bounds,
+            quad_points,
+            color,
+            author,
+            contents,
+        ) {
+            Ok(()) => self.regenerate_content().map(|()| annotation),
+            Err(err) => {
+                // Remove the partially configured annotation.
+
+                drop(annotation);
+
+                self.bindings()
+                    .FPDFPage_RemoveAnnot(self.page_handle, index as c_int);
+
+                Err(err)
+            }
+        }
+    }
+
+    // Sets the properties of the given newly created highlight, underline, strikeout, or
+    // squiggly annotation and generates its appearance stream.
+    #[allow(clippy::too_many_arguments)]
+    fn configure_text_markup_annotation<T: PdfPageAnnotationPrivate<'a>>(
+        &self,
+        annotation: &mut T,
+        annotation_type: PdfPageAnnotationType,
+        bounds: PdfRect,
+        quad_points: &[PdfQuadPoints],
+        color: PdfColor,
+        author: Option<&str>,
+        contents: Option<&str>,
+    ) -> Result<(), PdfiumError> {
+        annotation.set_bounds(bounds)?;
+        annotation.set_stroke_color(color)?;
+
+        if annotation_type == PdfPageAnnotationType::Highlight {
+            annotation.set_opacity(0.4)?;
+        }
+
+        if let Some(author) = author {
+            annotation.set_author(author)?;
+        }
+
+        if let Some(contents) = contents {
+            annotation.set_contents(contents)?;
+        }
+
+        annotation
+            .attachment_points_mut_impl()
+            .set_all(quad_points)?;
+
+        // The appearance stream must be set last, since Pdfium will not update the
+        // annotation's color once an appearance stream is present.
+
+        let stream = text_markup_appearance_stream(annotation_type, quad_points, color);
+
+        if self.bindings().is_true(self.bindings().FPDFAnnot_SetAP_str(
+            annotation.handle(),
+            PdfAppearanceMode::Normal.as_pdfium(),
+            stream.as_str(),
+        )) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Copies the given [PdfPageAnnotation], which may belong to a page in a different
+    /// `PdfDocument`, to the end of this [PdfPageAnnotations] collection, returning the newly
+    /// created annotation. The given [PdfMatrix] is applied to the position of the copy,
+    /// so the copy can be placed differently on the destination page.
+    ///
+    /// The copy has the same annotation type as the original, and receives the original's
+    /// bounds, contents, author, subject, flags, colors, opacity, border, and attachment
+    /// points. Ink strokes, embedded files, and the default appearance of free text
+    /// annotations are copied as well, as are the page objects of stamp annotations.
+    ///
+    /// The content of the original's appearance streams is copied, with the given [PdfMatrix]
+    /// applied, but the resources used by those streams are not, because they belong to
+    /// the original document. Pdfium provides no way to copy them. An appearance stream that
+    /// draws text or images, or that is not drawn in page coordinates, will therefore not
+    /// display correctly in the copy. The appearance stream of a free text annotation is
+    /// instead regenerated from its copied default appearance, and the page objects of
+    /// a stamp annotation are re-created in this document, so that images are embedded afresh
+    /// rather than referring back to the original document. Stamps containing text objects
+    /// cannot be copied, because Pdfium provides no way to retrieve the text of page objects
+    /// inside an annotation.
+    ///
+    /// The URI of a link annotation is copied, but any destination within the original
+    /// document is dropped. To keep destinations, use the
+    /// [PdfPageAnnotations::copy_annotation_from_with_page_mapping()] function.
+    ///
+    /// Returns [PdfiumError::PageAnnotationNotCopyable] if Pdfium cannot create annotations of
+    /// the given annotation's type, or if a page object of a stamp annotation cannot be copied.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn copy_annotation_from(
+        &mut self,
+        source: &PdfPageAnnotation,
+        transform: PdfMatrix,
+    ) -> Result<PdfPageAnnotation<'a>, PdfiumError> {
+        self.copy_annotation_from_with_page_mapping(source, transform, |_| None)
+    }
+
+    /// Copies the given [PdfPageAnnotation] to the end of this [PdfPageAnnotations] collection
+    /// in the same way as [PdfPageAnnotations::copy_annotation_from()], remapping the
+    /// destination of a link annotation using the given function.
+    ///
+    /// The function receives the index of the page targeted by the original link in the
+    /// original document, and returns the index of the page in this document the copy should
+    /// target, or `None` if the destination should be dropped. Pass `|index| Some(index)` to
+    /// keep destinations unchanged when copying within a single document.
+    pub fn copy_annotation_from_with_page_mapping<F>(
+        &mut self,
+        source: &PdfPageAnnotation,
+        transform: PdfMatrix,
+        page_index_mapping: F,
+    ) -> Result<PdfPageAnnotation<'a>, PdfiumError>
+    where
+        F: Fn(PdfPageIndex) -> Option<PdfPageIndex>,
+    {
+        let annotation_type = source.annotation_type();
+
+        if !self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_IsSupportedSubtype(annotation_type.as_pdfium()),
+        ) {
+            return Err(PdfiumError::PageAnnotationNotCopyable);
+        }
+
+        // Check that every page object can be copied before creating the annotation,
+        // so that we do not leave a partial copy behind. Pdfium cannot retrieve the text
+        // of text objects inside annotations, so text objects cannot be copied.
+
+        if annotation_type == PdfPageAnnotationType::Stamp
+            && !source
+                .objects()
+                .iter()
+                .all(|object| !matches!(object, PdfPageObject::Text(_)) && object.is_copyable())
+        {
+            return Err(PdfiumError::PageAnnotationNotCopyable);
+        }
+
+        let index = self.len();
+
+        let handle = self
+            .bindings
+            .FPDFPage_CreateAnnot(self.page_handle, annotation_type.as_pdfium());
+
+        if handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let mut annotation = PdfPageAnnotation::from_pdfium(
+            self.document_handle,
+            self.page_handle,
+            handle,
+            self.form_handle,
+            self.bindings,
+        );
+
+        match self.copy_annotation_properties(
+            source,
+            &mut annotation,
+            transform,
+            &page_index_mapping,
+        ) {
+            Ok(()) => self.regenerate_content().map(|()| annotation),
+            Err(err) => {
+                // Remove the partially copied annotation.
+
+                drop(annotation);
+
+                self.bindings
+                    .FPDFPage_RemoveAnnot(self.page_handle, index as c_int);
+
+                Err(err)
+            }
+        }
+    }
+
+    // Copies the properties of the given source annotation to the given newly created
+    // annotation of the same type.
+    fn copy_annotation_properties<F>(
+        &self,
+        source: &PdfPageAnnotation,
+        annotation: &mut PdfPageAnnotation<'a>,
+        transform: PdfMatrix,
+        page_index_mapping: &F,
+    ) -> Result<(), PdfiumError>
+    where
+        F: Fn(PdfPageIndex) -> Option<PdfPageIndex>,
+    {
+        annotation.set_creation_date(Utc::now())?;
+        annotation.set_flags(source.flags())?;
+
+        if let Some(contents) = source.contents() {
+            annotation.set_contents(&contents)?;
+        }
+
+        if let Some(author) = source.author() {
+            annotation.set_author(&author)?;
+        }
+
+        if let Some(subject) = source.subject() {
+            annotation.set_subject(&subject)?;
+        }
+
+        // FPDFAnnot_GetColor() fails for annotations with appearance streams, in which case
+        // there is no color to copy; the copy will be drawn using Pdfium's default colors.
+
+        for (key, color_type) in [
+            ("C", FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color),
+            ("IC", FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor),
+        ]
+        .iter()
+        {
+            if !source.has_key(key) {
+                continue;
+            }
+
+            let mut r: c_uint = 0;
+
+            let mut g: c_uint = 0;
+
+            let mut b: c_uint = 0;
+
+            let mut a: c_uint = 0;
+
+            if self.bindings.is_true(self.bindings.FPDFAnnot_GetColor(
+                source.handle(),
+                *color_type,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )) && !self.bindings.is_true(self.bindings.FPDFAnnot_SetColor(
+                annotation.handle(),
+                *color_type,
+                r,
+                g,
+                b,
+                a,
+            )) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+        }
+
+        if source.has_key("CA") {
+            annotation.set_opacity(source.opacity()?)?;
+        }
+
+        if source.has_key("Border") {
+            annotation.set_border(source.border())?;
+        }
+
+        if source.has_attachment_points() {
+            for attachment_point in source.attachment_points().iter() {
+                annotation
+                    .attachment_points_mut_impl()
+                    .create_attachment_point_at_end(attachment_point.transform(transform))?;
+            }
+        }
+
+        if let (Some(source), Some(annotation)) = (
+            source.as_ink_annotation(),
+            annotation.as_ink_annotation_mut(),
+        ) {
+            for stroke in source.strokes() {
+                let points = stroke
+                    .iter()
+                    .map(|(x, y)| transform.apply_to_points(*x, *y))
+                    .collect::<Vec<_>>();
+
+                annotation.add_stroke(&points)?;
+            }
+        }
+
+        if let (Some(source), Some(annotation)) = (
+            source.as_link_annotation(),
+            annotation.as_link_annotation_mut(),
+        ) {
+            if let Ok(link) = source.link() {
+                if let Some(uri) = link.uri()? {
+                    annotation.set_link(&uri)?;
+                } else if let Some(destination) = link.destination() {
+                    if let Some(page_index) =
+                        destination.page_index().ok().and_then(page_index_mapping)
+                    {
+                        let (x, y, zoom) = match destination.view_settings()? {
+                            PdfDestinationViewSettings::SpecificCoordinatesAndZoom(x, y, zoom) => (
+                                x.unwrap_or(PdfPoints::ZERO),
+                                y.unwrap_or(PdfPoints::ZERO),
+                                zoom.unwrap_or(0.0),
+                            ),
+                            _ => (PdfPoints::ZERO, PdfPoints::ZERO, 0.0),
+                        };
+
+                        self.set_annotation_dest(annotation.handle(), page_index, x, y, zoom)?;
+                    }
+                }
+            }
+        }
+
+        if let (Some(source), Some(annotation)) = (
+            source.as_file_attachment_annotation(),
+            annotation.as_file_attachment_annotation_mut(),
+        ) {
+            if let Some(icon) = source.icon() {
+                annotation.set_icon(icon)?;
+            }
+
+            if let Some(attachment) = source.attachment() {
+                annotation.set_attachment(&attachment.name(), &attachment.save_to_bytes()?)?;
+            }
+        }
+
+        if let Some(default_appearance) = source
+            .as_free_text_annotation()
+            .and_then(|source| source.default_appearance())
+        {
+            annotation.set_string_value("DA", &default_appearance)?;
+        }
+
+        if let (Some(source), Some(annotation)) = (
+            source.as_stamp_annotation(),
+            annotation.as_stamp_annotation_mut(),
+        ) {
+            for object in source.objects().iter() {
+                let mut copy = object.try_copy_impl(self.document_handle, self.bindings)?;
+
+                copy.apply_matrix(transform)?;
+
+                annotation.objects_mut().add_object(copy)?;
+            }
+        }
+
+        // Adding ink strokes resizes an ink annotation to fit its strokes, so we set
+        // the bounds of the copy before copying its appearance streams, since the bounding
+        // box of each appearance stream is taken from the bounds of the annotation.
+
+        annotation.set_bounds(source.bounds()?.transform(transform))?;
+
+        if let Some(annotation) = annotation.as_free_text_annotation_mut() {
+            // Free text appearance streams are generated from the copied default appearance.
+
+            return annotation.regenerate_appearance();
+        }
+
+        if annotation.annotation_type() == PdfPageAnnotationType::Stamp {
+            // Pdfium already wrote a new appearance stream when the page objects
+            // of the stamp were added.
+
+            return Ok(());
+        }
+
+        for appearance_mode in [
+            PdfAppearanceMode::Normal,
+            PdfAppearanceMode::RollOver,
+            PdfAppearanceMode::Down,
+        ]
+        .iter()
+        {
+            if let Some(content) = source.appearance_stream(*appearance_mode) {
+                annotation.set_appearance_stream(
+                    *appearance_mode,
+                    Some(
+                        format!(
+                            "q {} {} {} {} {} {} cm {} Q",
+                            format_pdf_number(transform.a()),
+                            format_pdf_number(transform.b()),
+                            format_pdf_number(transform.c()),
+                            format_pdf_number(transform.d()),
+                            format_pdf_number(transform.e()),
+                            format_pdf_number(transform.f()),
+                            content
+                        )
+                        .as_str(),
+                    ),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the given [PdfPageAnnotation] from this [PdfPageAnnotations] collection,
+    /// consuming the [PdfPageAnnotation].
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn delete_annotation(
+        &mut self,
+        annotation: PdfPageAnnotation<'a>,
+    ) -> Result<(), PdfiumError> {
+        let index = self
+            .bindings
+            .FPDFPage_GetAnnotIndex(self.page_handle, annotation.handle());
+
+        if index == -1 {
+            return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+        }
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPage_RemoveAnnot(self.page_handle, index))
+        {
+            self.regenerate_content()
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Removes the given [PdfPageAnnotation] from this [PdfPageAnnotations] collection,
+    /// along with its associated popup annotation, if any, consuming the [PdfPageAnnotation].
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn delete_with_popup(
+        &mut self,
+        annotation: PdfPageAnnotation<'a>,
+    ) -> Result<(), PdfiumError> {
+        let index = self
+            .bindings
+            .FPDFPage_GetAnnotIndex(self.page_handle, annotation.handle());
+
+        if index == -1 {
+            return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+        }
+
+        let popup_index = annotation.popup().map(|popup| {
+            self.bindings
+                .FPDFPage_GetAnnotIndex(self.page_handle, popup.handle())
+        });
+
+        drop(annotation);
+
+        // Remove the annotation with the higher index first, so that removing it does not
+        // shift the index of the other.
+
+        let mut indices = vec![index];
+
+        if let Some(popup_index) = popup_index {
+            if popup_index != -1 {
+                indices.push(popup_index);
+            }
+        }
+
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+
+        for index in indices {
+            if !self
+                .bindings
+                .is_true(self.bindings.FPDFPage_RemoveAnnot(self.page_handle, index))
+            {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+        }
+
+        self.regenerate_content()
+    }
+
+    /// Removes every [PdfPageAnnotation] in this [PdfPageAnnotations] collection for which
+    /// the given predicate returns `false`, returning the number of annotations removed.
+    ///
+    /// Annotations are passed to the predicate in reverse order, from the highest index
+    /// downward, so that removing an annotation never changes the index of an annotation
+    /// that has not yet been visited. Content regeneration is not triggered on the
+    /// containing `PdfPage`.
+    pub fn retain<F>(&mut self, mut predicate: F) -> Result<PdfPageAnnotationIndex, PdfiumError>
+    where
+        F: FnMut(&PdfPageAnnotation<'a>) -> bool,
+    {
+        let mut removed = 0;
+
+        for index in self.as_range().rev() {
+            let annotation = self.get(index)?;
+
+            let keep = predicate(&annotation);
+
+            drop(annotation);
+
+            if !keep {
+                if !self.bindings.is_true(
+                    self.bindings
+                        .FPDFPage_RemoveAnnot(self.page_handle, index as c_int),
+                ) {
+                    return Err(PdfiumError::PdfiumLibraryInternalError(
+                        PdfiumInternalError::Unknown,
+                    ));
+                }
+
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Removes every [PdfPageAnnotation] of the given [PdfPageAnnotationType] from this
+    /// [PdfPageAnnotations] collection, returning the number of annotations removed.
+    ///
+    /// Content regeneration is not triggered on the containing `PdfPage`.
+    #[inline]
+    pub fn delete_all_of_type(
+        &mut self,
+        annotation_type: PdfPageAnnotationType,
+    ) -> Result<PdfPageAnnotationIndex, PdfiumError> {
+        self.retain(|annotation| annotation.annotation_type() != annotation_type)
+    }
+}
+
+/// An iterator over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection.
+pub struct PdfPageAnnotationsIterator<'a> {
+    annotations: &'a PdfPageAnnotations<'a>,
+    next_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfPageAnnotationsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(annotations: &'a PdfPageAnnotations<'a>) -> Self {
+        PdfPageAnnotationsIterator {
+            annotations,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
+    type Item = PdfPageAnnotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.annotations.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+/// An iterator over all the [PdfPageAnnotation] objects of a single [PdfPageAnnotationType]
+/// in a [PdfPageAnnotations] collection.
+pub struct PdfPageAnnotationsOfTypeIterator<'a> {
+    annotations: &'a PdfPageAnnotations<'a>,
+    annotation_type: PdfPageAnnotationType,
+    next_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfPageAnnotationsOfTypeIterator<'a> {
+    #[inline]
+    pub(crate) fn new(
+        annotations: &'a PdfPageAnnotations<'a>,
+        annotation_type: PdfPageAnnotationType,
+    ) -> Self {
+        PdfPageAnnotationsOfTypeIterator {
+            annotations,
+            annotation_type,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageAnnotationsOfTypeIterator<'a> {
+    type Item = PdfPageAnnotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.annotations.len() {
+            let next = self.annotations.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Ok(annotation) = next {
+                if annotation.annotation_type() == self.annotation_type {
+                    return Some(annotation);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the annotations in a [PdfPageAnnotations] collection that unwraps
+/// each matching [PdfPageAnnotation] into its concrete annotation type, skipping all
+/// annotations of other types.
+pub struct PdfPageAnnotationsFilterIterator<'a, T> {
+    annotations: &'a PdfPageAnnotations<'a>,
+    filter: fn(PdfPageAnnotation<'a>) -> Option<T>,
+    next_index: PdfPageAnnotationIndex,
+}
+
+impl<'a, T> PdfPageAnnotationsFilterIterator<'a, T> {
+    #[inline]
+    pub(crate) fn new(
+        annotations: &'a PdfPageAnnotations<'a>,
+        filter: fn(PdfPageAnnotation<'a>) -> Option<T>,
+    ) -> Self {
+        PdfPageAnnotationsFilterIterator {
+            annotations,
+            filter,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PdfPageAnnotationsFilterIterator<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.annotations.len() {
+            let next = self.annotations.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Some(annotation) = next.ok().and_then(self.filter) {
+                return Some(annotation);
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection
+/// that were written in reply to a single parent annotation.
+pub struct PdfPageAnnotationRepliesIterator<'a> {
+    annotations: &'a PdfPageAnnotations<'a>,
+    parent_index: c_int,
+    next_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfPageAnnotationRepliesIterator<'a> {
+    #[inline]
+    pub(crate) fn new(annotations: &'a PdfPageAnnotations<'a>, parent_index: c_int) -> Self {
+        PdfPageAnnotationRepliesIterator {
+            annotations,
+            parent_index,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageAnnotationRepliesIterator<'a> {
+    type Item = PdfPageAnnotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parent_index == -1 {
+            // The parent annotation is not attached to this page.
+
+            return None;
+        }
+
+        while self.next_index < self.annotations.len() {
+            let next = self.annotations.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Ok(annotation) = next {
+                let is_reply = annotation
+                    .in_reply_to()
+                    .map(|parent| {
+                        self.annotations
+                            .bindings
+                            .FPDFPage_GetAnnotIndex(self.annotations.page_handle, parent.handle())
+                            == self.parent_index
+                    })
+                    .unwrap_or(false);
+
+                if is_reply {
+                    return Some(annotation);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns an appearance stream drawing each of the given freehand strokes as a line
+/// with round caps and joins, so that single-point strokes are drawn as dots.
+fn ink_appearance_stream(
+    strokes: &[Vec<(PdfPoints, PdfPoints)>],
+    color: PdfColor,
+    width: PdfPoints,
+) -> String {
+    let mut stream = format!(
+        "q {} {} {} RG {} w 1 J 1 j",
+        format_pdf_number(color.red() as f32 / 255.0),
+        format_pdf_number(color.green() as f32 / 255.0),
+        format_pdf_number(color.blue() as f32 / 255.0),
+        format_pdf_number(width.value),
+    );
+
+    for stroke in strokes {
+        for (index, (x, y)) in stroke.iter().enumerate() {
+            stream.push_str(
+                format!(
+                    " {} {} {}",
+                    format_pdf_number(x.value),
+                    format_pdf_number(y.value),
+                    if index == 0 { "m" } else { "l" }
+                )
+                .as_str(),
+            );
+        }
+
+        if let [(x, y)] = stroke.as_slice() {
+            // A stroke containing a single point is drawn as a zero-length line.
+
+            stream.push_str(
+                format!(
+                    " {} {} l",
+                    format_pdf_number(x.value),
+                    format_pdf_number(y.value)
+                )
+                .as_str(),
+            );
+        }
+
+        stream.push_str(" S");
+    }
+
+    stream.push_str(" Q");
+
+    stream
+}
+
+/// Generates an appearance stream for a highlight, underline, strikeout, or squiggly
+/// annotation marking the given attachment points in the given color.
+///
+/// Pdfium creates appearance streams with a bounding box equal to the annotation's bounds
+/// and an identity matrix, so coordinates in the stream are page coordinates. Highlights
+/// fill each attachment point; other marks follow the bottom edge of their attachment point,
+/// so rotated text is marked correctly.
+fn text_markup_appearance_stream(
+    annotation_type: PdfPageAnnotationType,
+    quad_points: &[PdfQuadPoints],
+    color: PdfColor,
+) -> String {
+    let (red, green, blue) = (
+        format_pdf_number(color.red() as f32 / 255.0),
+        format_pdf_number(color.green() as f32 / 255.0),
+        format_pdf_number(color.blue() as f32 / 255.0),
+    );
+
+    let mut stream = if annotation_type == PdfPageAnnotationType::Highlight {
+        format!("q {} {} {} rg", red, green, blue)
+    } else {
+        format!("q {} {} {} RG 1 j", red, green, blue)
+    };
+
+    for quad_points in quad_points {
+        if annotation_type == PdfPageAnnotationType::Highlight {
+            // Highlights fill the whole attachment point.
+
+            stream.push_str(
+                format!(
+                    " {} {} m {} {} l {} {} l {} {} l h f",
+                    format_pdf_number(quad_points.x3.value),
+                    format_pdf_number(quad_points.y3.value),
+                    format_pdf_number(quad_points.x4.value),
+                    format_pdf_number(quad_points.y4.value),
+                    format_pdf_number(quad_points.x2.value),
+                    format_pdf_number(quad_points.y2.value),
+                    format_pdf_number(quad_points.x1.value),
+                    format_pdf_number(quad_points.y1.value),
+                )
+                .as_str(),
+            );
+
+            continue;
+        }
+
+        // (x3, y3) and (x4, y4) are the bottom left and bottom right corners of the
+        // attachment point; (x1, y1) is the top left corner.
+
+        let (left_x, left_y) = (quad_points.x3.value, quad_points.y3.value);
+
+        let (right_x, right_y) = (quad_points.x4.value, quad_points.y4.value);
+
+        let (up_x, up_y) = (quad_points.x1.value - left_x, quad_points.y1.value - left_y);
+
+        let height = (up_x * up_x + up_y * up_y).sqrt();
+
+        let length = ((right_x - left_x).powi(2) + (right_y - left_y).powi(2)).sqrt();
+
+        if height <= 0.0 || length <= 0.0 {
+            continue;
+        }
+
+        let (up_x, up_y) = (up_x / height, up_y / height);
+
+        let line_width = (height / 16.0).max(0.5);
+
+        // Returns the point the given distance along the bottom edge, offset perpendicularly
+        // towards the top edge by the given amount.
+
+        let point = |along: f32, offset: f32| {
+            let t = along / length;
+
+            format!(
+                "{} {}",
+                format_pdf_number(left_x + (right_x - left_x) * t + up_x * offset),
+                format_pdf_number(left_y + (right_y - left_y) * t + up_y * offset),
+            )
+        };
+
+        stream.push_str(format!(" {} w", format_pdf_number(line_width)).as_str());
+
+        match annotation_type {
+            PdfPageAnnotationType::Squiggly => {
+                // The wave alternates between two offsets above the bottom edge, completing
+                // one full period for every quarter of the attachment point's height.
+
+                let amplitude = height / 8.0;
+
+                let steps = (length / amplitude).ceil().max(1.0) as usize;
+
+                let step = length / steps as f32;
+
+                for index in 0..=steps {
+                    let offset = if index % 2 == 0 {
+                        line_width
+                    } else {
+                        line_width + amplitude
+                    };
+
+                    stream.push_str(
+                        format!(
+                            " {} {}",
+                            point(index as f32 * step, offset),
+                            if index == 0 { "m" } else { "l" }
+                        )
+                        .as_str(),
+                    );
+                }
+            }
+            PdfPageAnnotationType::Strikeout => {
+                stream.push_str(
+                    format!(
+                        " {} m {} l",
+                        point(0.0, height / 2.0),
+                        point(length, height / 2.0)
+                    )
+                    .as_str(),
+                );
+            }
+            _ => {
+                stream.push_str(
+                    format!(
+                        " {} m {} l",
+                        point(0.0, line_width),
+                        point(length, line_width)
+                    )
+                    .as_str(),
+                );
+            }
+        }
+
+        stream.push_str(" S");
+    }
+
+    stream.push_str(" Q");
+
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_create_link_annotation_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            page.annotations_mut().create_link_annotation_at_end(
+                PdfRect::new_from_values(100.0, 100.0, 120.0, 300.0),
+                PdfLinkTarget::Uri("https://example.com/".to_string()),
+            )?;
+
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        let link = annotation.as_link_annotation().unwrap().link()?;
+
+        assert_eq!(link.action_type()?, PdfLinkActionType::Uri);
+        assert_eq!(link.uri()?.as_deref(), Some("https://example.com/"));
+
+        let action = link.action().unwrap();
+
+        assert_eq!(
+            action.as_uri_action().unwrap().uri()?,
+            "https://example.com/"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_attachment_annotation_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let name = "R\u{e9}sum\u{e9} \u{5c65}\u{6b74}\u{66f8}.bin";
+
+        // The payload deliberately includes bytes that are not valid UTF-8.
+
+        let payload = (0..=255).collect::<Vec<u8>>();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let annotation = page
+                .annotations_mut()
+                .create_file_attachment_annotation_at_end(
+                    PdfRect::new_from_values(700.0, 100.0, 720.0, 120.0),
+                    PdfFileAttachmentIcon::Paperclip,
+                    name,
+                    &payload,
+                )?;
+
+            assert_eq!(annotation.icon(), Some(PdfFileAttachmentIcon::Paperclip));
+
+            drop(annotation);
+            drop(page);
+
+            // The embedded file should not be listed as a document-level attachment.
+
+            assert!(document.attachments().is_empty());
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        assert_eq!(
+            annotation.annotation_type(),
+            PdfPageAnnotationType::FileAttachment
+        );
+
+        let annotation = annotation.as_file_attachment_annotation().unwrap();
+
+        assert_eq!(annotation.icon(), Some(PdfFileAttachmentIcon::Paperclip));
+
+        let attachment = annotation.attachment().unwrap();
+
+        assert_eq!(attachment.name(), name);
+        assert_eq!(attachment.save_to_bytes()?, payload);
+        assert_eq!(attachment.mime_type(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_link_annotation_dest() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let internal = page.annotations_mut().create_link_annotation_at_end(
+            PdfRect::new_from_values(100.0, 100.0, 120.0, 300.0),
+            PdfLinkTarget::PageDestination {
+                page_index: 1,
+                x: PdfPoints::new(0.0),
+                y: PdfPoints::new(500.0),
+                zoom: 1.0,
+            },
+        )?;
+
+        assert_eq!(internal.dest()?.unwrap().page_index()?, 1);
+
+        assert_eq!(
+            internal.link()?.action_type()?,
+            PdfLinkActionType::GoToDestination
+        );
+        assert!(internal.link()?.uri()?.is_none());
+
+        let external = page.annotations_mut().create_link_annotation_at_end(
+            PdfRect::new_from_values(200.0, 100.0, 220.0, 300.0),
+            PdfLinkTarget::Uri("https://example.com/".to_string()),
+        )?;
+
+        assert!(external.dest()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attachment_points_insert() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_link_annotation_at_end(
+            PdfRect::new_from_values(100.0, 100.0, 160.0, 300.0),
+            PdfLinkTarget::Uri("https://example.com/".to_string()),
+        )?;
+
+        // One quad per line of a three-line link, identified by the left edge of each quad.
+
+        let line = |left: f32| {
+            PdfQuadPoints::from_rect(PdfRect::new_from_values(100.0, left, 120.0, 300.0))
+        };
+
+        let attachment_points = annotation.attachment_points_mut();
+
+        attachment_points.push(line(10.0))?;
+        attachment_points.push(line(30.0))?;
+        attachment_points.insert(1, line(20.0))?;
+        attachment_points.insert(0, line(0.0))?;
+
+        let lefts = |attachment_points: &PdfPageAnnotationAttachmentPoints| {
+            attachment_points
+                .iter()
+                .map(|quad| quad.x1.value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(lefts(attachment_points), vec![0.0, 10.0, 20.0, 30.0]);
+
+        assert!(matches!(
+            attachment_points.insert(5, line(40.0)),
+            Err(PdfiumError::PageAnnotationAttachmentPointIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attachment_points_set_all_and_bounds() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_highlight_annotation()?;
+
+        let attachment_points = annotation.attachment_points_mut();
+
+        assert!(matches!(
+            attachment_points.bounds(),
+            Err(PdfiumError::NoAttachmentPointsInPageAnnotation)
+        ));
+
+        // An axis-aligned quad, and a quad rotated by 45 degrees around (200, 200)
+        // whose vertices extend beyond the first quad.
+
+        let axis_aligned =
+            PdfQuadPoints::from_rect(PdfRect::new_from_values(100.0, 100.0, 120.0, 300.0));
+
+        let rotated =
+            PdfQuadPoints::new_from_values(200.0, 250.0, 250.0, 200.0, 150.0, 200.0, 200.0, 150.0);
+
+        attachment_points.push(axis_aligned)?;
+        attachment_points.set_all(&[axis_aligned, rotated])?;
+
+        assert_eq!(attachment_points.len(), 2);
+        assert_eq!(attachment_points.to_vec()[1].x3.value, 150.0);
+
+        let bounds = attachment_points.bounds()?;
+
+        assert_eq!(bounds.bottom.value, 100.0);
+        assert_eq!(bounds.left.value, 100.0);
+        assert_eq!(bounds.top.value, 250.0);
+        assert_eq!(bounds.right.value, 300.0);
+
+        // Pdfium cannot remove attachment points.
+
+        assert!(matches!(
+            attachment_points.set_all(&[axis_aligned]),
+            Err(PdfiumError::PageAnnotationAttachmentPointsCannotShrink)
+        ));
+        assert_eq!(attachment_points.len(), 2);
+        assert_eq!(attachment_points.to_vec()[1].x3.value, 150.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_square_annotation_interior_color() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        annotation.set_bounds(PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0))?;
+
+        assert!(annotation.interior_color()?.is_none());
+
+        annotation.set_interior_color(PdfColor::new(255, 0, 0, 255))?;
+
+        assert_eq!(
+            annotation
+                .interior_color()?
+                .map(|color| color.to_hex_with_alpha()),
+            Some(PdfColor::new(255, 0, 0, 255).to_hex_with_alpha())
+        );
+
+        drop(annotation);
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new().set_target_width(page.width().value as Pixels),
+        )?;
+
+        // Sample the pixel at the center of the square; it should be filled with red.
+
+        let x = 200;
+        let y = bitmap.height() as usize - 450;
+        let offset = (y * bitmap.width() as usize + x) * 4;
+
+        assert_eq!(&bitmap.as_rgba_bytes()[offset..offset + 3], &[255, 0, 0]);
+
+        // A custom appearance stream should be preserved rather than silently discarded.
+
+        let mut annotation = page.annotations_mut().get(0)?;
+
+        annotation.set_appearance_stream(
+            PdfAppearanceMode::Normal,
+            Some("0 0 1 rg 400 100 100 200 re f"),
+        )?;
+
+        let square = annotation.as_square_annotation_mut().unwrap();
+
+        assert!(matches!(
+            square.set_interior_color(PdfColor::new(0, 255, 0, 255)),
+            Err(PdfiumError::PageAnnotationHasAppearanceStream)
+        ));
+        assert!(square
+            .appearance_stream(PdfAppearanceMode::Normal)
+            .is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_appearance_stream() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        annotation.set_bounds(PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0))?;
+
+        assert!(annotation
+            .appearance_stream(PdfAppearanceMode::RollOver)
+            .is_none());
+
+        // Fill the annotation's bounds with blue.
+
+        let content = "0 0 1 rg 100 400 200 100 re f";
+
+        annotation.set_appearance_stream(PdfAppearanceMode::Normal, Some(content))?;
+        annotation.set_appearance_stream(PdfAppearanceMode::RollOver, Some(content))?;
+
+        assert_eq!(
+            annotation.appearance_stream(PdfAppearanceMode::Normal),
+            Some(content.to_string())
+        );
+        assert_eq!(
+            annotation.appearance_stream(PdfAppearanceMode::RollOver),
+            Some(content.to_string())
+        );
+
+        annotation.set_appearance_stream(PdfAppearanceMode::RollOver, None)?;
+
+        assert!(annotation
+            .appearance_stream(PdfAppearanceMode::RollOver)
+            .is_none());
+
+        drop(annotation);
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new().set_target_width(page.width().value as Pixels),
+        )?;
+
+        // Sample the pixel at the center of the annotation; it should be filled with blue.
+
+        let x = 200;
+        let y = bitmap.height() as usize - 450;
+        let offset = (y * bitmap.width() as usize + x) * 4;
+
+        assert_eq!(&bitmap.as_rgba_bytes()[offset..offset + 3], &[0, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_border() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        // A new annotation has no /Border entry, so the PDF default should be returned.
+
+        assert_eq!(annotation.border(), PdfAnnotationBorder::DEFAULT);
+
+        let border = PdfAnnotationBorder::new(
+            PdfPoints::new(2.0),
+            PdfPoints::new(3.0),
+            PdfPoints::new(4.0),
+        );
+
+        annotation.set_border(border)?;
+
+        assert_eq!(annotation.border(), border);
+
+        annotation.set_border(PdfAnnotationBorder::NONE)?;
+
+        assert_eq!(annotation.border().width, PdfPoints::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_opacity() -> Result<(), PdfiumError> {
+        // Renders a highlight annotation over a blank page at full and reduced opacity,
+        // and returns the blue channel of the pixel at the center of the highlight.
+        // Pdfium draws highlights in yellow by default, so the blue channel increases
+        // as the highlight becomes more transparent.
+
+        fn render_highlight(opacity: Option<f32>) -> Result<u8, PdfiumError> {
+            let pdfium = test_bind_to_pdfium();
+
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let bounds = PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0);
+
+            let mut annotation = page.annotations_mut().create_highlight_annotation()?;
+
+            annotation.set_bounds(bounds)?;
+            annotation
+                .attachment_points_mut()
+                .create_attachment_point_at_end(PdfQuadPoints::from_rect(bounds))?;
+
+            if let Some(opacity) = opacity {
+                annotation.set_opacity(opacity)?;
+
+                assert!((annotation.opacity()? - opacity).abs() < 0.01);
+            } else {
+                assert_eq!(annotation.opacity()?, 1.0);
+            }
+
+            drop(annotation);
+
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new()
+                    .set_target_width(page.width().value as Pixels)
+                    .render_annotations(true),
+            )?;
+
+            let x = 200;
+            let y = bitmap.height() as usize - 450;
+
+            Ok(bitmap.as_rgba_bytes()[(y * bitmap.width() as usize + x) * 4 + 2])
+        }
+
+        let opaque = render_highlight(None)?;
+        let translucent = render_highlight(Some(0.3))?;
+
+        assert!(translucent > opaque);
+        assert!(translucent > 150);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_opacity_rejects_nan() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        assert!(matches!(
+            annotation.set_opacity(f32::NAN),
+            Err(PdfiumError::AnnotationOpacityIsNaN)
+        ));
+
+        annotation.set_opacity(2.0)?;
+
+        assert_eq!(annotation.opacity()?, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_hidden_flag_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_bounds(PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0))?;
+            annotation.set_flags(PdfAnnotationFlags::Print | PdfAnnotationFlags::Hidden)?;
+
+            assert!(annotation.flags().contains(PdfAnnotationFlags::Hidden));
+
+            drop(annotation);
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let bytes = {
+            let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+            let page = document.pages().first()?;
+
+            let mut annotation = page.annotations().first()?;
+
+            assert_eq!(
+                annotation.flags(),
+                PdfAnnotationFlags::Print | PdfAnnotationFlags::Hidden
+            );
+
+            annotation.set_flags(annotation.flags() - PdfAnnotationFlags::Hidden)?;
+
+            drop(annotation);
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        assert_eq!(
+            page.annotations().first()?.flags(),
+            PdfAnnotationFlags::Print
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ink_annotation_strokes_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_ink_annotation()?;
+
+        assert!(annotation.strokes().is_empty());
+
+        let zig_zag = (0..8)
+            .map(|index| {
+                (
+                    PdfPoints::new(100.0 + index as f32 * 25.0),
+                    PdfPoints::new(if index % 2 == 0 { 400.0 } else { 450.0 }),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        annotation.add_stroke(&zig_zag)?;
+
+        assert_eq!(annotation.strokes(), vec![zig_zag]);
+
+        // The annotation bounds should enclose the entire stroke.
+
+        let bounds = annotation.bounds()?;
+
+        assert!(bounds.left.value <= 100.0);
+        assert!(bounds.right.value >= 275.0);
+        assert!(bounds.bottom.value <= 400.0);
+        assert!(bounds.top.value >= 450.0);
+
+        annotation.clear_strokes()?;
+
+        assert!(annotation.strokes().is_empty());
+
+        assert!(matches!(
+            annotation.add_stroke(&[]),
+            Err(PdfiumError::NoPointsInInkStroke)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_free_text_annotation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let bounds = PdfRect::new_from_values(400.0, 100.0, 500.0, 400.0);
+
+        let annotation = page
+            .annotations_mut()
+            .create_free_text_annotation(bounds, "Review comment")?;
+
+        assert_eq!(annotation.bounds()?, bounds);
+        assert_eq!(annotation.contents(), Some("Review comment".to_owned()));
+
+        let appearance = annotation
+            .appearance_stream(PdfAppearanceMode::Normal)
+            .unwrap();
+
+        assert!(appearance.contains("/Helvetica 12 Tf"));
+        assert!(appearance.contains("(Review comment) Tj"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_free_text_annotation_at_end() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let annotation = page.annotations_mut().create_free_text_annotation_at_end(
+            PdfRect::new_from_values(400.0, 100.0, 500.0, 400.0),
+            "Hello (world) \\",
+            document.fonts().get(font).unwrap(),
+            PdfPoints::new(36.0),
+            PdfColor::RED,
+        )?;
+
+        assert_eq!(annotation.contents(), Some("Hello (world) \\".to_owned()));
+        assert_eq!(
+            annotation.default_appearance(),
+            Some("/Helvetica 36 Tf 1 0 0 rg".to_owned())
+        );
+
+        drop(annotation);
+
+        // The text should be visible when the page is rendered with annotations enabled.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        let is_red_pixel_present = (height - 500..height - 400).any(|y| {
+            (100..400).any(|x| {
+                let offset = (y * width + x) * 4;
+
+                bytes[offset] > 200 && bytes[offset + 1] < 100 && bytes[offset + 2] < 100
+            })
+        });
+
+        assert!(is_red_pixel_present);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_free_text_annotation_appearance() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_free_text_annotation_at_end(
+            PdfRect::new_from_values(400.0, 100.0, 500.0, 400.0),
+            "Review comment",
+            document.fonts().get(font).unwrap(),
+            PdfPoints::new(36.0),
+            PdfColor::RED,
+        )?;
+
+        annotation.set_font_size(PdfPoints::new(24.0))?;
+        annotation.set_text_color(PdfColor::BLUE)?;
+        annotation.set_border_appearance(PdfPoints::new(4.0), PdfColor::LIME)?;
+
+        assert_eq!(
+            annotation.default_appearance(),
+            Some("/Helvetica 24 Tf 0 0 1 rg".to_owned())
+        );
+        assert_eq!(annotation.font_size(), Some(PdfPoints::new(24.0)));
+        assert_eq!(annotation.text_color(), Some(PdfColor::BLUE));
+        assert_eq!(annotation.border().width, PdfPoints::new(4.0));
+
+        drop(annotation);
+
+        // The border should be drawn along the edges of the annotation's bounds.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        let is_green_pixel = |x: usize, y: usize| {
+            let offset = ((height - y) * width + x) * 4;
+
+            bytes[offset] < 100 && bytes[offset + 1] > 200 && bytes[offset + 2] < 100
+        };
+
+        assert!(is_green_pixel(101, 450));
+        assert!(is_green_pixel(250, 499));
+        assert!(!is_green_pixel(250, 450));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_markup_annotations_at_end() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let underline = page.annotations_mut().create_underline_annotation_at_end(
+            &[
+                PdfQuadPoints::from_rect(PdfRect::new_from_values(700.0, 100.0, 720.0, 300.0)),
+                PdfQuadPoints::from_rect(PdfRect::new_from_values(670.0, 100.0, 690.0, 200.0)),
+            ],
+            PdfColor::RED,
+            Some("Reviewer"),
+        )?;
+
+        assert_eq!(underline.attachment_points().len(), 2);
+        assert_eq!(underline.author(), Some("Reviewer".to_owned()));
+
+        drop(underline);
+
+        let strikeout = page.annotations_mut().create_strikeout_annotation_at_end(
+            &[PdfQuadPoints::from_rect(PdfRect::new_from_values(
+                500.0, 100.0, 520.0, 300.0,
+            ))],
+            PdfColor::RED,
+            None,
+        )?;
+
+        assert_eq!(strikeout.attachment_points().len(), 1);
+        assert_eq!(strikeout.author(), None);
+
+        drop(strikeout);
+
+        let squiggly = page.annotations_mut().create_squiggly_annotation_at_end(
+            &[PdfQuadPoints::from_rect(PdfRect::new_from_values(
+                300.0, 100.0, 320.0, 300.0,
+            ))],
+            PdfColor::RED,
+            Some("Reviewer"),
+        )?;
+
+        assert_eq!(squiggly.attachment_points().len(), 1);
+
+        drop(squiggly);
+
+        assert!(page
+            .annotations_mut()
+            .create_underline_annotation_at_end(&[], PdfColor::RED, None)
+            .is_err());
+
+        // Each mark should be visible when the page is rendered with annotations enabled.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        let is_red_pixel_present = |bottom: usize, top: usize| {
+            (height - top..height - bottom).any(|y| {
+                (100..300).any(|x| {
+                    let offset = (y * width + x) * 4;
+
+                    bytes[offset] > 200 && bytes[offset + 1] < 100 && bytes[offset + 2] < 100
+                })
+            })
+        };
+
+        assert!(is_red_pixel_present(700, 720));
+        assert!(is_red_pixel_present(500, 520));
+        assert!(is_red_pixel_present(300, 320));
+
+        // The strikeout should be drawn through the middle of its rectangle, not along the bottom.
+
+        assert!(!is_red_pixel_present(500, 505));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_markup_annotations_at_end_with_highlight() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let quad_points = [
+            PdfQuadPoints::from_rect(PdfRect::new_from_values(700.0, 100.0, 720.0, 300.0)),
+            PdfQuadPoints::from_rect(PdfRect::new_from_values(670.0, 100.0, 690.0, 200.0)),
+        ];
+
+        let highlight = page.annotations_mut().create_highlight_annotation_at_end(
+            &quad_points,
+            PdfColor::RED,
+            None,
+        )?;
+
+        assert_eq!(highlight.attachment_points().len(), 2);
+        assert_eq!(
+            highlight.bounds()?,
+            PdfRect::new_from_values(670.0, 100.0, 720.0, 300.0)
+        );
+
+        drop(highlight);
+
+        let underline = page.annotations_mut().create_underline_annotation_at_end(
+            &[PdfQuadPoints::from_rect(PdfRect::new_from_values(
+                500.0, 100.0, 520.0, 300.0,
+            ))],
+            PdfColor::BLUE,
+            None,
+        )?;
+
+        assert_eq!(underline.attachment_points().len(), 1);
+
+        drop(underline);
+
+        let strikeout = page.annotations_mut().create_strikeout_annotation_at_end(
+            &quad_points,
+            PdfColor::BLUE,
+            None,
+        )?;
+
+        assert_eq!(strikeout.attachment_points().len(), 2);
+
+        drop(strikeout);
+
+        let squiggly = page.annotations_mut().create_squiggly_annotation_at_end(
+            &[PdfQuadPoints::from_rect(PdfRect::new_from_values(
+                300.0, 100.0, 320.0, 300.0,
+            ))],
+            PdfColor::BLUE,
+            None,
+        )?;
+
+        assert_eq!(squiggly.attachment_points().len(), 1);
+
+        drop(squiggly);
+
+        assert!(matches!(
+            page.annotations_mut()
+                .create_highlight_annotation_at_end(&[], PdfColor::RED, None),
+            Err(PdfiumError::NoRectsForAttachmentPoints)
+        ));
+
+        assert_eq!(
+            page.annotations()
+                .iter()
+                .map(|annotation| annotation.annotation_type())
+                .collect::<Vec<_>>(),
+            vec![
+                PdfPageAnnotationType::Highlight,
+                PdfPageAnnotationType::Underline,
+                PdfPageAnnotationType::Strikeout,
+                PdfPageAnnotationType::Squiggly,
+            ]
+        );
+
+        // The highlight should be rendered as a translucent fill across each attachment point.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        let is_highlighted = |x: usize, y: usize| {
+            let offset = ((height - y) * width + x) * 4;
+
+            bytes[offset] > 200 && bytes[offset + 1] > 100 && bytes[offset + 1] < 200
+        };
+
+        assert!(is_highlighted(250, 703));
+        assert!(is_highlighted(150, 673));
+        assert!(!is_highlighted(250, 673));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_ink_annotation_at_end() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let point = |x: f32, y: f32| (PdfPoints::new(x), PdfPoints::new(y));
+
+        let strokes = vec![
+            vec![
+                point(100.0, 500.0),
+                point(200.0, 500.0),
+                point(200.0, 600.0),
+            ],
+            vec![point(300.0, 300.0)],
+        ];
+
+        let annotation = page.annotations_mut().create_ink_annotation_at_end(
+            &strokes,
+            PdfColor::RED,
+            PdfPoints::new(4.0),
+        )?;
+
+        assert_eq!(annotation.strokes_len(), 2);
+        assert_eq!(annotation.strokes(), strokes);
+        assert_eq!(
+            annotation.bounds()?,
+            PdfRect::new_from_values(298.0, 98.0, 602.0, 302.0)
+        );
+
+        drop(annotation);
+
+        // Empty strokes are rejected without creating an annotation.
+
+        assert!(matches!(
+            page.annotations_mut().create_ink_annotation_at_end(
+                &[],
+                PdfColor::RED,
+                PdfPoints::new(4.0)
+            ),
+            Err(PdfiumError::NoPointsInInkStroke)
+        ));
+        assert!(matches!(
+            page.annotations_mut().create_ink_annotation_at_end(
+                &[vec![point(100.0, 100.0)], vec![]],
+                PdfColor::RED,
+                PdfPoints::new(4.0)
+            ),
+            Err(PdfiumError::NoPointsInInkStroke)
+        ));
+        assert_eq!(page.annotations().len(), 1);
+
+        // The strokes, including the single-point stroke, should be visible when the page
+        // is rendered with annotations enabled.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let width = bitmap.width() as usize;
+
+        let height = bitmap.height() as usize;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        let is_red = |x: usize, y: usize| {
+            let offset = ((height - y) * width + x) * 4;
+
+            bytes[offset] > 200 && bytes[offset + 1] < 100 && bytes[offset + 2] < 100
+        };
+
+        assert!(is_red(150, 500));
+        assert!(is_red(200, 550));
+        assert!(is_red(300, 300));
+        assert!(!is_red(150, 550));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stamp_annotation_image() -> Result<(), PdfiumError> {
+        fn solid_bitmap(pdfium: &Pdfium, color: PdfColor) -> Result<PdfBitmap, PdfiumError> {
+            let bitmap = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+            pdfium.bindings().FPDFBitmap_FillRect(
+                *bitmap.handle(),
+                0,
+                0,
+                10,
+                10,
+                color.as_pdfium_color(),
+            );
+
+            Ok(bitmap)
+        }
+
+        fn pixel_at(page: &PdfPage, x: usize, y: usize) -> Result<Vec<u8>, PdfiumError> {
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new().set_target_width(page.width().value as Pixels),
+            )?;
+
+            let offset = ((bitmap.height() as usize - y) * bitmap.width() as usize + x) * 4;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 3].to_vec())
+        }
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_stamp_annotation()?;
+
+        annotation.set_bitmap(
+            &solid_bitmap(&pdfium, PdfColor::RED)?,
+            PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0),
+        )?;
+
+        assert_eq!(annotation.objects().len(), 1);
+
+        drop(annotation);
+
+        assert_eq!(pixel_at(&page, 200, 450)?, vec![255, 0, 0]);
+
+        // Replacing the image should remove the existing image, and the new image should
+        // fill the new bounds.
+
+        let mut annotation = page.annotations_mut().get(0)?;
+
+        let stamp = annotation.as_stamp_annotation_mut().unwrap();
+
+        stamp.set_bitmap(
+            &solid_bitmap(&pdfium, PdfColor::BLUE)?,
+            PdfRect::new_from_values(200.0, 300.0, 300.0, 500.0),
+        )?;
+
+        assert_eq!(stamp.objects().len(), 1);
+
+        // Removing the image should leave an empty annotation.
+
+        stamp.remove_image()?;
+
+        assert!(stamp.objects().is_empty());
+
+        drop(annotation);
+
+        assert_eq!(pixel_at(&page, 200, 450)?, vec![255, 255, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_stamp_annotation_at_end() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let bitmap = PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        pdfium.bindings().FPDFBitmap_FillRect(
+            *bitmap.handle(),
+            0,
+            0,
+            10,
+            10,
+            PdfColor::GREEN.as_pdfium_color(),
+        );
+
+        let bounds = PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0);
+
+        let annotation = page
+            .annotations_mut()
+            .create_stamp_annotation_at_end(bounds, &bitmap)?;
+
+        assert_eq!(annotation.bounds()?, bounds);
+        assert_eq!(annotation.objects().len(), 1);
+
+        // The bitmap should be scaled to fill the bounds of the annotation.
+
+        let image_bounds = annotation.objects().get(0)?.bounds()?;
+
+        assert!((image_bounds.left.value - 100.0).abs() < 0.01);
+        assert!((image_bounds.bottom.value - 400.0).abs() < 0.01);
+        assert!((image_bounds.width().value - 200.0).abs() < 0.01);
+        assert!((image_bounds.height().value - 100.0).abs() < 0.01);
+
+        drop(annotation);
+
+        // The stamp should not have been added to the page's own content, and should be
+        // removable on its own.
+
+        assert!(page.objects().is_empty());
+
+        let annotation = page.annotations().get(0)?;
+
+        page.annotations_mut().delete_annotation(annotation)?;
+
+        assert!(page.annotations().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_author_subject_and_dates() -> Result<(), PdfiumError> {
+        use chrono::prelude::*;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        assert_eq!(annotation.author(), None);
+        assert_eq!(annotation.subject(), None);
+
+        annotation.set_author("Reviewer")?;
+        annotation.set_subject("Needs rewording")?;
+
+        assert_eq!(annotation.author(), Some("Reviewer".to_owned()));
+        assert_eq!(annotation.subject(), Some("Needs rewording".to_owned()));
+
+        #[allow(deprecated)]
+        let creator = annotation.creator();
+
+        assert_eq!(creator, Some("Reviewer".to_owned()));
+
+        let date = Utc.with_ymd_and_hms(2023, 6, 30, 12, 34, 56).unwrap();
+
+        annotation.set_modification_date(date)?;
+
+        assert_eq!(annotation.modification_date_time()?, Some(date.into()));
+
+        // New annotations are given a creation date automatically. Any timezone offset
+        // in the date string should be preserved.
+
+        assert!(annotation.creation_date_time()?.is_some());
+
+        annotation.set_string_value("CreationDate", "D:20230630143456+02'00'")?;
+
+        let creation_date = annotation.creation_date_time()?.unwrap();
+
+        assert_eq!(creation_date, date);
+        assert_eq!(creation_date.offset().local_minus_utc(), 2 * 3600);
+
+        // Malformed date strings should be reported as errors.
+
+        annotation.set_string_value("M", "last Tuesday")?;
+
+        assert!(matches!(
+            annotation.modification_date_time(),
+            Err(PdfiumError::ParsePdfDateStringError)
+        ));
+
+        // Empty dates should be reported as absent.
+
+        annotation.set_string_value("CreationDate", "")?;
+
+        assert_eq!(annotation.creation_date(), None);
+        assert_eq!(annotation.creation_date_time()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_creation_date_time() -> Result<(), PdfiumError> {
+        use chrono::prelude::*;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-dates-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let dated = annotations.get(0)?;
+
+        let creation_date = dated.creation_date_time()?.unwrap();
+
+        assert_eq!(
+            creation_date,
+            Utc.with_ymd_and_hms(2020, 1, 2, 8, 4, 5).unwrap()
+        );
+        assert_eq!(creation_date.offset().local_minus_utc(), -5 * 3600);
+
+        assert!(matches!(
+            annotations.get(1)?.creation_date_time(),
+            Err(PdfiumError::ParsePdfDateStringError)
+        ));
+
+        assert_eq!(annotations.get(2)?.creation_date_time()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight_annotation_over_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Hello world",
+            font,
+            PdfPoints::new(20.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(650.0),
+            "Second line",
+            font,
+            PdfPoints::new(20.0),
+        )?;
+
+        let hits = page
+            .text()?
+            .search("world", &PdfSearchOptions::new())
+            .iter(PdfSearchDirection::SearchForward)
+            .map(|hit| hit.char_range())
+            .collect::<Vec<_>>();
+
+        assert_eq!(hits.len(), 1);
+
+        let annotation = page
+            .annotations_mut()
+            .create_highlight_annotation_over_text(hits[0].clone(), PdfColor::YELLOW)?;
+
+        assert_eq!(annotation.contents(), Some("world".to_owned()));
+        assert_eq!(annotation.attachment_points().len(), 1);
+        assert!((annotation.opacity()? - 0.4).abs() < 0.01);
+        assert!(annotation
+            .appearance_stream(PdfAppearanceMode::Normal)
+            .is_some());
+
+        // A range spanning the end of the first line is highlighted with one
+        // attachment point per line.
+
+        let range = 6..(page.text()?.len() as usize);
+
+        let annotation = page
+            .annotations_mut()
+            .create_highlight_annotation_over_text(range, PdfColor::YELLOW)?;
+
+        assert_eq!(annotation.contents(), Some("world Second line".to_owned()));
+        assert_eq!(annotation.attachment_points().len(), 2);
+
+        let count = page.annotations().len();
+
+        assert!(matches!(
+            page.annotations_mut()
+                .create_highlight_annotation_over_text(0..10_000, PdfColor::YELLOW),
+            Err(PdfiumError::CharIndexOutOfBounds)
+        ));
+        assert_eq!(page.annotations().len(), count);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_popup_links() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-popup-test.pdf", None)?;
+
+        let mut page = document.pages().first()?;
+
+        {
+            let annotations = page.annotations();
+
+            assert_eq!(annotations.len(), 3);
+
+            let text = annotations.get(0)?;
+
+            let popup = text.popup().unwrap();
+
+            assert_eq!(popup.annotation_type(), PdfPageAnnotationType::Popup);
+            assert_eq!(popup.bounds()?.bottom, PdfPoints::new(600.0));
+
+            let popup = popup.as_popup_annotation().unwrap();
+
+            assert!(popup.is_open());
+
+            let parent = popup.parent().unwrap();
+
+            assert_eq!(parent.annotation_type(), PdfPageAnnotationType::Text);
+            assert_eq!(parent.contents(), Some("Commented".to_owned()));
+
+            let orphan = annotations.get(2)?;
+
+            let orphan = orphan.as_popup_annotation().unwrap();
+
+            assert!(orphan.parent().is_none());
+            assert!(!orphan.is_open());
+        }
+
+        let annotations = page.annotations_mut();
+
+        let text = annotations.get(0)?;
+
+        annotations.delete_with_popup(text)?;
+
+        // Both the text annotation and its popup should have been removed, leaving
+        // only the unlinked popup.
+
+        assert_eq!(annotations.len(), 1);
+        assert!(annotations
+            .get(0)?
+            .as_popup_annotation()
+            .unwrap()
+            .parent()
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_with_popup_without_linked_popup() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let annotations = page.annotations_mut();
+
+        let text = annotations.create_text_annotation("A comment")?;
+
+        // Pdfium cannot link a newly created popup to its parent, so neither annotation
+        // should report the other.
+
+        let popup = annotations.create_popup_annotation()?;
+
+        assert!(text.popup().is_none());
+        assert!(popup.parent().is_none());
+
+        drop(text);
+        drop(popup);
+
+        assert_eq!(annotations.len(), 2);
+
+        let text = annotations.get(0)?;
+
+        assert_eq!(text.annotation_type(), PdfPageAnnotationType::Text);
+
+        annotations.delete_with_popup(text)?;
+
+        // Only the text annotation should have been removed.
+
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(
+            annotations.get(0)?.annotation_type(),
+            PdfPageAnnotationType::Popup
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reply_chain() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-replies-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let contents = |annotation: PdfPageAnnotation| annotation.contents().unwrap_or_default();
+
+        let question = annotations.get(0)?;
+        let first_answer = annotations.get(1)?;
+        let follow_up = annotations.get(2)?;
+        let second_answer = annotations.get(3)?;
+
+        assert!(question.in_reply_to().is_none());
+        assert_eq!(
+            first_answer.in_reply_to().map(contents),
+            Some("Question".to_owned())
+        );
+        assert_eq!(
+            follow_up.in_reply_to().map(contents),
+            Some("First answer".to_owned())
+        );
+        assert_eq!(
+            second_answer.in_reply_to().map(contents),
+            Some("Question".to_owned())
+        );
+
+        assert_eq!(
+            annotations
+                .replies(&question)
+                .map(contents)
+                .collect::<Vec<_>>(),
+            vec!["First answer".to_owned(), "Second answer".to_owned()]
+        );
+        assert_eq!(
+            annotations
+                .replies(&first_answer)
+                .map(contents)
+                .collect::<Vec<_>>(),
+            vec!["Follow-up".to_owned()]
+        );
+        assert_eq!(annotations.replies(&follow_up).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_annotation_endpoints() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-line-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        let line = annotation.as_line_annotation().unwrap();
+
+        let start = (PdfPoints::new(100.0), PdfPoints::new(200.0));
+        let end = (PdfPoints::new(300.0), PdfPoints::new(400.0));
+
+        assert_eq!(line.line()?, (start, end));
+        assert_eq!(line.start()?, start);
+        assert_eq!(line.end()?, end);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_and_polyline_vertices() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-vertices-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let points = |vertices: &[(f32, f32)]| {
+            vertices
+                .iter()
+                .map(|(x, y)| (PdfPoints::new(*x), PdfPoints::new(*y)))
+                .collect::<Vec<_>>()
+        };
+
+        let open = annotations.get(0)?;
+
+        let open = open.as_polygon_annotation().unwrap();
+
+        assert_eq!(
+            open.vertices(),
+            points(&[(100.0, 100.0), (200.0, 100.0), (150.0, 200.0)])
+        );
+        assert!(!open.is_closed());
+
+        let closed = annotations.get(1)?;
+
+        let closed = closed.as_polygon_annotation().unwrap();
+
+        assert_eq!(
+            closed.vertices(),
+            points(&[
+                (300.0, 100.0),
+                (400.0, 100.0),
+                (350.0, 200.0),
+                (300.0, 100.0)
+            ])
+        );
+        assert!(closed.is_closed());
+
+        let polyline = annotations.get(2)?;
+
+        let polyline = polyline.as_polyline_annotation().unwrap();
+
+        assert_eq!(
+            polyline.vertices(),
+            points(&[(100.0, 300.0), (200.0, 400.0), (300.0, 300.0)])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replies_without_in_reply_to() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let annotations = page.annotations_mut();
+
+        annotations.create_text_annotation("First comment")?;
+        annotations.create_text_annotation("Second comment")?;
+
+        let first = annotations.get(0)?;
+        let second = annotations.get(1)?;
+
+        assert!(first.in_reply_to().is_none());
+        assert!(second.in_reply_to().is_none());
+        assert_eq!(annotations.replies(&first).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_dictionary_values() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-values-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        assert!(annotation.has_key("ACME:Note"));
+        assert!(!annotation.has_key("ACME:Missing"));
+
+        assert_eq!(
+            annotation.value_type("ACME:Note"),
+            Some(PdfAnnotationValueType::String)
+        );
+        assert_eq!(
+            annotation.value_type("ACME:Scale"),
+            Some(PdfAnnotationValueType::Number)
+        );
+        assert_eq!(
+            annotation.value_type("ACME:Kind"),
+            Some(PdfAnnotationValueType::Name)
+        );
+        assert_eq!(
+            annotation.value_type("Rect"),
+            Some(PdfAnnotationValueType::Array)
+        );
+        assert_eq!(annotation.value_type("ACME:Missing"), None);
+
+        // Embedded null characters must not truncate the value.
+
+        assert_eq!(
+            annotation.get_string_value("ACME:Note"),
+            Some("first\0second".to_owned())
+        );
+        assert_eq!(
+            annotation.get_string_value("ACME:Kind"),
+            Some("Custom".to_owned())
+        );
+        assert_eq!(annotation.get_string_value("ACME:Scale"), None);
+        assert_eq!(annotation.get_string_value("ACME:Missing"), None);
+
+        assert_eq!(annotation.get_number_value("ACME:Scale"), Some(2.5));
+        assert_eq!(annotation.get_number_value("ACME:Note"), None);
+        assert_eq!(annotation.get_number_value("ACME:Missing"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_set_string_value_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_string_value("ACME:Reviewer", "Zoë Ångström")?;
+
+            assert!(annotation.has_key("ACME:Reviewer"));
+            assert!(annotation.modification_date().is_some());
+
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().first()?;
+
+        assert_eq!(
+            annotation.value_type("ACME:Reviewer"),
+            Some(PdfAnnotationValueType::String)
+        );
+        assert_eq!(
+            annotation.get_string_value("ACME:Reviewer"),
+            Some("Zoë Ångström".to_owned())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_and_delete_all_of_type() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let annotations = page.annotations_mut();
+
+        for i in 0..10 {
+            let contents = i.to_string();
+
+            match i % 5 {
+                0 => annotations
+                    .create_square_annotation()?
+                    .set_contents(&contents)?,
+                1 => annotations
+                    .create_highlight_annotation()?
+                    .set_contents(&contents)?,
+                2 => annotations
+                    .create_ink_annotation()?
+                    .set_contents(&contents)?,
+                3 => annotations
+                    .create_stamp_annotation()?
+                    .set_contents(&contents)?,
+                _ => {
+                    annotations.create_text_annotation(&contents)?;
+                }
+            }
+        }
+
+        assert_eq!(annotations.len(), 10);
+
+        let removed = annotations.retain(|annotation| {
+            annotation
+                .contents()
+                .and_then(|contents| contents.parse::<u32>().ok())
+                .map(|value| value % 2 == 0)
+                .unwrap_or(false)
+        })?;
+
+        assert_eq!(removed, 5);
+
+        let survivors = annotations
+            .iter()
+            .filter_map(|annotation| annotation.contents())
+            .collect::<Vec<_>>();
+
+        assert_eq!(survivors, vec!["0", "2", "4", "6", "8"]);
+
+        // The survivors are, in order, a square, an ink, a text, a highlight,
+        // and a stamp annotation.
+
+        assert_eq!(
+            annotations.delete_all_of_type(PdfPageAnnotationType::Ink)?,
+            1
+        );
+        assert_eq!(
+            annotations.delete_all_of_type(PdfPageAnnotationType::Ink)?,
+            0
+        );
+
+        let survivors = annotations
+            .iter()
+            .map(|annotation| (annotation.annotation_type(), annotation.contents()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            survivors,
+            vec![
+                (PdfPageAnnotationType::Square, Some("0".to_owned())),
+                (PdfPageAnnotationType::Text, Some("4".to_owned())),
+                (PdfPageAnnotationType::Highlight, Some("6".to_owned())),
+                (PdfPageAnnotationType::Stamp, Some("8".to_owned())),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_annotation_from_other_document() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source_document = pdfium.create_new_pdf()?;
+
+        source_document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut source_page = source_document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let highlight_bounds = PdfRect::new_from_values(100.0, 100.0, 120.0, 200.0);
+
+        let mut highlight = source_page
+            .annotations_mut()
+            .create_highlight_annotation()?;
+
+        highlight.set_contents("Highlighted")?;
+        highlight.set_stroke_color(PdfColor::YELLOW)?;
+        highlight
+            .attachment_points_mut()
+            .create_attachment_point_at_end(PdfQuadPoints::from_rect(highlight_bounds))?;
+        highlight.set_bounds(highlight_bounds)?;
+
+        source_page
+            .annotations_mut()
+            .create_ink_annotation()?
+            .add_stroke(&[
+                (PdfPoints::new(300.0), PdfPoints::new(300.0)),
+                (PdfPoints::new(350.0), PdfPoints::new(320.0)),
+            ])?;
+
+        source_page
+            .annotations_mut()
+            .create_link_annotation_at_end(
+                PdfRect::new_from_values(200.0, 100.0, 220.0, 300.0),
+                PdfLinkTarget::Uri("https://example.com/".to_string()),
+            )?;
+
+        source_page
+            .annotations_mut()
+            .create_link_annotation_at_end(
+                PdfRect::new_from_values(250.0, 100.0, 270.0, 300.0),
+                PdfLinkTarget::PageDestination {
+                    page_index: 1,
+                    x: PdfPoints::new(0.0),
+                    y: PdfPoints::new(500.0),
+                    zoom: 1.0,
+                },
+            )?;
+
+        source_page
+            .annotations_mut()
+            .create_stamp_annotation()?
+            .set_bitmap(
+                &PdfBitmap::empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?,
+                PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0),
+            )?;
+
+        let mut destination_document = pdfium.create_new_pdf()?;
+
+        destination_document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut destination_page = destination_document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let transform = PdfMatrix::new(1.0, 0.0, 0.0, 1.0, 10.0, 20.0);
+
+        for annotation in source_page.annotations().iter() {
+            destination_page
+                .annotations_mut()
+                .copy_annotation_from_with_page_mapping(&annotation, transform, |index| {
+                    if index == 1 {
+                        Some(0)
+                    } else {
+                        None
+                    }
+                })?;
+        }
+
+        // Copying without a page mapping drops the destination of a link.
+
+        let unmapped = destination_page
+            .annotations_mut()
+            .copy_annotation_from(&source_page.annotations().get(3)?, transform)?;
+
+        assert!(unmapped.as_link_annotation().unwrap().dest()?.is_none());
+
+        drop(unmapped);
+
+        let annotations = destination_page.annotations();
+
+        assert_eq!(annotations.len(), 6);
+
+        let highlight = annotations.get(0)?;
+
+        assert_eq!(
+            highlight.annotation_type(),
+            PdfPageAnnotationType::Highlight
+        );
+        assert_eq!(highlight.contents(), Some("Highlighted".to_string()));
+        assert_eq!(highlight.stroke_color()?, PdfColor::YELLOW);
+        assert_eq!(
+            highlight.bounds()?,
+            PdfRect::new_from_values(120.0, 110.0, 140.0, 210.0)
+        );
+        assert_eq!(
+            highlight.attachment_points().first()?.to_rect(),
+            PdfRect::new_from_values(120.0, 110.0, 140.0, 210.0)
+        );
+
+        assert_eq!(
+            annotations
+                .get(1)?
+                .as_ink_annotation()
+                .map(|ink| ink.strokes()),
+            Some(vec![vec![
+                (PdfPoints::new(310.0), PdfPoints::new(320.0)),
+                (PdfPoints::new(360.0), PdfPoints::new(340.0)),
+            ]])
+        );
+
+        assert_eq!(
+            annotations
+                .get(2)?
+                .as_link_annotation()
+                .unwrap()
+                .link()?
+                .uri()?,
+            Some("https://example.com/".to_string())
+        );
+
+        assert_eq!(
+            annotations
+                .get(3)?
+                .as_link_annotation()
+                .unwrap()
+                .dest()?
+                .unwrap()
+                .page_index()?,
+            0
+        );
+
+        drop(destination_page);
+        drop(source_page);
+        drop(source_document);
+
+        // The copied stamp image must not depend on the source document.
+
+        let bytes = destination_document.save_to_bytes()?;
+
+        drop(destination_document);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let stamp = page.annotations().get(4)?;
+
+        assert_eq!(stamp.annotation_type(), PdfPageAnnotationType::Stamp);
+        assert_eq!(stamp.objects().len(), 1);
+        assert_eq!(
+            stamp
+                .objects()
+                .get(0)?
+                .as_image_object()
+                .unwrap()
+                .get_raw_bitmap()?
+                .width(),
+            10
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_annotation_appearance_stream() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut square = page.annotations_mut().create_square_annotation()?;
+
+        square.set_bounds(PdfRect::new_from_values(100.0, 100.0, 150.0, 150.0))?;
+        square.set_appearance_stream(
+            PdfAppearanceMode::Normal,
+            Some("0 0 1 rg 100 100 50 50 re f"),
+        )?;
+
+        drop(square);
+
+        page.annotations_mut().create_free_text_annotation_at_end(
+            PdfRect::new_from_values(200.0, 100.0, 300.0, 150.0),
+            "Copied",
+            document.fonts().get(font).unwrap(),
+            PdfPoints::new(12.0),
+            PdfColor::RED,
+        )?;
+
+        let transform = PdfMatrix::new(1.0, 0.0, 0.0, 1.0, 10.0, 20.0);
+
+        let source = page.annotations().get(0)?;
+
+        let square = page
+            .annotations_mut()
+            .copy_annotation_from(&source, transform)?;
+
+        assert_eq!(
+            square.appearance_stream(PdfAppearanceMode::Normal),
+            Some("q 1 0 0 1 10 20 cm 0 0 1 rg 100 100 50 50 re f Q".to_owned())
+        );
+
+        drop(square);
+
+        // The appearance stream of a free text annotation is regenerated in its new position.
+
+        let source = page.annotations().get(1)?;
+
+        let free_text = page
+            .annotations_mut()
+            .copy_annotation_from(&source, transform)?;
+
+        let free_text = free_text.as_free_text_annotation().unwrap();
+
+        assert_eq!(
+            free_text.bounds()?,
+            PdfRect::new_from_values(220.0, 110.0, 320.0, 160.0)
+        );
+        assert!(free_text
+            .appearance_stream(PdfAppearanceMode::Normal)
+            .unwrap()
+            .contains("(Copied) Tj"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_type_filters() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-types-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotations = page.annotations();
+
+        assert_eq!(annotations.len(), 8);
+        assert_eq!(annotations.links().count(), 2);
+        assert_eq!(annotations.highlights().count(), 1);
+        assert_eq!(annotations.text_annotations().count(), 3);
+        assert_eq!(annotations.widgets().count(), 1);
+        assert_eq!(
+            annotations.of_type(PdfPageAnnotationType::Square).count(),
+            1
+        );
+        assert_eq!(annotations.of_type(PdfPageAnnotationType::Ink).count(), 0);
+        assert!(annotations
+            .of_type(PdfPageAnnotationType::Text)
+            .all(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Text));
+
+        let contents = annotations
+            .text_annotations()
+            .filter_map(|annotation| annotation.contents())
+            .collect::<Vec<_>>();
+
+        assert_eq!(contents, vec!["First note", "Second note", "Third note"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_widget_form_fields() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-fields-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotations = page.annotations();
+
+        assert_eq!(annotations.widgets().count(), 4);
+
+        // Hit-test the text field, which is a child of a non-terminal "person" field.
+
+        let widget = annotations
+            .widget_at_point(PdfPoints::new(200.0), PdfPoints::new(710.0))
+            .unwrap();
+
+        let field = widget.form_field().unwrap();
+
+        assert_eq!(field.field_type(), PdfFormFieldType::Text);
+        assert_eq!(field.name(), Some("person.name".to_string()));
+        assert_eq!(
+            field.as_text_field().and_then(|field| field.value()),
+            Some("Alice".to_string())
+        );
+        assert!(field.is_read_only());
+        assert!(field.is_required());
+        assert!(!field.field_flags().contains(PdfFormFieldFlags::NoExport));
+
+        let widget = annotations
+            .widget_at_point(PdfPoints::new(110.0), PdfPoints::new(660.0))
+            .unwrap();
+
+        let checkbox = widget
+            .form_field()
+            .and_then(|field| field.as_checkbox_field())
+            .unwrap();
+
+        assert_eq!(checkbox.export_value(), Some("Agree".to_string()));
+        assert!(checkbox.is_checked()?);
+        assert!(!checkbox.is_read_only());
+
+        // Both radio buttons belong to the "choice" group; only the second is selected.
+
+        let radio_buttons = annotations
+            .widgets()
+            .filter(|widget| {
+                widget.form_field().map(|field| field.field_type())
+                    == Some(PdfFormFieldType::RadioButton)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(radio_buttons.len(), 2);
+
+        for (index, widget) in radio_buttons.iter().enumerate() {
+            let radio = widget
+                .form_field()
+                .and_then(|field| field.as_radio_button_field())
+                .unwrap();
+
+            assert_eq!(radio.name(), Some("choice".to_string()));
+            assert_eq!(radio.index_in_group(), index as u32);
+            assert_eq!(radio.group_value(), Some("B".to_string()));
+            assert_eq!(radio.is_checked()?, index == 1);
+        }
+
+        assert_eq!(
+            radio_buttons[0]
+                .form_field()
+                .and_then(|field| field.as_radio_button_field())
+                .and_then(|radio| radio.export_value()),
+            Some("A".to_string())
+        );
+
+        assert!(annotations
+            .widget_at_point(PdfPoints::new(500.0), PdfPoints::new(100.0))
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_annotations_by_name() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for (index, name) in ["review-1", "review-2", "review-1"].iter().enumerate() {
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_bounds(PdfRect::new_from_values(
+                100.0,
+                100.0 * (index + 1) as f32,
+                150.0,
+                100.0 * (index + 1) as f32 + 50.0,
+            ))?;
+            annotation.set_name(name)?;
+        }
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        assert!(matches!(
+            annotation.set_name(""),
+            Err(PdfiumError::EmptyAnnotationName)
+        ));
+
+        drop(annotation);
+
+        let annotations = page.annotations();
+
+        let first = annotations.find_by_name("review-1").unwrap();
+
+        assert_eq!(first.name(), Some("review-1".to_string()));
+        assert_eq!(first.bounds()?.left.value, 100.0);
+
+        let all = annotations.find_all_by_name("review-1");
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].bounds()?.left.value, 300.0);
+
+        assert_eq!(annotations.find_all_by_name("review-2").len(), 1);
+        assert!(annotations.find_by_name("review-3").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations_at_point() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // Two overlapping squares; the second is drawn over the first.
+
+        for (name, bounds) in [
+            (
+                "lower",
+                PdfRect::new_from_values(100.0, 100.0, 300.0, 300.0),
+            ),
+            (
+                "upper",
+                PdfRect::new_from_values(200.0, 200.0, 400.0, 400.0),
+            ),
+        ]
+        .iter()
+        {
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_bounds(*bounds)?;
+            annotation.set_name(name)?;
+        }
+
+        // A highlight whose single attachment point is a diamond centered on (500, 500).
+        // The corners of its bounding box lie outside the diamond.
+
+        let mut annotation = page.annotations_mut().create_highlight_annotation()?;
+
+        annotation.set_bounds(PdfRect::new_from_values(450.0, 450.0, 550.0, 550.0))?;
+        annotation.set_name("markup")?;
+        annotation
+            .attachment_points_mut()
+            .push(PdfQuadPoints::new_from_values(
+                500.0, 550.0, 550.0, 500.0, 450.0, 500.0, 500.0, 450.0,
+            ))?;
+
+        drop(annotation);
+
+        let annotations = page.annotations();
+
+        let names_at = |x: f32, y: f32| {
+            annotations
+                .annotations_at_point(PdfPoints::new(x), PdfPoints::new(y))
+                .iter()
+                .map(|annotation| annotation.name().unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+
+        // Overlapping annotations are returned topmost-first.
+
+        assert_eq!(names_at(250.0, 250.0), vec!["upper", "lower"]);
+        assert_eq!(names_at(150.0, 150.0), vec!["lower"]);
+        assert_eq!(names_at(350.0, 350.0), vec!["upper"]);
+        assert!(names_at(50.0, 50.0).is_empty());
+
+        // Points exactly on an edge are inside.
+
+        assert_eq!(names_at(100.0, 100.0), vec!["lower"]);
+        assert_eq!(names_at(300.0, 300.0), vec!["upper", "lower"]);
+        assert_eq!(names_at(400.0, 250.0), vec!["upper"]);
+
+        // Markup annotations are hit-tested against their attachment points, not their bounds.
+
+        assert_eq!(names_at(500.0, 500.0), vec!["markup"]);
+        assert_eq!(names_at(525.0, 525.0), vec!["markup"]);
+        assert!(names_at(460.0, 540.0).is_empty());
+
+        Ok(())
     }
 }
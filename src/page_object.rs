@@ -7,12 +7,14 @@ use crate::bindgen::{
     FPDF_PAGEOBJ_SHADING, FPDF_PAGEOBJ_TEXT, FPDF_PAGEOBJ_UNKNOWN,
 };
 use crate::bindings::PdfiumLibraryBindings;
+use crate::clip_path::PdfClipPath;
 use crate::color::PdfColor;
 use crate::document::PdfDocument;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::page_annotation_objects::PdfPageAnnotationObjects;
 use crate::page_object_image::PdfPageImageObject;
+use crate::page_object_marks::PdfPageObjectMarks;
 use crate::page_object_path::PdfPagePathObject;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_object_shading::PdfPageShadingObject;
@@ -21,6 +23,7 @@ use crate::page_object_unsupported::PdfPageUnsupportedObject;
 use crate::page_object_x_object_form::PdfPageXObjectFormObject;
 use crate::page_objects::PdfPageObjects;
 use crate::points::PdfPoints;
+use crate::quad_points::PdfQuadPoints;
 use crate::rect::PdfRect;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
@@ -81,7 +84,11 @@ impl PdfPageObjectType {
 ///
 /// A formal definition of these blend modes can be found in Section 7.2.4 of
 /// the PDF Reference Manual, version 1.7, on page 520.
-#[derive(Debug, Copy, Clone, PartialEq)]
+///
+/// Blend modes can only be set, using [PdfPageObjectCommon::set_blend_mode()]. Pdfium does not
+/// provide an `FPDFPageObj_GetBlendMode()` function, so the blend mode of an existing
+/// [PdfPageObject] cannot be retrieved.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PdfPageObjectBlendMode {
     /// Selects the source color, ignoring the backdrop.
     Normal,
@@ -155,6 +162,10 @@ pub enum PdfPageObjectBlendMode {
     /// Preserves the luminosity and hue of the backdrop color while adopting the saturation
     /// of the source color.
     HSLSaturation,
+
+    /// A non-standard blend mode with the given name. Viewers that do not recognize the
+    /// name will fall back to [PdfPageObjectBlendMode::Normal].
+    Unknown(String),
 }
 
 impl PdfPageObjectBlendMode {
@@ -176,10 +187,21 @@ impl PdfPageObjectBlendMode {
             PdfPageObjectBlendMode::HSLSaturation => "Saturation",
             PdfPageObjectBlendMode::Screen => "Screen",
             PdfPageObjectBlendMode::SoftLight => "SoftLight",
+            PdfPageObjectBlendMode::Unknown(name) => name.as_str(),
         }
     }
 }
 
+// Deriving Default for enums is experimental. We implement the trait ourselves
+// to provide better compatibility with older Rust versions.
+#[allow(clippy::derivable_impls)]
+impl Default for PdfPageObjectBlendMode {
+    #[inline]
+    fn default() -> Self {
+        PdfPageObjectBlendMode::Normal
+    }
+}
+
 /// The shape that should be used at the corners of stroked paths.
 ///
 /// Join styles are significant only at points where consecutive segments of a path
@@ -192,34 +214,43 @@ pub enum PdfPageObjectLineJoin {
     /// The outer edges of the strokes for the two path segments are extended
     /// until they meet at an angle, as in a picture frame. If the segments meet at too
     /// sharp an angle, a bevel join is used instead.
-    Miter = FPDF_LINEJOIN_MITER as isize,
+    Miter,
 
     /// An arc of a circle with a diameter equal to the line width is drawn
     /// around the point where the two path segments meet, connecting the outer edges of
     /// the strokes for the two segments. This pie-slice-shaped figure is filled in,
     /// producing a rounded corner.
-    Round = FPDF_LINEJOIN_ROUND as isize,
+    Round,
 
     /// The two path segments are finished with butt caps and the resulting notch
     /// beyond the ends of the segments is filled with a triangle.
-    Bevel = FPDF_LINEJOIN_BEVEL as isize,
+    Bevel,
+
+    /// A line join style not defined by the PDF specification, as read from an
+    /// existing document. Pdfium will not apply an unknown line join style to a page object.
+    Unknown(i32),
 }
 
 impl PdfPageObjectLineJoin {
-    pub(crate) fn from_pdfium(value: c_int) -> Option<Self> {
+    pub(crate) fn from_pdfium(value: c_int) -> Result<Self, PdfiumError> {
+        if value == -1 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
         match value as u32 {
-            FPDF_LINEJOIN_MITER => Some(Self::Miter),
-            FPDF_LINEJOIN_ROUND => Some(Self::Round),
-            FPDF_LINEJOIN_BEVEL => Some(Self::Bevel),
-            _ => None,
+            FPDF_LINEJOIN_MITER => Ok(Self::Miter),
+            FPDF_LINEJOIN_ROUND => Ok(Self::Round),
+            FPDF_LINEJOIN_BEVEL => Ok(Self::Bevel),
+            _ => Ok(Self::Unknown(value)),
         }
     }
 
-    pub(crate) fn as_pdfium(&self) -> u32 {
+    pub(crate) fn as_pdfium(&self) -> c_int {
         match self {
-            PdfPageObjectLineJoin::Miter => FPDF_LINEJOIN_MITER,
-            PdfPageObjectLineJoin::Round => FPDF_LINEJOIN_ROUND,
-            PdfPageObjectLineJoin::Bevel => FPDF_LINEJOIN_BEVEL,
+            PdfPageObjectLineJoin::Miter => FPDF_LINEJOIN_MITER as c_int,
+            PdfPageObjectLineJoin::Round => FPDF_LINEJOIN_ROUND as c_int,
+            PdfPageObjectLineJoin::Bevel => FPDF_LINEJOIN_BEVEL as c_int,
+            PdfPageObjectLineJoin::Unknown(value) => *value,
         }
     }
 }
@@ -232,32 +263,41 @@ impl PdfPageObjectLineJoin {
 pub enum PdfPageObjectLineCap {
     /// The stroke is squared off at the endpoint of the path. There is no
     /// projection beyond the end of the path.
-    Butt = FPDF_LINECAP_BUTT as isize,
+    Butt,
 
     /// A semicircular arc with a diameter equal to the line width is
     /// drawn around the endpoint and filled in.
-    Round = FPDF_LINECAP_ROUND as isize,
+    Round,
 
     /// The stroke continues beyond the endpoint of the path
     /// for a distance equal to half the line width and is squared off.
-    Square = FPDF_LINECAP_PROJECTING_SQUARE as isize,
+    Square,
+
+    /// A line cap style not defined by the PDF specification, as read from an
+    /// existing document. Pdfium will not apply an unknown line cap style to a page object.
+    Unknown(i32),
 }
 
 impl PdfPageObjectLineCap {
-    pub(crate) fn from_pdfium(value: c_int) -> Option<Self> {
+    pub(crate) fn from_pdfium(value: c_int) -> Result<Self, PdfiumError> {
+        if value == -1 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
         match value as u32 {
-            FPDF_LINECAP_BUTT => Some(Self::Butt),
-            FPDF_LINECAP_ROUND => Some(Self::Round),
-            FPDF_LINECAP_PROJECTING_SQUARE => Some(Self::Square),
-            _ => None,
+            FPDF_LINECAP_BUTT => Ok(Self::Butt),
+            FPDF_LINECAP_ROUND => Ok(Self::Round),
+            FPDF_LINECAP_PROJECTING_SQUARE => Ok(Self::Square),
+            _ => Ok(Self::Unknown(value)),
         }
     }
 
-    pub(crate) fn as_pdfium(&self) -> u32 {
+    pub(crate) fn as_pdfium(&self) -> c_int {
         match self {
-            PdfPageObjectLineCap::Butt => FPDF_LINECAP_BUTT,
-            PdfPageObjectLineCap::Round => FPDF_LINECAP_ROUND,
-            PdfPageObjectLineCap::Square => FPDF_LINECAP_PROJECTING_SQUARE,
+            PdfPageObjectLineCap::Butt => FPDF_LINECAP_BUTT as c_int,
+            PdfPageObjectLineCap::Round => FPDF_LINECAP_ROUND as c_int,
+            PdfPageObjectLineCap::Square => FPDF_LINECAP_PROJECTING_SQUARE as c_int,
+            PdfPageObjectLineCap::Unknown(value) => *value,
         }
     }
 }
@@ -546,6 +586,19 @@ pub trait PdfPageObjectCommon<'a> {
     /// any glyphs, use the [PdfPageTextObject::descent()] function.
     fn bounds(&self) -> Result<PdfRect, PdfiumError>;
 
+    /// Returns the four corners of the bounding box of this [PdfPageObject] in page coordinates,
+    /// taking into account any rotation or skew applied by the object's transformation matrix.
+    ///
+    /// Unlike the axis-aligned rectangle returned by [PdfPageObjectCommon::bounds()], the
+    /// returned [PdfQuadPoints] follow the edges of a rotated object closely. The result of
+    /// calling [PdfQuadPoints::to_rect()] on the returned quadrilateral always encloses it.
+    ///
+    /// Pdfium only reports axis-aligned bounds, so the object's transformation matrix is briefly
+    /// reset to the identity matrix in order to measure its untransformed bounds. For shading
+    /// objects, whose transformation matrix cannot be changed, the corners of the axis-aligned
+    /// bounding box are returned instead.
+    fn quad_bounds(&self) -> Result<PdfQuadPoints, PdfiumError>;
+
     /// Returns the width of this [PdfPageObject].
     #[inline]
     fn width(&self) -> Result<PdfPoints, PdfiumError> {
@@ -575,6 +628,14 @@ pub trait PdfPageObjectCommon<'a> {
             .unwrap_or(false)
     }
 
+    /// Returns the paths making up the clipping region applied to this [PdfPageObject].
+    /// Only the parts of this [PdfPageObject] that lie inside every returned [PdfClipPath]
+    /// are painted.
+    ///
+    /// An empty `Vec` is returned if this [PdfPageObject] is not clipped. Clipping regions
+    /// defined by text rather than by paths are not included.
+    fn clip_paths(&self) -> Result<Vec<PdfClipPath>, PdfiumError>;
+
     /// Transforms this [PdfPageObject] by applying the transformation matrix read from the given [PdfPageObject].
     ///
     /// Any translation, rotation, scaling, or skewing transformations currently applied to the
@@ -583,7 +644,11 @@ pub trait PdfPageObjectCommon<'a> {
 
     /// Sets the blend mode that will be applied when painting this [PdfPageObject].
     ///
-    /// Note that Pdfium does not currently expose a function to read the currently set blend mode.
+    /// The new blend mode takes effect immediately when the containing page is next rendered.
+    ///
+    /// Note that Pdfium does not currently expose a function to read the currently set blend mode,
+    /// nor any way to inspect the graphics state or content stream operators of an individual
+    /// page object, so there is no corresponding `blend_mode()` function.
     fn set_blend_mode(&mut self, blend_mode: PdfPageObjectBlendMode) -> Result<(), PdfiumError>;
 
     /// Returns the color of any filled paths in this [PdfPageObject].
@@ -617,6 +682,10 @@ pub trait PdfPageObjectCommon<'a> {
 
     /// Returns the line join style that will be used when painting stroked path segments
     /// in this [PdfPageObject].
+    ///
+    /// Line join styles not defined by the PDF specification are returned as
+    /// [PdfPageObjectLineJoin::Unknown]. Pdfium does not expose the miter limit that
+    /// controls when a [PdfPageObjectLineJoin::Miter] join is replaced by a bevel join.
     fn line_join(&self) -> Result<PdfPageObjectLineJoin, PdfiumError>;
 
     /// Sets the line join style that will be used when painting stroked path segments
@@ -625,6 +694,9 @@ pub trait PdfPageObjectCommon<'a> {
 
     /// Returns the line cap style that will be used when painting stroked path segments
     /// in this [PdfPageObject].
+    ///
+    /// Line cap styles not defined by the PDF specification are returned as
+    /// [PdfPageObjectLineCap::Unknown].
     fn line_cap(&self) -> Result<PdfPageObjectLineCap, PdfiumError>;
 
     /// Sets the line cap style that will be used when painting stroked path segments
@@ -680,6 +752,8 @@ pub trait PdfPageObjectCommon<'a> {
     /// Note that dash pattern save support in Pdfium was not fully stabilized until release
     /// `chromium/5772` (May 2023). Versions of Pdfium older than this can load and render
     /// dash patterns, but will not save dash patterns to PDF files.
+    ///
+    /// An empty dash array indicates a solid stroke.
     fn dash_array(&self) -> Result<Vec<PdfPoints>, PdfiumError>;
 
     /// Sets the line dash array that will be used when painting stroked path segments
@@ -697,8 +771,24 @@ pub trait PdfPageObjectCommon<'a> {
     /// Note that dash pattern save support in Pdfium was not fully stabilized until release
     /// `chromium/5772` (May 2023). Versions of Pdfium older than this can load and render
     /// dash patterns, but will not save dash patterns to PDF files.
+    ///
+    /// Passing an empty dash array removes any dash pattern, restoring a solid stroke.
     fn set_dash_array(&mut self, array: &[PdfPoints], phase: PdfPoints) -> Result<(), PdfiumError>;
 
+    /// Returns the collection of content marks attached to this [PdfPageObject].
+    ///
+    /// Content marks record the marked content sequences containing this [PdfPageObject].
+    /// They link page content to the structure tree of a tagged PDF, and can flag decorative
+    /// content as an `/Artifact` that should be skipped by screen readers.
+    fn marks(&self) -> PdfPageObjectMarks;
+
+    /// Returns the marked content ID linking this [PdfPageObject] to an element in the
+    /// structure tree of a tagged PDF, if any.
+    ///
+    /// The marked content ID is taken from the `MCID` parameter of the innermost content mark
+    /// attached to this [PdfPageObject] that has one.
+    fn marked_content_id(&self) -> Option<i32>;
+
     /// Returns `true` if this [PdfPageObject] can be successfully copied by calling its
     /// `try_copy()` function.
     ///
@@ -709,7 +799,9 @@ pub trait PdfPageObjectCommon<'a> {
     /// Bézier curve of an existing path object.
     /// * For text objects, the font used by the object must be present in the destination document,
     /// or text rendering behaviour will be unpredictable. While text objects refer to fonts,
-    /// font data is embedded into documents separately from text objects.
+    /// font data is embedded into documents separately from text objects. The 14 built-in
+    /// PDF fonts are exempt from this restriction, since they are loaded into the destination
+    /// document automatically.
     /// * For image objects, Pdfium allows iterating over the list of image filters applied
     /// to an image object, but currently provides no way to set a new object's image filters.
     /// As a result, it is not possible to copy an image object that has any image filters applied.
@@ -729,7 +821,9 @@ pub trait PdfPageObjectCommon<'a> {
     /// Bézier curve of an existing path object.
     /// * For text objects, the font used by the object must be present in the destination document,
     /// or text rendering behaviour will be unpredictable. While text objects refer to fonts,
-    /// font data is embedded into documents separately from text objects.
+    /// font data is embedded into documents separately from text objects. The 14 built-in
+    /// PDF fonts are exempt from this restriction, since they are loaded into the destination
+    /// document automatically.
     /// * For image objects, Pdfium allows iterating over the list of image filters applied
     /// to an image object, but currently provides no way to set a new object's image filters.
     /// As a result, it is not possible to copy an image object that has any image filters applied.
@@ -760,6 +854,43 @@ where
         self.bounds_impl()
     }
 
+    fn quad_bounds(&self) -> Result<PdfQuadPoints, PdfiumError> {
+        let matrix = self.get_matrix_impl()?;
+
+        if self.reset_matrix_impl(PdfMatrix::IDENTITY).is_err() {
+            return self.bounds_impl().map(PdfQuadPoints::from_rect);
+        }
+
+        let bounds = self.bounds_impl();
+
+        // Restore the original matrix before reporting any error in measuring the bounds.
+
+        self.reset_matrix_impl(matrix)?;
+
+        bounds.map(|bounds| PdfQuadPoints::from_rect(bounds).transform(matrix))
+    }
+
+    fn clip_paths(&self) -> Result<Vec<PdfClipPath>, PdfiumError> {
+        let handle = self
+            .bindings()
+            .FPDFPageObj_GetClipPath(self.get_object_handle());
+
+        if handle.is_null() {
+            // This object is not clipped.
+
+            return Ok(Vec::new());
+        }
+
+        match self.bindings().FPDFClipPath_CountPaths(handle) {
+            count if count < 0 => Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            )),
+            count => Ok((0..count)
+                .map(|index| PdfClipPath::from_pdfium(handle, index, self.bindings()))
+                .collect()),
+        }
+    }
+
     #[inline]
     fn transform_from(&mut self, other: &PdfPageObject) -> Result<(), PdfiumError> {
         self.reset_matrix_impl(other.matrix()?)
@@ -911,14 +1042,13 @@ where
             self.bindings()
                 .FPDFPageObj_GetLineJoin(self.get_object_handle()),
         )
-        .ok_or(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
     }
 
     #[inline]
     fn set_line_join(&mut self, line_join: PdfPageObjectLineJoin) -> Result<(), PdfiumError> {
         if self.bindings().is_true(
             self.bindings()
-                .FPDFPageObj_SetLineJoin(self.get_object_handle(), line_join.as_pdfium() as c_int),
+                .FPDFPageObj_SetLineJoin(self.get_object_handle(), line_join.as_pdfium()),
         ) {
             Ok(())
         } else {
@@ -932,14 +1062,13 @@ where
             self.bindings()
                 .FPDFPageObj_GetLineCap(self.get_object_handle()),
         )
-        .ok_or(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
     }
 
     #[inline]
     fn set_line_cap(&mut self, line_cap: PdfPageObjectLineCap) -> Result<(), PdfiumError> {
         if self.bindings().is_true(
             self.bindings()
-                .FPDFPageObj_SetLineCap(self.get_object_handle(), line_cap.as_pdfium() as c_int),
+                .FPDFPageObj_SetLineCap(self.get_object_handle(), line_cap.as_pdfium()),
         ) {
             Ok(())
         } else {
@@ -977,7 +1106,13 @@ where
     fn dash_array(&self) -> Result<Vec<PdfPoints>, PdfiumError> {
         let dash_count = self
             .bindings()
-            .FPDFPageObj_GetDashCount(self.get_object_handle()) as usize;
+            .FPDFPageObj_GetDashCount(self.get_object_handle());
+
+        if dash_count < 0 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
+        let dash_count = dash_count as usize;
 
         let mut dash_array = vec![0.0; dash_count];
 
@@ -1016,6 +1151,16 @@ where
         }
     }
 
+    #[inline]
+    fn marks(&self) -> PdfPageObjectMarks {
+        PdfPageObjectMarks::from_pdfium(self.get_object_handle(), self.bindings())
+    }
+
+    #[inline]
+    fn marked_content_id(&self) -> Option<i32> {
+        self.marks().marked_content_id()
+    }
+
     #[inline]
     fn is_copyable(&self) -> bool {
         self.is_copyable_impl()
@@ -1180,11 +1325,20 @@ impl<'a> Drop for PdfPageObject<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::color::PdfColor;
     use crate::error::PdfiumError;
     use crate::matrix::PdfMatrix;
+    use crate::page_object::{
+        PdfPageObjectBlendMode, PdfPageObjectCommon, PdfPageObjectLineCap, PdfPageObjectLineJoin,
+        PdfPageObjectType,
+    };
+    use crate::page_object_mark::PdfPageObjectMarkParamValue;
+    use crate::page_object_path::PdfPathFillMode;
     use crate::page_objects_common::PdfPageObjectsCommon;
     use crate::page_size::PdfPagePaperSize;
+    use crate::path_segments::PdfPathSegments;
     use crate::points::PdfPoints;
+    use crate::rect::PdfRect;
     use crate::utils::test::test_bind_to_pdfium;
 
     #[test]
@@ -1264,4 +1418,590 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_set_blend_mode() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().times_roman();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            "My new text object",
+            font,
+            PdfPoints::new(10.0),
+        )?;
+
+        assert_eq!(
+            PdfPageObjectBlendMode::default(),
+            PdfPageObjectBlendMode::Normal
+        );
+
+        object.set_blend_mode(PdfPageObjectBlendMode::Multiply)?;
+
+        drop(object);
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::new(100.0),
+            "My other text object",
+            font,
+            PdfPoints::new(10.0),
+        )?;
+
+        object.set_blend_mode(PdfPageObjectBlendMode::Unknown("ACMEGlow".to_string()))?;
+
+        drop(object);
+
+        page.regenerate_content()?;
+
+        drop(page);
+
+        // Pdfium cannot read blend modes back, so check the graphics states written
+        // into the saved document instead.
+
+        let bytes = document.save_to_bytes()?;
+
+        let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+
+        assert!(contains(b"/Multiply"));
+        assert!(contains(b"/ACMEGlow"));
+        assert!(!contains(b"/Screen"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_fill_mode_and_dash_pattern() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            Some(PdfColor::RED),
+            Some(PdfPoints::new(2.0)),
+            Some(PdfColor::BLUE),
+        )?;
+
+        let path = object.as_path_object_mut().unwrap();
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::Winding);
+        assert!(path.is_stroked()?);
+        assert!(path.dash_array()?.is_empty());
+
+        path.set_fill_mode(PdfPathFillMode::EvenOdd)?;
+        path.set_dash_array(
+            &[PdfPoints::new(6.0), PdfPoints::new(3.0)],
+            PdfPoints::new(1.5),
+        )?;
+
+        // Changing the fill mode must not alter the stroke setting, and vice versa.
+
+        assert!(path.is_stroked()?);
+
+        path.set_stroked(false)?;
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::EvenOdd);
+
+        path.set_stroked(true)?;
+
+        page.regenerate_content()?;
+
+        drop(page);
+
+        // The settings should survive a round trip through a saved document.
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().first()?;
+
+        let mut object = page.objects().first()?;
+
+        let path = object.as_path_object_mut().unwrap();
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::EvenOdd);
+        assert!(path.is_stroked()?);
+        assert_eq!(
+            path.dash_array()?,
+            vec![PdfPoints::new(6.0), PdfPoints::new(3.0)]
+        );
+        assert_eq!(path.dash_phase()?, PdfPoints::new(1.5));
+
+        // An empty dash array restores a solid stroke.
+
+        path.set_dash_array(&[], PdfPoints::ZERO)?;
+
+        assert!(path.dash_array()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_cap_and_join() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The test document contains a line stroked with the undefined line cap style 7
+        // and line join style 5, followed by a line stroked with round caps and bevel joins.
+
+        let document = pdfium.load_pdf_from_file("./test/line-style-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page.objects().get(0)?;
+
+        assert_eq!(object.line_cap()?, PdfPageObjectLineCap::Unknown(7));
+        assert_eq!(object.line_join()?, PdfPageObjectLineJoin::Unknown(5));
+
+        let object = page.objects().get(1)?;
+
+        assert_eq!(object.line_cap()?, PdfPageObjectLineCap::Round);
+        assert_eq!(object.line_join()?, PdfPageObjectLineJoin::Bevel);
+
+        // Line caps and joins can be set on any stroked object, including text.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            "Outlined text",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        object.set_line_cap(PdfPageObjectLineCap::Square)?;
+        object.set_line_join(PdfPageObjectLineJoin::Round)?;
+
+        assert_eq!(object.line_cap()?, PdfPageObjectLineCap::Square);
+        assert_eq!(object.line_join()?, PdfPageObjectLineJoin::Round);
+
+        // Pdfium will not apply undefined styles.
+
+        assert!(object
+            .set_line_cap(PdfPageObjectLineCap::Unknown(7))
+            .is_err());
+        assert_eq!(object.line_cap()?, PdfPageObjectLineCap::Square);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_paths() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The test document contains a line clipped to the rectangle (100, 100)..(300, 300),
+        // followed by an unclipped line.
+
+        let document = pdfium.load_pdf_from_file("./test/clip-path-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        assert_eq!(page.objects().len(), 2);
+
+        let object = page.objects().get(0)?;
+
+        let clip_paths = object.clip_paths()?;
+
+        assert_eq!(clip_paths.len(), 1);
+
+        let segments = clip_paths[0].segments();
+
+        assert!(!segments.is_empty());
+
+        let xs = segments
+            .iter()
+            .map(|segment| segment.x().value)
+            .collect::<Vec<_>>();
+
+        let ys = segments
+            .iter()
+            .map(|segment| segment.y().value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(xs.iter().copied().fold(f32::MAX, f32::min), 100.0);
+        assert_eq!(xs.iter().copied().fold(f32::MIN, f32::max), 300.0);
+        assert_eq!(ys.iter().copied().fold(f32::MAX, f32::min), 100.0);
+        assert_eq!(ys.iter().copied().fold(f32::MIN, f32::max), 300.0);
+
+        assert!(page.objects().get(1)?.clip_paths()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_marks() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Marks should be read from the content stream of an existing tagged document.
+
+        let document = pdfium.load_pdf_from_file("./test/structure-tree-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page.objects().first()?;
+
+        let marks = object.marks();
+
+        assert_eq!(marks.len(), 1);
+
+        let mark = marks.get(0)?;
+
+        assert_eq!(mark.name(), "P");
+        assert_eq!(mark.param_keys(), vec!["MCID".to_string()]);
+        assert_eq!(
+            mark.param("MCID"),
+            Some(PdfPageObjectMarkParamValue::Integer(0))
+        );
+        assert_eq!(mark.param("Lang"), None);
+        assert_eq!(object.marked_content_id(), Some(0));
+
+        // Marks added to a new object should survive a round trip through a saved document.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        assert!(object.marks().is_empty());
+        assert_eq!(object.marked_content_id(), None);
+
+        let mut artifact = object.marks().create_mark("Artifact")?;
+
+        artifact.set_string_param(&document, "Type", "Pagination")?;
+        artifact.set_blob_param(&document, "Data", &[0x00, 0xff, 0x7f])?;
+
+        let mut span = object.marks().create_mark("Span")?;
+
+        span.set_int_param(&document, "MCID", 7)?;
+        span.set_string_param(&document, "Lang", "en-US")?;
+
+        assert_eq!(object.marked_content_id(), Some(7));
+
+        page.regenerate_content()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page.objects().first()?;
+
+        assert_eq!(
+            object
+                .marks()
+                .iter()
+                .map(|mark| mark.name())
+                .collect::<Vec<_>>(),
+            vec!["Artifact", "Span"]
+        );
+        assert_eq!(object.marked_content_id(), Some(7));
+        assert_eq!(
+            object
+                .marks()
+                .find("Span")
+                .and_then(|mark| mark.param("Lang")),
+            Some(PdfPageObjectMarkParamValue::String("en-US".to_string()))
+        );
+
+        let artifact = object.marks().find("Artifact").unwrap();
+
+        assert_eq!(
+            artifact.param("Type"),
+            Some(PdfPageObjectMarkParamValue::String(
+                "Pagination".to_string()
+            ))
+        );
+        assert_eq!(artifact.blob_param("Data"), Some(vec![0x00, 0xff, 0x7f]));
+        assert_eq!(artifact.blob_param("Missing"), None);
+
+        // Removing a mark leaves the remaining marks in place.
+
+        let mut marks = object.marks();
+
+        marks.delete_at_index(0)?;
+
+        assert_eq!(marks.len(), 1);
+        assert_eq!(marks.get(0)?.name(), "Span");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mark_blob_params() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        let mut artifact = object.marks().create_mark("Artifact")?;
+
+        // Blob values may contain bytes that are not valid UTF-8, including embedded nulls.
+
+        let data = [0x00, 0x80, 0xfe, 0xff, 0x00];
+
+        artifact.set_blob_param(&document, "Data", &data)?;
+
+        assert_eq!(artifact.blob_param("Data"), Some(data.to_vec()));
+
+        // Setting a blob parameter again replaces the existing value.
+
+        artifact.set_blob_param(&document, "Data", &[0x2a])?;
+
+        assert_eq!(artifact.blob_param("Data"), Some(vec![0x2a]));
+
+        artifact.set_blob_param(&document, "Empty", &[])?;
+
+        assert_eq!(artifact.blob_param("Empty"), Some(Vec::new()));
+
+        // String parameters are returned as their raw bytes; integer parameters are not blobs.
+
+        artifact.set_string_param(&document, "Type", "Layout")?;
+        artifact.set_int_param(&document, "Count", 3)?;
+
+        assert_eq!(artifact.blob_param("Type"), Some(b"Layout".to_vec()));
+        assert_eq!(artifact.blob_param("Count"), None);
+        assert_eq!(artifact.blob_param("Missing"), None);
+
+        artifact.remove_param("Data")?;
+
+        assert_eq!(artifact.blob_param("Data"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_descendants() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Each page of this document consists of six scaled form XObjects; the first form
+        // on the third page contains an image.
+
+        let document = pdfium.load_pdf_from_file("./test/tile-test.pdf", None)?;
+
+        let page = document.pages().get(2)?;
+
+        let objects = page.objects();
+
+        assert_eq!(objects.len(), 6);
+
+        let descendants = objects.descendants().collect::<Vec<_>>();
+
+        assert!(descendants.len() > objects.len());
+
+        // Each form object is yielded before its children, and objects directly on the page
+        // are not transformed.
+
+        let (first, first_matrix) = &descendants[0];
+
+        assert_eq!(first.object_type(), PdfPageObjectType::XObjectForm);
+        assert_eq!(first_matrix, &PdfMatrix::IDENTITY);
+        assert_eq!(
+            descendants
+                .iter()
+                .filter(|(_, matrix)| *matrix == PdfMatrix::IDENTITY)
+                .count(),
+            6
+        );
+
+        let first_form_len = first.as_x_object_form_object().unwrap().len();
+
+        assert!(descendants[1..=first_form_len]
+            .iter()
+            .all(|(_, matrix)| (matrix.a() - 0.531496).abs() < 0.0001
+                && (matrix.e() - 35.7874).abs() < 0.0001
+                && (matrix.f() - 420.945).abs() < 0.0001));
+
+        // The accumulated matrix converts the bounds of the nested image into page coordinates.
+
+        let (image, matrix) = descendants[1..=first_form_len]
+            .iter()
+            .find(|(object, _)| object.object_type() == PdfPageObjectType::Image)
+            .unwrap();
+
+        let bounds = image.bounds()?.transform(*matrix);
+
+        assert!((bounds.left.value - (35.7874 + 36.0 * 0.531496)).abs() < 0.01);
+        assert!((bounds.bottom.value - (420.945 + 696.4565 * 0.531496)).abs() < 0.01);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_object_from_other_document() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source = pdfium.create_new_pdf()?;
+
+        let mut source_page = source
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = source.fonts_mut().helvetica();
+
+        let text = source_page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(300.0),
+            "Copied text",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let path = source_page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let mut destination = pdfium.create_new_pdf()?;
+
+        let mut destination_page = destination
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let text_copy = destination_page
+            .objects_mut()
+            .copy_object_from(&text, Some(PdfMatrix::new(1.0, 0.0, 0.0, 1.0, 50.0, 25.0)))?;
+
+        let path_copy = destination_page
+            .objects_mut()
+            .copy_object_from(&path, None)?;
+
+        assert_eq!(destination_page.objects().len(), 2);
+        assert_eq!(path_copy.bounds()?, path.bounds()?);
+        assert_eq!(path_copy.fill_color()?, PdfColor::RED);
+
+        // The copied text should be translated by the given matrix, and should use
+        // a font loaded into the destination document.
+
+        let text_bounds = text.bounds()?;
+
+        let copy_bounds = text_copy.bounds()?;
+
+        assert!((copy_bounds.left.value - text_bounds.left.value - 50.0).abs() < 0.01);
+        assert!((copy_bounds.bottom.value - text_bounds.bottom.value - 25.0).abs() < 0.01);
+        assert_eq!(
+            text_copy.as_text_object().unwrap().font().name(),
+            "Helvetica"
+        );
+
+        drop(text);
+        drop(path);
+        drop(source_page);
+        drop(source);
+        drop(text_copy);
+        drop(path_copy);
+        drop(destination_page);
+
+        // The copies should survive a round trip through a saved document, independently
+        // of the source document.
+
+        let document = pdfium.load_pdf_from_byte_vec(destination.save_to_bytes()?, None)?;
+
+        let page = document.pages().first()?;
+
+        assert_eq!(page.objects().len(), 2);
+        assert!(page.text()?.all().contains("Copied text"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quad_bounds_of_rotated_object() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut path = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        path.rotate_counter_clockwise_degrees(45.0)?;
+
+        let matrix = path.matrix()?;
+
+        let bounds = path.bounds()?;
+
+        let quad = path.quad_bounds()?;
+
+        // Measuring the quad bounds should leave the object's matrix untouched.
+
+        assert_eq!(path.matrix()?, matrix);
+
+        // Rotating the square about the origin places its corners at the midpoints
+        // of the edges of its axis-aligned bounding box.
+
+        let half_diagonal = 50.0 * 2.0_f32.sqrt();
+
+        assert!((bounds.left.value + half_diagonal).abs() < 0.1);
+        assert!((bounds.right.value - half_diagonal).abs() < 0.1);
+        assert!((bounds.bottom.value - 2.0 * half_diagonal).abs() < 0.1);
+        assert!((bounds.top.value - 4.0 * half_diagonal).abs() < 0.1);
+
+        let enclosing = quad.to_rect();
+
+        assert!((enclosing.left.value - bounds.left.value).abs() < 0.1);
+        assert!((enclosing.bottom.value - bounds.bottom.value).abs() < 0.1);
+        assert!((enclosing.right.value - bounds.right.value).abs() < 0.1);
+        assert!((enclosing.top.value - bounds.top.value).abs() < 0.1);
+
+        // The center of the rotated square lies inside the quad; the corners of the
+        // axis-aligned bounding box do not.
+
+        assert!(quad.contains(PdfPoints::ZERO, PdfPoints::new(3.0 * half_diagonal)));
+        assert!(!quad.contains(
+            PdfPoints::new(bounds.left.value + 5.0),
+            PdfPoints::new(bounds.bottom.value + 5.0)
+        ));
+        assert!(!quad.contains(
+            PdfPoints::new(bounds.right.value - 5.0),
+            PdfPoints::new(bounds.top.value - 5.0)
+        ));
+
+        Ok(())
+    }
 }
@@ -583,7 +583,7 @@ impl<'a> PdfPageGroupObject<'a> {
         &mut self,
         blend_mode: PdfPageObjectBlendMode,
     ) -> Result<(), PdfiumError> {
-        self.apply_to_each(|object| object.set_blend_mode(blend_mode))
+        self.apply_to_each(|object| object.set_blend_mode(blend_mode.clone()))
     }
 
     /// Sets the color of any filled paths in every [PdfPageObject] in this group.
@@ -12,12 +12,15 @@ use crate::color_space::PdfColorSpace;
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::page::PdfPage;
 use crate::page_object::PdfPageObject;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::points::PdfPoints;
+use crate::utils::files::get_pdfium_file_accessor_from_reader;
 use crate::utils::mem::create_byte_buffer;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
+use std::io::Cursor;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_int, c_void};
 
@@ -392,9 +395,7 @@ impl<'a> PdfPageImageObject<'a> {
             // to the caller.
 
             self.reset_matrix_impl(original_matrix)?;
-            return Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
-            ));
+            return Err(PdfiumError::ImageObjectRenderingFailed);
         }
 
         let result = PdfBitmap::from_pdfium(handle, self.bindings);
@@ -422,30 +423,57 @@ impl<'a> PdfPageImageObject<'a> {
 
             // Generate the bitmap again at the new scale.
 
-            let result = PdfBitmap::from_pdfium(
-                match self.page_handle {
-                    Some(page_handle) => self.bindings.FPDFImageObj_GetRenderedBitmap(
-                        document.handle(),
-                        page_handle,
-                        self.object_handle,
-                    ),
-                    None => self.bindings.FPDFImageObj_GetRenderedBitmap(
-                        document.handle(),
-                        std::ptr::null_mut::<fpdf_page_t__>(),
-                        self.object_handle,
-                    ),
-                },
-                self.bindings,
-            );
+            let handle = match self.page_handle {
+                Some(page_handle) => self.bindings.FPDFImageObj_GetRenderedBitmap(
+                    document.handle(),
+                    page_handle,
+                    self.object_handle,
+                ),
+                None => self.bindings.FPDFImageObj_GetRenderedBitmap(
+                    document.handle(),
+                    std::ptr::null_mut::<fpdf_page_t__>(),
+                    self.object_handle,
+                ),
+            };
 
             // Restore the original transformation matrix values before we return to the caller.
 
             self.reset_matrix_impl(original_matrix)?;
 
-            Ok(result)
+            if handle.is_null() {
+                Err(PdfiumError::ImageObjectRenderingFailed)
+            } else {
+                Ok(PdfBitmap::from_pdfium(handle, self.bindings))
+            }
         }
     }
 
+    /// Returns a new [PdfBitmap] created from the bitmap buffer backing
+    /// this [PdfPageImageObject], taking into account any image filters, image mask, and
+    /// object transforms applied to this page object.
+    ///
+    /// The returned bitmap will be scaled during rendering so its width and height are
+    /// the current width and height of the image multiplied by the given scale factor.
+    /// A scale factor less than 1.0 can be used to render a thumbnail of a large image
+    /// without rendering the image at its full size.
+    pub fn get_processed_bitmap_with_scale(
+        &self,
+        document: &PdfDocument,
+        scale: f32,
+    ) -> Result<PdfBitmap, PdfiumError> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(PdfiumError::ImageSizeOutOfBounds);
+        }
+
+        let (current_width, current_height) = self.get_current_width_and_height_from_metadata()?;
+
+        self.get_processed_bitmap_with_size(
+            document,
+            ((current_width as f32 * scale).round() as Pixels).max(1),
+            ((current_height as f32 * scale).round() as Pixels).max(1),
+        )
+    }
+
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing
     /// this [PdfPageImageObject], taking into account any image filters, image mask, and
     /// object transforms applied to this page object.
@@ -466,6 +494,27 @@ impl<'a> PdfPageImageObject<'a> {
             .and_then(|bitmap| self.get_image_from_bitmap(&bitmap))
     }
 
+    /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing
+    /// this [PdfPageImageObject], taking into account any image filters, image mask, and
+    /// object transforms applied to this page object.
+    ///
+    /// The returned image will be scaled during rendering so its width and height are
+    /// the current width and height of the image multiplied by the given scale factor.
+    /// A scale factor less than 1.0 can be used to render a thumbnail of a large image
+    /// without rendering the image at its full size.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    #[inline]
+    pub fn get_processed_image_with_scale(
+        &self,
+        document: &PdfDocument,
+        scale: f32,
+    ) -> Result<DynamicImage, PdfiumError> {
+        self.get_processed_bitmap_with_scale(document, scale)
+            .and_then(|bitmap| self.get_image_from_bitmap(&bitmap))
+    }
+
     #[cfg(feature = "image")]
     pub(crate) fn get_image_from_bitmap(
         &self,
@@ -533,9 +582,60 @@ impl<'a> PdfPageImageObject<'a> {
 
     /// Applies the byte data in the given `Image::DynamicImage` to this [PdfPageImageObject].
     ///
+    /// If the given image has an alpha channel, Pdfium stores the alpha channel as a soft mask
+    /// alongside the image data, so transparent areas of the image remain transparent when
+    /// the page is rendered. Images without an alpha channel are stored without a soft mask.
+    ///
     /// This function is only available when this crate's `image` feature is enabled.
     #[cfg(feature = "image")]
     pub fn set_image(&mut self, image: &DynamicImage) -> Result<(), PdfiumError> {
+        let bitmap = self.get_bitmap_from_image(image)?;
+
+        self.set_bitmap(&bitmap)
+    }
+
+    /// Sets the soft mask of the image assigned to this [PdfPageImageObject] to the given
+    /// grayscale image, replacing any existing soft mask. Black areas of the mask are fully
+    /// transparent and white areas are fully opaque.
+    ///
+    /// The mask must have the same pixel dimensions as the image. Pdfium offers no way of
+    /// changing a soft mask independently of the image it masks, so the image data is
+    /// re-encoded without any of its original image filters.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn set_mask_image(&mut self, mask: &GrayImage) -> Result<(), PdfiumError> {
+        let mut image = self.get_raw_image()?.to_rgba8();
+
+        if image.dimensions() != mask.dimensions() {
+            return Err(PdfiumError::ImageMaskSizeMismatch);
+        }
+
+        for (pixel, mask_pixel) in image.pixels_mut().zip(mask.pixels()) {
+            pixel.0[3] = mask_pixel.0[0];
+        }
+
+        self.set_image(&DynamicImage::ImageRgba8(image))
+    }
+
+    /// Removes any soft mask from the image assigned to this [PdfPageImageObject],
+    /// making the image fully opaque.
+    ///
+    /// Pdfium offers no way of changing a soft mask independently of the image it masks,
+    /// so the image data is re-encoded without any of its original image filters.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn clear_mask(&mut self) -> Result<(), PdfiumError> {
+        let image = self.get_raw_image()?.to_rgb8();
+
+        self.set_image(&DynamicImage::ImageRgb8(image))
+    }
+
+    /// Creates a new [PdfBitmap] containing the byte data in the given `Image::DynamicImage`,
+    /// ready to be applied to this [PdfPageImageObject].
+    #[cfg(feature = "image")]
+    fn get_bitmap_from_image(&self, image: &DynamicImage) -> Result<PdfBitmap<'a>, PdfiumError> {
         let width: Pixels = image
             .width()
             .try_into()
@@ -546,7 +646,16 @@ impl<'a> PdfPageImageObject<'a> {
             .try_into()
             .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?;
 
-        let bitmap = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, self.bindings)?;
+        // Pdfium only creates a soft mask for bitmaps in an alpha-carrying format,
+        // so opaque images are passed in BGRx format.
+
+        let format = if image.color().has_alpha() {
+            PdfBitmapFormat::BGRA
+        } else {
+            PdfBitmapFormat::BGRx
+        };
+
+        let bitmap = PdfBitmap::empty(width, height, format, self.bindings)?;
 
         let buffer = if let Some(image) = image.as_rgba8() {
             // The given image is already in RGBA format.
@@ -569,14 +678,29 @@ impl<'a> PdfPageImageObject<'a> {
             ));
         }
 
-        self.set_bitmap(&bitmap)
+        Ok(bitmap)
     }
 
     /// Applies the byte data in the given [PdfBitmap] to this [PdfPageImageObject].
+    ///
+    /// The object's transformation matrix is left unchanged, so the object keeps its position
+    /// and size on the page. Since an image always fills the unit square of its object,
+    /// a bitmap with a different aspect ratio to the existing image will be stretched to
+    /// fit the existing placement; adjust the matrix afterwards if this is not wanted.
     pub fn set_bitmap(&mut self, bitmap: &PdfBitmap) -> Result<(), PdfiumError> {
+        // If this object is attached to a page, Pdfium must be given the page so that
+        // it can discard any cached rendering of the previous image.
+
+        let mut page_handle = self.page_handle;
+
+        let (pages, count) = match page_handle.as_mut() {
+            Some(page_handle) => (page_handle as *mut FPDF_PAGE, 1),
+            None => (std::ptr::null_mut::<FPDF_PAGE>(), 0),
+        };
+
         if self.bindings.is_true(self.bindings.FPDFImageObj_SetBitmap(
-            std::ptr::null_mut::<FPDF_PAGE>(),
-            0,
+            pages,
+            count,
             self.object_handle,
             *bitmap.handle(),
         )) {
@@ -588,6 +712,77 @@ impl<'a> PdfPageImageObject<'a> {
         }
     }
 
+    /// Replaces the image displayed by this [PdfPageImageObject] with the given
+    /// `Image::DynamicImage`, keeping the object's transformation matrix unchanged so that
+    /// the new image occupies exactly the same area of the page as the old image, irrespective
+    /// of its pixel dimensions.
+    ///
+    /// If the given image has an alpha channel, Pdfium stores the alpha channel as a soft mask
+    /// alongside the image data; otherwise, the image is stored without a soft mask.
+    ///
+    /// If this object is attached to a `PdfPage`, the page's content will be regenerated
+    /// once the image is replaced.
+    ///
+    /// Pdfium modifies the image stream in place. If the same image stream is referenced
+    /// by other pages in the document, those pages will also display the new image; Pdfium
+    /// offers no way of detecting whether an image stream is shared in this way.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image")]
+    pub fn replace_image(&mut self, image: &DynamicImage) -> Result<(), PdfiumError> {
+        self.set_image(image)?;
+        self.regenerate_containing_page_content()
+    }
+
+    /// Replaces the image displayed by this [PdfPageImageObject] with the given JPEG file data,
+    /// keeping the object's transformation matrix unchanged so that the new image occupies
+    /// exactly the same area of the page as the old image, irrespective of its pixel dimensions.
+    ///
+    /// The JPEG data is embedded into the document as-is, without being decoded and re-encoded.
+    ///
+    /// If this object is attached to a `PdfPage`, the page's content will be regenerated
+    /// once the image is replaced.
+    ///
+    /// Pdfium modifies the image stream in place. If the same image stream is referenced
+    /// by other pages in the document, those pages will also display the new image; Pdfium
+    /// offers no way of detecting whether an image stream is shared in this way.
+    pub fn replace_image_jpeg_bytes(&mut self, bytes: &[u8]) -> Result<(), PdfiumError> {
+        let mut page_handle = self.page_handle;
+
+        let (pages, count) = match page_handle.as_mut() {
+            Some(page_handle) => (page_handle as *mut FPDF_PAGE, 1),
+            None => (std::ptr::null_mut::<FPDF_PAGE>(), 0),
+        };
+
+        let mut reader = get_pdfium_file_accessor_from_reader(Cursor::new(bytes));
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFImageObj_LoadJpegFileInline(
+                pages,
+                count,
+                self.object_handle,
+                reader.as_fpdf_file_access_mut_ptr(),
+            ))
+        {
+            self.regenerate_containing_page_content()
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Regenerates the content of the `PdfPage` containing this [PdfPageImageObject], if any.
+    fn regenerate_containing_page_content(&self) -> Result<(), PdfiumError> {
+        match self.page_handle {
+            Some(page_handle) => {
+                PdfPage::regenerate_content_immut_for_handle(page_handle, self.bindings)
+            }
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn get_raw_metadata(&self) -> Result<FPDF_IMAGEOBJ_METADATA, PdfiumError> {
         let mut metadata = FPDF_IMAGEOBJ_METADATA {
             width: 0,
@@ -635,6 +830,36 @@ impl<'a> PdfPageImageObject<'a> {
             .map(|metadata| metadata.vertical_dpi)
     }
 
+    /// Returns the effective horizontal and vertical dots per inch resolution of the image
+    /// assigned to this [PdfPageImageObject], as placed on the page.
+    ///
+    /// The resolution is calculated from the pixel dimensions of the image and the lengths
+    /// of the vectors that this object's transformation matrix maps the image's width and
+    /// height onto, so it remains accurate when the image is rotated or skewed.
+    /// Unlike [PdfPageImageObject::horizontal_dpi()] and [PdfPageImageObject::vertical_dpi()],
+    /// it does not depend on the axis-aligned bounding box of the object.
+    pub fn effective_dpi(&self) -> Result<(f32, f32), PdfiumError> {
+        let metadata = self.get_raw_metadata()?;
+
+        let matrix = self.matrix()?;
+
+        // The image occupies the unit square in image space, so the first and second
+        // columns of the matrix give the placed width and height of the image in points.
+
+        let placed_width = (matrix.a() * matrix.a() + matrix.b() * matrix.b()).sqrt();
+
+        let placed_height = (matrix.c() * matrix.c() + matrix.d() * matrix.d()).sqrt();
+
+        if placed_width == 0.0 || placed_height == 0.0 {
+            return Err(PdfiumError::ImageObjectHasZeroPlacedSize);
+        }
+
+        Ok((
+            metadata.width as f32 / placed_width * 72.0,
+            metadata.height as f32 / placed_height * 72.0,
+        ))
+    }
+
     /// Returns the bits per pixel for the image assigned to this [PdfPageImageObject].
     ///
     /// This value is not available if this object has not been attached to a `PdfPage`.
@@ -659,6 +884,156 @@ impl<'a> PdfPageImageObject<'a> {
         PdfPageImageObjectFilters::new(self)
     }
 
+    /// Returns the raw, still-encoded image data stream assigned to this [PdfPageImageObject],
+    /// along with the ordered list of [PdfImageFilter] values that must be applied to decode it.
+    ///
+    /// No decoding is performed, so the returned bytes can be re-embedded elsewhere
+    /// without the quality loss of a decode and re-encode round trip. For example, an image
+    /// with a single [PdfImageFilter::DctDecode] filter returns the bytes of a JPEG file.
+    /// Filters are listed in the order in which they are applied during decoding.
+    pub fn raw_image_data(&self) -> Result<(Vec<u8>, Vec<PdfImageFilter>), PdfiumError> {
+        // Retrieving the raw image data from Pdfium is a two-step operation. First, we call
+        // FPDFImageObj_GetImageDataRaw() with a null buffer; this will retrieve the length of
+        // the raw image data in bytes. If the length is zero, then there is no image data.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFImageObj_GetImageDataRaw() again with a pointer to the buffer;
+        // this will write the raw image data into the buffer.
+
+        let buffer_length =
+            self.bindings
+                .FPDFImageObj_GetImageDataRaw(self.object_handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDFImageObj_GetImageDataRaw(
+            self.object_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        let filters = self
+            .filters()
+            .iter()
+            .map(|filter| filter.filter_type())
+            .collect();
+
+        Ok((buffer, filters))
+    }
+
+    /// Returns the image filter identifying the standalone image file format of the raw
+    /// image data returned by [PdfPageImageObject::raw_image_data()], if the raw data can be
+    /// written directly to a file without any further processing.
+    ///
+    /// Returns [PdfImageFilter::DctDecode] if the raw data is a JPEG (JFIF) file, and
+    /// [PdfImageFilter::Jpx] if the raw data is a JPEG 2000 file. Returns `None` if the image
+    /// has no filters, more than one filter, or a filter whose encoded output is not
+    /// a standalone image file format. Only the image's filter list is inspected, so no image
+    /// data is read or decoded.
+    pub fn raw_image_data_file_format(&self) -> Option<PdfImageFilter> {
+        let filters = self.filters();
+
+        if filters.len() != 1 {
+            return None;
+        }
+
+        match filters.get(0).ok()?.filter_type() {
+            PdfImageFilter::DctDecode => Some(PdfImageFilter::DctDecode),
+            PdfImageFilter::Jpx => Some(PdfImageFilter::Jpx),
+            _ => None,
+        }
+    }
+
+    /// Returns the image data stream assigned to this [PdfPageImageObject], decoded by
+    /// Pdfium's stream filters but not rendered into a bitmap.
+    ///
+    /// Pdfium decodes general-purpose compression filters such as [PdfImageFilter::Flate],
+    /// but image-specific compression such as [PdfImageFilter::DctDecode] or
+    /// [PdfImageFilter::Jpx] may be left in place. The returned data is not adjusted by
+    /// the image's color space, decode array, or soft mask; use
+    /// [PdfPageImageObject::get_raw_image()] to retrieve a fully decoded image.
+    pub fn decoded_image_data(&self) -> Result<Vec<u8>, PdfiumError> {
+        // Retrieving the decoded image data from Pdfium is a two-step operation. First, we call
+        // FPDFImageObj_GetImageDataDecoded() with a null buffer; this will retrieve the length of
+        // the decoded image data in bytes. If the length is zero, then there is no image data.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFImageObj_GetImageDataDecoded() again with a pointer to the buffer;
+        // this will write the decoded image data into the buffer.
+
+        let buffer_length = self.bindings.FPDFImageObj_GetImageDataDecoded(
+            self.object_handle,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDFImageObj_GetImageDataDecoded(
+            self.object_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        Ok(buffer)
+    }
+
+    /// Returns a [PdfPageImageObjectMetadata] snapshot describing the image assigned to this
+    /// [PdfPageImageObject] and its placement on the page.
+    ///
+    /// The resolution reported in the returned metadata is the effective resolution
+    /// returned by [PdfPageImageObject::effective_dpi()], so it takes this object's current
+    /// transformation matrix into account.
+    ///
+    /// The bits per pixel and color space reported in the returned metadata are not available
+    /// if this object has not been attached to a `PdfPage`; both will be reported as zero
+    /// or unknown.
+    pub fn metadata(&self) -> Result<PdfPageImageObjectMetadata, PdfiumError> {
+        let metadata = self.get_raw_metadata()?;
+
+        let (horizontal_dpi, vertical_dpi) = self.effective_dpi()?;
+
+        Ok(PdfPageImageObjectMetadata {
+            width: metadata
+                .width
+                .try_into()
+                .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?,
+            height: metadata
+                .height
+                .try_into()
+                .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?,
+            horizontal_dpi,
+            vertical_dpi,
+            bits_per_pixel: metadata.bits_per_pixel as u8,
+            color_space: PdfColorSpace::from_pdfium(metadata.colorspace as u32)?,
+            marked_content_id: match metadata.marked_content_id {
+                -1 => None,
+                id => Some(id),
+            },
+            filters: self
+                .filters()
+                .iter()
+                .map(|filter| filter.filter_type())
+                .collect(),
+        })
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -749,6 +1124,129 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageImageObject<'a> {
     }
 }
 
+/// A snapshot of the metadata describing the image assigned to a [PdfPageImageObject]
+/// and its placement on the page, as returned by [PdfPageImageObject::metadata()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPageImageObjectMetadata {
+    width: Pixels,
+    height: Pixels,
+    horizontal_dpi: f32,
+    vertical_dpi: f32,
+    bits_per_pixel: u8,
+    color_space: PdfColorSpace,
+    marked_content_id: Option<i32>,
+    filters: Vec<PdfImageFilter>,
+}
+
+impl PdfPageImageObjectMetadata {
+    /// Returns the width of the image, in pixels.
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.width
+    }
+
+    /// Returns the height of the image, in pixels.
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.height
+    }
+
+    /// Returns the effective horizontal dots per inch resolution of the image,
+    /// as placed on the page.
+    #[inline]
+    pub fn horizontal_dpi(&self) -> f32 {
+        self.horizontal_dpi
+    }
+
+    /// Returns the effective vertical dots per inch resolution of the image,
+    /// as placed on the page.
+    #[inline]
+    pub fn vertical_dpi(&self) -> f32 {
+        self.vertical_dpi
+    }
+
+    /// Returns the number of bits per pixel of the image.
+    #[inline]
+    pub fn bits_per_pixel(&self) -> u8 {
+        self.bits_per_pixel
+    }
+
+    /// Returns the color space of the image.
+    #[inline]
+    pub fn color_space(&self) -> PdfColorSpace {
+        self.color_space
+    }
+
+    /// Returns the marked content ID of the image object, if any.
+    #[inline]
+    pub fn marked_content_id(&self) -> Option<i32> {
+        self.marked_content_id
+    }
+
+    /// Returns the image filters that must be applied, in order, to decode the image data.
+    #[inline]
+    pub fn filters(&self) -> &[PdfImageFilter] {
+        self.filters.as_slice()
+    }
+}
+
+/// The type of a single image filter applied to a [PdfPageImageObject].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PdfImageFilter {
+    /// ASCII hexadecimal encoding.
+    AsciiHex,
+
+    /// ASCII base-85 encoding.
+    Ascii85,
+
+    /// LZW compression.
+    Lzw,
+
+    /// zlib/deflate compression.
+    Flate,
+
+    /// Byte-oriented run-length compression.
+    RunLength,
+
+    /// CCITT Group 3 or Group 4 facsimile compression.
+    CcittFax,
+
+    /// JBIG2 compression.
+    Jbig2,
+
+    /// JPEG compression.
+    DctDecode,
+
+    /// JPEG 2000 compression.
+    Jpx,
+
+    /// Encryption applied by a security handler.
+    Crypt,
+
+    /// A filter with the given name that is not defined by the PDF specification.
+    Unknown(String),
+}
+
+impl PdfImageFilter {
+    pub(crate) fn from_pdfium(name: &str) -> Self {
+        // Abbreviated filter names are permitted in inline images.
+
+        match name {
+            "ASCIIHexDecode" | "AHx" => PdfImageFilter::AsciiHex,
+            "ASCII85Decode" | "A85" => PdfImageFilter::Ascii85,
+            "LZWDecode" | "LZW" => PdfImageFilter::Lzw,
+            "FlateDecode" | "Fl" => PdfImageFilter::Flate,
+            "RunLengthDecode" | "RL" => PdfImageFilter::RunLength,
+            "CCITTFaxDecode" | "CCF" => PdfImageFilter::CcittFax,
+            "JBIG2Decode" => PdfImageFilter::Jbig2,
+            "DCTDecode" | "DCT" => PdfImageFilter::DctDecode,
+            "JPXDecode" => PdfImageFilter::Jpx,
+            "Crypt" => PdfImageFilter::Crypt,
+            _ => PdfImageFilter::Unknown(name.to_owned()),
+        }
+    }
+}
+
 pub type PdfPageImageObjectFilterIndex = usize;
 
 /// A collection of all the image filters applied to a [PdfPageImageObject].
@@ -865,6 +1363,12 @@ impl PdfPageImageObjectFilter {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Returns the [PdfImageFilter] type of this [PdfPageImageObjectFilter].
+    #[inline]
+    pub fn filter_type(&self) -> PdfImageFilter {
+        PdfImageFilter::from_pdfium(self.name())
+    }
 }
 
 /// An iterator over all the [PdfPageImageObjectFilter] objects in a
@@ -900,6 +1404,8 @@ impl<'a> Iterator for PdfPageImageObjectFiltersIterator<'a> {
 mod tests {
     use crate::prelude::*;
     use crate::utils::test::test_bind_to_pdfium;
+    use image::{DynamicImage, GrayImage, ImageFormat, RgbImage, RgbaImage};
+    use std::io::Cursor;
 
     #[test]
     fn test_page_image_object_retains_format() -> Result<(), PdfiumError> {
@@ -964,6 +1470,514 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_raw_image_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let object = page.objects().get(0)?;
+
+        let (data, filters) = object.as_image_object().unwrap().raw_image_data()?;
+
+        // The Flate-encoded stream is returned as-is, starting with a zlib header.
+
+        assert_eq!(data.len(), 16);
+        assert_eq!(data[0], 0x78);
+        assert_eq!(filters, vec![PdfImageFilter::Flate]);
+
+        let object = page.objects().get(1)?;
+
+        let (data, filters) = object.as_image_object().unwrap().raw_image_data()?;
+
+        assert_eq!(data.as_slice(), b"789CFBCFC0C0F01F848100001DEE05FB>");
+        assert_eq!(
+            filters,
+            vec![PdfImageFilter::AsciiHex, PdfImageFilter::Flate]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoded_image_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        // Both images decode to the same red, green, blue, and white 2x2 pixel RGB image data,
+        // regardless of how many filters were applied to encode them.
+
+        for index in 0..2 {
+            let object = page.objects().get(index)?;
+
+            let image = object.as_image_object().unwrap();
+
+            assert_eq!(
+                image.decoded_image_data()?,
+                vec![255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255]
+            );
+
+            // Flate-encoded data is not a standalone image file.
+
+            assert_eq!(image.raw_image_data_file_format(), None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_effective_dpi() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let mut object = page.objects().get(0)?;
+
+        let image = object.as_image_object_mut().unwrap();
+
+        // The 2x2 pixel image is placed in a 50x50 point square.
+
+        let (horizontal_dpi, vertical_dpi) = image.effective_dpi()?;
+
+        assert!((horizontal_dpi - 2.88).abs() < 0.001);
+        assert!((vertical_dpi - 2.88).abs() < 0.001);
+
+        // Scaling changes the effective resolution; rotation does not.
+
+        image.scale(0.5, 2.0)?;
+        image.rotate_clockwise_degrees(30.0)?;
+
+        let (horizontal_dpi, vertical_dpi) = image.effective_dpi()?;
+
+        assert!((horizontal_dpi - 5.76).abs() < 0.001);
+        assert!((vertical_dpi - 1.44).abs() < 0.001);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let mut object = page.objects().get(1)?;
+
+        let image = object.as_image_object_mut().unwrap();
+
+        // The 2x2 pixel RGB image is placed in a 50x50 point square.
+
+        let metadata = image.metadata()?;
+
+        assert_eq!(metadata.width(), 2);
+        assert_eq!(metadata.height(), 2);
+        assert!((metadata.horizontal_dpi() - 2.88).abs() < 0.001);
+        assert!((metadata.vertical_dpi() - 2.88).abs() < 0.001);
+        assert_eq!(metadata.bits_per_pixel(), 24);
+        assert_eq!(metadata.color_space(), PdfColorSpace::DeviceRGB);
+        assert_eq!(metadata.marked_content_id(), None);
+        assert_eq!(
+            metadata.filters(),
+            &[PdfImageFilter::AsciiHex, PdfImageFilter::Flate]
+        );
+
+        // Scaling the object changes the reported resolution.
+
+        image.scale(0.5, 2.0)?;
+
+        let metadata = image.metadata()?;
+
+        assert!((metadata.horizontal_dpi() - 5.76).abs() < 0.001);
+        assert!((metadata.vertical_dpi() - 1.44).abs() < 0.001);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_processed_bitmap_with_scale() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let object = page.objects().get(0)?;
+
+        let image = object.as_image_object().unwrap();
+
+        // The 2x2 pixel image is rendered at ten times its size.
+
+        let bitmap = image.get_processed_bitmap_with_scale(&document, 10.0)?;
+
+        assert!((bitmap.width() - 20).abs() <= 1);
+        assert!((bitmap.height() - 20).abs() <= 1);
+
+        // Rendering does not disturb the object's placement on the page.
+
+        assert_eq!(
+            image.matrix()?,
+            PdfMatrix::new(50.0, 0.0, 0.0, 50.0, 20.0, 20.0)
+        );
+
+        assert!(matches!(
+            image.get_processed_bitmap_with_scale(&document, 0.0),
+            Err(PdfiumError::ImageSizeOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_blend_mode() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, image::Rgb([200, 100, 50]))),
+            Some(PdfPoints::new(200.0)),
+            Some(PdfPoints::new(200.0)),
+        )?;
+
+        let mut rect = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(50.0, 50.0, 150.0, 150.0),
+            None,
+            None,
+            Some(PdfColor::new(128, 128, 128, 255)),
+        )?;
+
+        // Returns the RGB color of the pixel at the center of the rendered page.
+
+        let center = |page: &PdfPage| -> Result<Vec<u8>, PdfiumError> {
+            let bitmap =
+                page.render_with_config(&PdfRenderConfig::new().set_target_size(200, 200))?;
+
+            let offset = ((100 * bitmap.width() + 100) * 4) as usize;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 3].to_vec())
+        };
+
+        let normal = center(&page)?;
+
+        // A 50% gray rectangle multiplied over the image halves each color channel
+        // of the image, rather than replacing it.
+
+        rect.set_blend_mode(PdfPageObjectBlendMode::Multiply)?;
+
+        let multiplied = center(&page)?;
+
+        assert!(normal
+            .iter()
+            .all(|channel| (*channel as i32 - 128).abs() <= 2));
+        assert!(multiplied
+            .iter()
+            .zip(normal.iter())
+            .all(|(multiplied, normal)| multiplied < normal));
+        assert!(multiplied
+            .iter()
+            .zip([100, 50, 25].iter())
+            .all(|(actual, expected)| (*actual as i32 - *expected).abs() <= 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_send_to_back_behind_image() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        let image = page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, image::Rgb([0, 0, 255]))),
+            Some(PdfPoints::new(200.0)),
+            Some(PdfPoints::new(200.0)),
+        )?;
+
+        let rect = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(50.0, 50.0, 150.0, 150.0),
+            None,
+            None,
+            Some(PdfColor::new(255, 0, 0, 255)),
+        )?;
+
+        // Returns the RGB color of the pixel at the center of the rendered page.
+
+        let center = |page: &PdfPage| -> Result<Vec<u8>, PdfiumError> {
+            let bitmap =
+                page.render_with_config(&PdfRenderConfig::new().set_target_size(200, 200))?;
+
+            let offset = ((100 * bitmap.width() + 100) * 4) as usize;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 3].to_vec())
+        };
+
+        let rect_bounds = rect.bounds()?;
+
+        assert_eq!(center(&page)?, vec![255, 0, 0]);
+
+        // Sending the rectangle behind the image should hide it completely.
+
+        page.objects_mut().send_to_back(&rect)?;
+
+        assert_eq!(center(&page)?, vec![0, 0, 255]);
+        assert_eq!(
+            page.objects().get(0)?.object_type(),
+            PdfPageObjectType::Path
+        );
+        assert_eq!(
+            page.objects().get(1)?.object_type(),
+            PdfPageObjectType::Image
+        );
+
+        // Bringing it back to the front should reveal it again.
+
+        page.objects_mut().bring_to_front(&rect)?;
+
+        assert_eq!(center(&page)?, vec![255, 0, 0]);
+        assert_eq!(
+            page.objects().get(0)?.object_type(),
+            PdfPageObjectType::Image
+        );
+
+        page.objects_mut().move_object_to_index(&image, 1)?;
+
+        assert_eq!(center(&page)?, vec![0, 0, 255]);
+        assert_eq!(
+            page.objects().get(1)?.object_type(),
+            PdfPageObjectType::Image
+        );
+        assert!(matches!(
+            page.objects_mut().move_object_to_index(&image, 2),
+            Err(PdfiumError::PageObjectIndexOutOfBounds)
+        ));
+
+        // The objects should keep their bounds, and the new order should survive
+        // a round trip through a saved document.
+
+        assert_eq!(page.objects().get(0)?.bounds()?, rect_bounds);
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().first()?;
+
+        assert_eq!(center(&page)?, vec![0, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_soft_mask() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        // A half-transparent red image placed over a blue background should blend to purple.
+
+        let mut object = page.objects_mut().create_image_object(
+            PdfPoints::new(50.0),
+            PdfPoints::new(50.0),
+            &DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, image::Rgba([255, 0, 0, 128]))),
+            Some(PdfPoints::new(100.0)),
+            Some(PdfPoints::new(100.0)),
+        )?;
+
+        // Returns the RGBA color of the pixel at the center of the rendered page.
+
+        let center = |page: &PdfPage| -> Result<Vec<u8>, PdfiumError> {
+            let bitmap =
+                page.render_with_config(&PdfRenderConfig::new().set_target_size(200, 200))?;
+
+            let offset = ((100 * bitmap.width() + 100) * 4) as usize;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 4].to_vec())
+        };
+
+        let is_close_to = |color: &[u8], expected: [u8; 3]| {
+            color
+                .iter()
+                .zip(expected.iter())
+                .all(|(a, b)| (*a as i32 - *b as i32).abs() <= 2)
+        };
+
+        assert!(is_close_to(&center(&page)?, [128, 0, 127]));
+
+        // Clearing the mask makes the image fully opaque.
+
+        object.as_image_object_mut().unwrap().clear_mask()?;
+
+        assert!(is_close_to(&center(&page)?, [255, 0, 0]));
+
+        // A black mask makes the image fully transparent.
+
+        object
+            .as_image_object_mut()
+            .unwrap()
+            .set_mask_image(&GrayImage::from_pixel(4, 4, image::Luma([0])))?;
+
+        assert!(is_close_to(&center(&page)?, [0, 0, 255]));
+
+        // The mask must match the size of the image.
+
+        assert!(matches!(
+            object
+                .as_image_object_mut()
+                .unwrap()
+                .set_mask_image(&GrayImage::new(2, 2)),
+            Err(PdfiumError::ImageMaskSizeMismatch)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_bitmap_preserves_placement() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let mut object = page.objects().get(0)?;
+
+        let image = object.as_image_object_mut().unwrap();
+
+        let matrix = image.matrix()?;
+
+        // Replace the 2x2 pixel image with a 4x1 pixel bitmap of a different aspect ratio.
+
+        let bitmap = PdfBitmap::empty(4, 1, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        image.set_bitmap(&bitmap)?;
+
+        assert_eq!(image.matrix()?, matrix);
+        assert_eq!(image.get_raw_bitmap()?.width(), 4);
+        assert_eq!(image.get_raw_bitmap()?.height(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_image_preserves_placement() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let mut object = page.objects().get(0)?;
+
+        let image = object.as_image_object_mut().unwrap();
+
+        let matrix = image.matrix()?;
+
+        // Replace the 2x2 pixel image with a higher resolution, partially transparent image.
+
+        let replacement =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(8, 6, image::Rgba([255, 0, 0, 128])));
+
+        image.replace_image(&replacement)?;
+
+        assert_eq!(image.matrix()?, matrix);
+        assert_eq!(image.get_raw_bitmap()?.width(), 8);
+        assert_eq!(image.get_raw_bitmap()?.height(), 6);
+
+        // The page content has been regenerated, so the replacement survives a save.
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let object = page.objects().get(0)?;
+
+        let image = object.as_image_object().unwrap();
+
+        assert_eq!(image.matrix()?, matrix);
+        assert_eq!(image.get_raw_bitmap()?.width(), 8);
+        assert_eq!(image.get_raw_bitmap()?.height(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_image_jpeg_bytes() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Encode a 6x3 pixel replacement image as JPEG.
+
+        let mut jpeg = Vec::new();
+
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(6, 3, image::Rgb([0, 0, 255])))
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let document = pdfium.load_pdf_from_file("./test/raw-image-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let mut object = page.objects().get(0)?;
+
+        let image = object.as_image_object_mut().unwrap();
+
+        let matrix = image.matrix()?;
+
+        image.replace_image_jpeg_bytes(jpeg.as_slice())?;
+
+        assert_eq!(image.matrix()?, matrix);
+        assert_eq!(image.get_raw_bitmap()?.width(), 6);
+        assert_eq!(image.get_raw_bitmap()?.height(), 3);
+        assert_eq!(
+            image.raw_image_data()?,
+            (jpeg, vec![PdfImageFilter::DctDecode])
+        );
+
+        Ok(())
+    }
+
     fn compare_equality_of_byte_arrays(a: &[u8], b: &[u8]) -> bool {
         if a.len() != b.len() {
             return false;
@@ -0,0 +1,347 @@
+//! Defines the [PdfPageObjectMark] struct, exposing functionality related to a single
+//! content mark in a `PdfPageObjectMarks` collection.
+
+use crate::bindgen::{
+    FPDF_BOOL, FPDF_OBJECT_NAME, FPDF_OBJECT_NUMBER, FPDF_OBJECT_STRING, FPDF_PAGEOBJECT,
+    FPDF_PAGEOBJECTMARK,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::document::PdfDocument;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+/// The value of a single parameter in the property list of a [PdfPageObjectMark].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfPageObjectMarkParamValue {
+    /// A numeric value. Pdfium only reports numeric values as integers.
+    Integer(i32),
+
+    /// A string or name value.
+    String(String),
+
+    /// A value of a type not supported by Pdfium's content mark API, such as
+    /// an array or dictionary.
+    Unsupported,
+}
+
+/// A single content mark attached to a page object.
+///
+/// Content marks tag the page objects in a marked content sequence. Marks are used to link
+/// page content to the elements of a tagged PDF's structure tree via a marked content ID,
+/// to flag decorative content as an `/Artifact` that should be skipped by screen readers,
+/// and to associate optional content groups with page objects, amongst other uses.
+///
+/// Each mark has a name, such as `P`, `Span`, or `Artifact`, and an optional property list
+/// of parameters.
+pub struct PdfPageObjectMark<'a> {
+    handle: FPDF_PAGEOBJECTMARK,
+    object_handle: FPDF_PAGEOBJECT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMark<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_PAGEOBJECTMARK,
+        object_handle: FPDF_PAGEOBJECT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMark {
+            handle,
+            object_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_PAGEOBJECTMARK` handle for this [PdfPageObjectMark].
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_PAGEOBJECTMARK {
+        self.handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMark].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name of this [PdfPageObjectMark], such as `P`, `Span`, or `Artifact`.
+    pub fn name(&self) -> String {
+        self.get_string(|buffer, buffer_length, out_buflen| {
+            self.bindings
+                .FPDFPageObjMark_GetName(self.handle, buffer, buffer_length, out_buflen)
+        })
+        .unwrap_or_default()
+    }
+
+    /// Returns the number of parameters in the property list of this [PdfPageObjectMark].
+    #[inline]
+    pub fn param_count(&self) -> usize {
+        self.bindings
+            .FPDFPageObjMark_CountParams(self.handle)
+            .max(0) as usize
+    }
+
+    /// Returns the keys of all parameters in the property list of this [PdfPageObjectMark].
+    pub fn param_keys(&self) -> Vec<String> {
+        (0..self.param_count())
+            .filter_map(|index| {
+                self.get_string(|buffer, buffer_length, out_buflen| {
+                    self.bindings.FPDFPageObjMark_GetParamKey(
+                        self.handle,
+                        index as c_ulong,
+                        buffer,
+                        buffer_length,
+                        out_buflen,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the value of the parameter with the given key in the property list of
+    /// this [PdfPageObjectMark], or `None` if no parameter with the given key exists.
+    pub fn param(&self, key: &str) -> Option<PdfPageObjectMarkParamValue> {
+        match self
+            .bindings
+            .FPDFPageObjMark_GetParamValueType(self.handle, key) as u32
+        {
+            FPDF_OBJECT_NUMBER => self
+                .int_param(key)
+                .map(PdfPageObjectMarkParamValue::Integer),
+            FPDF_OBJECT_STRING | FPDF_OBJECT_NAME => self
+                .get_string(|buffer, buffer_length, out_buflen| {
+                    self.bindings.FPDFPageObjMark_GetParamStringValue(
+                        self.handle,
+                        key,
+                        buffer,
+                        buffer_length,
+                        out_buflen,
+                    )
+                })
+                .map(PdfPageObjectMarkParamValue::String),
+            _ => {
+                if self.param_keys().iter().any(|k| k == key) {
+                    Some(PdfPageObjectMarkParamValue::Unsupported)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the integer value of the parameter with the given key in the property list
+    /// of this [PdfPageObjectMark], or `None` if no parameter with the given key exists or
+    /// its value is not numeric.
+    pub fn int_param(&self, key: &str) -> Option<i32> {
+        let mut value: c_int = 0;
+
+        if self
+            .bindings
+            .is_true(
+                self.bindings
+                    .FPDFPageObjMark_GetParamIntValue(self.handle, key, &mut value),
+            )
+        {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the raw bytes of the string parameter with the given key in the property list
+    /// of this [PdfPageObjectMark], or `None` if no parameter with the given key exists or
+    /// its value is not a string.
+    ///
+    /// Unlike [PdfPageObjectMark::param()], no decoding is applied to the returned bytes,
+    /// making this function suitable for retrieving binary data.
+    pub fn blob_param(&self, key: &str) -> Option<Vec<u8>> {
+        // Retrieving a blob value from Pdfium follows the same two-step operation as
+        // retrieving a string value, except that the value has no trailing null terminator.
+
+        let mut out_buflen: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamBlobValue(
+                self.handle,
+                key,
+                std::ptr::null_mut(),
+                0,
+                &mut out_buflen,
+            ))
+        {
+            return None;
+        }
+
+        if out_buflen == 0 {
+            return Some(Vec::new());
+        }
+
+        let buffer_length = out_buflen;
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDFPageObjMark_GetParamBlobValue(
+            self.handle,
+            key,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+            &mut out_buflen,
+        );
+
+        assert!(self.bindings.is_true(result));
+        assert_eq!(buffer_length, out_buflen);
+
+        Some(buffer)
+    }
+
+    /// Sets the parameter with the given key in the property list of this [PdfPageObjectMark]
+    /// to the given integer value, replacing any existing value.
+    ///
+    /// Pdfium requires the [PdfDocument] containing the page object to which this mark
+    /// is attached in order to create the mark's property list.
+    pub fn set_int_param(
+        &mut self,
+        document: &PdfDocument,
+        key: &str,
+        value: i32,
+    ) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_SetIntParam(
+                document.handle(),
+                self.object_handle,
+                self.handle,
+                key,
+                value,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the parameter with the given key in the property list of this [PdfPageObjectMark]
+    /// to the given string value, replacing any existing value.
+    ///
+    /// Pdfium requires the [PdfDocument] containing the page object to which this mark
+    /// is attached in order to create the mark's property list.
+    pub fn set_string_param(
+        &mut self,
+        document: &PdfDocument,
+        key: &str,
+        value: &str,
+    ) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_SetStringParam(
+                document.handle(),
+                self.object_handle,
+                self.handle,
+                key,
+                value,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the parameter with the given key in the property list of this [PdfPageObjectMark]
+    /// to a string containing the given raw bytes, replacing any existing value.
+    ///
+    /// Pdfium requires the [PdfDocument] containing the page object to which this mark
+    /// is attached in order to create the mark's property list.
+    pub fn set_blob_param(
+        &mut self,
+        document: &PdfDocument,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_SetBlobParam(
+                document.handle(),
+                self.object_handle,
+                self.handle,
+                key,
+                value.as_ptr() as *mut c_void,
+                value.len() as c_ulong,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Removes the parameter with the given key from the property list of
+    /// this [PdfPageObjectMark].
+    pub fn remove_param(&mut self, key: &str) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_RemoveParam(
+                self.object_handle,
+                self.handle,
+                key,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Retrieves a string value from this [PdfPageObjectMark] using the given
+    /// Pdfium string accessor function.
+    fn get_string(
+        &self,
+        accessor: impl Fn(*mut c_void, c_ulong, *mut c_ulong) -> FPDF_BOOL,
+    ) -> Option<String> {
+        // Retrieving a string value from Pdfium is a two-step operation. First, we call
+        // the accessor with a null buffer; this will retrieve the length of the value
+        // in bytes, including a trailing null terminator.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the accessor again with a pointer to the buffer;
+        // this will write the value to the buffer in UTF16-LE format.
+
+        let mut out_buflen: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(accessor(std::ptr::null_mut(), 0, &mut out_buflen))
+            || out_buflen == 0
+        {
+            return None;
+        }
+
+        let buffer_length = out_buflen;
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = accessor(
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+            &mut out_buflen,
+        );
+
+        assert!(self.bindings.is_true(result));
+        assert_eq!(buffer_length, out_buflen);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
@@ -0,0 +1,193 @@
+//! Defines the [PdfPageObjectMarks] struct, a collection of all the content marks
+//! attached to a single `PdfPageObject`.
+
+use crate::bindgen::FPDF_PAGEOBJECT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::page_object_mark::PdfPageObjectMark;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_ulong;
+
+#[cfg(doc)]
+use crate::page_object::PdfPageObject;
+
+/// The zero-based index of a single [PdfPageObjectMark] inside its containing
+/// [PdfPageObjectMarks] collection.
+pub type PdfPageObjectMarkIndex = usize;
+
+/// The collection of content marks attached to a single [PdfPageObject].
+///
+/// Marks are listed from the outermost to the innermost marked content sequence
+/// containing the page object.
+///
+/// If the containing `PdfPage` has a content regeneration strategy of
+/// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange`, changes to marks will be
+/// written to the page's content stream the next time the page's content is regenerated.
+pub struct PdfPageObjectMarks<'a> {
+    object_handle: FPDF_PAGEOBJECT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMarks<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        object_handle: FPDF_PAGEOBJECT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMarks {
+            object_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of marks in this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn len(&self) -> PdfPageObjectMarkIndex {
+        self.bindings
+            .FPDFPageObj_CountMarks(self.object_handle)
+            .max(0) as PdfPageObjectMarkIndex
+    }
+
+    /// Returns `true` if this [PdfPageObjectMarks] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of marks)` for this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageObjectMarkIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of marks - 1)`
+    /// for this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageObjectMarkIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfPageObjectMark] from this [PdfPageObjectMarks] collection.
+    pub fn get(&self, index: PdfPageObjectMarkIndex) -> Result<PdfPageObjectMark<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageObjectMarkIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDFPageObj_GetMark(self.object_handle, index as c_ulong);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns the first [PdfPageObjectMark] in this [PdfPageObjectMarks] collection
+    /// with the given name, if any.
+    pub fn find(&self, name: &str) -> Option<PdfPageObjectMark<'a>> {
+        self.as_range()
+            .filter_map(|index| self.get(index).ok())
+            .find(|mark| mark.name() == name)
+    }
+
+    /// Returns the marked content ID of the page object owning this [PdfPageObjectMarks]
+    /// collection, taken from the `MCID` parameter of the innermost mark that has one.
+    ///
+    /// The marked content ID links the page object to an element in the structure tree
+    /// of a tagged PDF.
+    pub fn marked_content_id(&self) -> Option<i32> {
+        self.as_range()
+            .rev()
+            .filter_map(|index| self.get(index).ok())
+            .find_map(|mark| mark.int_param("MCID"))
+    }
+
+    /// Creates a new [PdfPageObjectMark] with the given name, attaching it to the page object
+    /// owning this [PdfPageObjectMarks] collection. The new mark becomes the innermost mark.
+    ///
+    /// For example, creating a mark named `Artifact` flags the page object as decorative
+    /// content that should be skipped by screen readers.
+    pub fn create_mark(&mut self, name: &str) -> Result<PdfPageObjectMark<'a>, PdfiumError> {
+        let handle = self.bindings.FPDFPageObj_AddMark(self.object_handle, name);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Removes the mark at the given index from this [PdfPageObjectMarks] collection.
+    pub fn delete_at_index(&mut self, index: PdfPageObjectMarkIndex) -> Result<(), PdfiumError> {
+        let mark = self.get(index)?;
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFPageObj_RemoveMark(self.object_handle, mark.handle()),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns an iterator over all the marks in this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageObjectMarksIterator {
+        PdfPageObjectMarksIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfPageObjectMark] objects in a [PdfPageObjectMarks] collection.
+pub struct PdfPageObjectMarksIterator<'a> {
+    marks: &'a PdfPageObjectMarks<'a>,
+    next_index: PdfPageObjectMarkIndex,
+}
+
+impl<'a> PdfPageObjectMarksIterator<'a> {
+    #[inline]
+    pub(crate) fn new(marks: &'a PdfPageObjectMarks<'a>) -> Self {
+        PdfPageObjectMarksIterator {
+            marks,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageObjectMarksIterator<'a> {
+    type Item = PdfPageObjectMark<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.marks.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
@@ -18,13 +18,14 @@ use crate::points::PdfPoints;
 use crate::rect::PdfRect;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
+use std::f32::consts::FRAC_PI_2;
 use std::os::raw::{c_int, c_uint};
 
 /// Sets the method used to determine the path region to fill.
 ///
 /// The default fill mode used by `pdfium-render` when creating new [PdfPagePathObject]
 /// instances is [PdfPathFillMode::Winding]. The fill mode can be changed on an
-/// object-by-object basis by calling the [PdfPagePathObject::set_fill_and_stroke_mode()] function.
+/// object-by-object basis by calling the [PdfPagePathObject::set_fill_mode()] function.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PdfPathFillMode {
     /// The path will not be filled.
@@ -54,7 +55,7 @@ pub enum PdfPathFillMode {
     ///
     /// This is the default fill mode used by `pdfium-render` when creating new [PdfPagePathObject]
     /// instances. The fill mode can be changed on an object-by-object basis by calling the
-    /// [PdfPagePathObject::set_fill_and_stroke_mode()] function.
+    /// [PdfPagePathObject::set_fill_mode()] function.
     ///
     /// More information, including visual examples, can be found in Section 4.4.2 of
     /// the PDF Reference Manual, version 1.7, on page 232.
@@ -705,6 +706,143 @@ impl<'a> PdfPagePathObject<'a> {
         self.move_to(x, y)
     }
 
+    /// Appends a rectangle with rounded corners to this [PdfPagePathObject], filling a rectangle
+    /// from the current point to the given coordinates. Each corner is drawn as a quarter-circle
+    /// Bézier curve with the given radius. The current sub-path will be closed.
+    /// The new current point is set to the given coordinates.
+    ///
+    /// The radius is clamped to half the width or half the height of the rectangle,
+    /// whichever is smaller, so that adjacent corners never overlap.
+    pub fn rounded_rect_to(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        radius: PdfPoints,
+    ) -> Result<(), PdfiumError> {
+        const C: f32 = 0.551915;
+
+        let left = PdfPoints::new(self.current_point_x.value.min(x.value));
+
+        let right = PdfPoints::new(self.current_point_x.value.max(x.value));
+
+        let bottom = PdfPoints::new(self.current_point_y.value.min(y.value));
+
+        let top = PdfPoints::new(self.current_point_y.value.max(y.value));
+
+        let radius = PdfPoints::new(
+            radius
+                .value
+                .min((right - left).value / 2.0)
+                .min((top - bottom).value / 2.0)
+                .max(0.0),
+        );
+
+        let c = radius * C;
+
+        self.close_path()?;
+        self.move_to(left + radius, bottom)?;
+        self.line_to(right - radius, bottom)?;
+        self.bezier_to(
+            right,
+            bottom + radius,
+            right - radius + c,
+            bottom,
+            right,
+            bottom + radius - c,
+        )?;
+        self.line_to(right, top - radius)?;
+        self.bezier_to(
+            right - radius,
+            top,
+            right,
+            top - radius + c,
+            right - radius + c,
+            top,
+        )?;
+        self.line_to(left + radius, top)?;
+        self.bezier_to(
+            left,
+            top - radius,
+            left + radius - c,
+            top,
+            left,
+            top - radius + c,
+        )?;
+        self.line_to(left, bottom + radius)?;
+        self.bezier_to(
+            left + radius,
+            bottom,
+            left,
+            bottom + radius - c,
+            left + radius - c,
+            bottom,
+        )?;
+        self.close_path()?;
+        self.move_to(x, y)
+    }
+
+    /// Appends a circular arc to this [PdfPagePathObject], centered on the given coordinates
+    /// with the given radius. The arc begins at the given start angle and sweeps through the
+    /// given number of degrees, counter-clockwise for positive values and clockwise for
+    /// negative values. Angles are measured counter-clockwise from the positive x axis.
+    ///
+    /// A straight line segment is drawn from the current point to the start of the arc.
+    /// The arc itself is approximated using one Bézier curve for every 90 degrees of sweep.
+    /// The new current point is set to the end of the arc.
+    pub fn arc_to(
+        &mut self,
+        center_x: PdfPoints,
+        center_y: PdfPoints,
+        radius: PdfPoints,
+        start_degrees: f32,
+        sweep_degrees: f32,
+    ) -> Result<(), PdfiumError> {
+        let start = start_degrees.to_radians();
+
+        let sweep = sweep_degrees.to_radians();
+
+        let point_at = |angle: f32| {
+            (
+                center_x + radius * angle.cos(),
+                center_y + radius * angle.sin(),
+            )
+        };
+
+        let (start_x, start_y) = point_at(start);
+
+        self.line_to(start_x, start_y)?;
+
+        let count = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+
+        let step = sweep / count as f32;
+
+        // The distance from each end point to its adjacent control point, as a proportion of
+        // the radius, for a Bézier curve approximating an arc of the given step angle.
+
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+        for index in 0..count {
+            let from = start + step * index as f32;
+
+            let to = from + step;
+
+            let (from_x, from_y) = point_at(from);
+
+            let (to_x, to_y) = point_at(to);
+
+            self.bezier_to(
+                to_x,
+                to_y,
+                from_x - radius * (k * from.sin()),
+                from_y + radius * (k * from.cos()),
+                to_x + radius * (k * to.sin()),
+                to_y - radius * (k * to.cos()),
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Appends an ellipse to this [PdfPagePathObject] by drawing four Bézier curves approximating
     /// an ellipse filling a rectangle from the current point to the given coordinates.
     /// The current sub-path will be closed. The new current point is set to the given coordinates.
@@ -737,9 +875,10 @@ impl<'a> PdfPagePathObject<'a> {
         self.move_to(x, y)
     }
 
-    /// Draws an ellipse at the current point using the given horizontal and vertical radii.
-    /// The ellipse will be constructed using four Bézier curves, one for each quadrant.
-    fn ellipse(&mut self, x_radius: PdfPoints, y_radius: PdfPoints) -> Result<(), PdfiumError> {
+    /// Appends an ellipse centered on the current point to this [PdfPagePathObject], using
+    /// the given horizontal and vertical radii. The ellipse will be constructed as a closed
+    /// sub-path using four Bézier curves, one for each quadrant. The current point is unchanged.
+    pub fn ellipse(&mut self, x_radius: PdfPoints, y_radius: PdfPoints) -> Result<(), PdfiumError> {
         // Ellipse approximation method: https://spencermortensen.com/articles/bezier-circle/
         // Implementation based on: https://stackoverflow.com/a/2007782
 
@@ -786,7 +925,8 @@ impl<'a> PdfPagePathObject<'a> {
             orig_x - x_radius,
             orig_y - y_c,
         )?;
-        self.close_path()
+        self.close_path()?;
+        self.move_to(orig_x, orig_y)
     }
 
     /// Closes the current sub-path in this [PdfPagePathObject] by appending a straight line segment
@@ -870,12 +1010,41 @@ impl<'a> PdfPagePathObject<'a> {
         }
     }
 
+    /// Sets the method used to determine which sub-paths of any path in this [PdfPagePathObject]
+    /// should be filled, leaving the object's stroke setting unchanged.
+    ///
+    /// Setting [PdfPathFillMode::None] disables filling entirely.
+    pub fn set_fill_mode(&mut self, fill_mode: PdfPathFillMode) -> Result<(), PdfiumError> {
+        let do_stroke = self.is_stroked()?;
+
+        self.set_fill_and_stroke_mode(fill_mode, do_stroke)
+    }
+
+    /// Sets whether or not any path in this [PdfPagePathObject] should be stroked,
+    /// leaving the object's fill mode unchanged.
+    ///
+    /// Even if this object's path is set to be stroked, the stroke must be configured with
+    /// a visible color and a non-zero width in order to actually be visible.
+    pub fn set_stroked(&mut self, do_stroke: bool) -> Result<(), PdfiumError> {
+        let fill_mode = self.fill_mode()?;
+
+        self.set_fill_and_stroke_mode(fill_mode, do_stroke)
+    }
+
     /// Returns the collection of path segments currently defined by this [PdfPagePathObject].
     #[inline]
     pub fn segments(&self) -> PdfPagePathObjectSegments {
         PdfPagePathObjectSegments::from_pdfium(self.object_handle, self.bindings())
     }
 
+    /// Returns the collection of path segments currently defined by this [PdfPagePathObject],
+    /// with the coordinates of each segment transformed from the object's own coordinate
+    /// space into page coordinates using the object's current transformation matrix.
+    #[inline]
+    pub fn segments_in_page_space(&self) -> Result<PdfPagePathObjectSegments, PdfiumError> {
+        Ok(self.segments().transform(self.matrix()?))
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
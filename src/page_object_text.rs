@@ -16,16 +16,18 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::font::PdfFont;
-use crate::fonts::ToPdfFontToken;
+use crate::fonts::{PdfFontBuiltin, ToPdfFontToken};
 use crate::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::page_object::{PdfPageObject, PdfPageObjectCommon};
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_text::PdfPageText;
+use crate::page_text_char::PdfPageTextChar;
 use crate::page_text_chars::PdfPageTextChars;
 use crate::points::PdfPoints;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use crate::{create_transform_getters, create_transform_setters};
+use std::os::raw::{c_float, c_uint};
 
 /// The text rendering modes supported by the PDF standard, as listed in table 5.3
 /// on page 402 in the PDF Reference manual version 1.7.
@@ -138,6 +140,13 @@ impl PdfPageTextRenderMode {
 /// add the object to a containing `PdfPage` manually. To create a detached page text object,
 /// use the [PdfPageTextObject::new()] function. The detached page text object can later
 /// be attached to a page by using the `PdfPageObjects::add_text_object()` function.
+///
+/// Pdfium does not provide any way to retrieve or set the character spacing, word spacing,
+/// or horizontal scaling text state parameters of a text object, and does not write them
+/// when regenerating page content. Text can be scaled horizontally by applying a horizontal
+/// scale factor to the object's transformation matrix, for instance by calling
+/// [PdfPageTextObject::scale()] with a vertical scale factor of 1.0; the bounds of the
+/// object account for any such scaling.
 pub struct PdfPageTextObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
     page_handle: Option<FPDF_PAGE>,
@@ -361,6 +370,15 @@ impl<'a> PdfPageTextObject<'a> {
     }
 
     /// Sets the text rendering mode for the text contained within this [PdfPageTextObject].
+    ///
+    /// Stroked render modes outline each glyph using the stroke color and stroke width
+    /// of this [PdfPageTextObject]; filled render modes use its fill color.
+    /// Setting the render mode to [PdfPageTextRenderMode::Invisible] hides the text
+    /// without removing it from the page, so it can still be selected and extracted;
+    /// this is commonly used for the text layer of scanned documents that have been
+    /// processed with optical character recognition.
+    ///
+    /// Pdfium will not accept [PdfPageTextRenderMode::Unknown] as a render mode.
     pub fn set_render_mode(
         &mut self,
         render_mode: PdfPageTextRenderMode,
@@ -379,11 +397,50 @@ impl<'a> PdfPageTextObject<'a> {
 
     /// Returns a collection of the characters contained within this [PdfPageTextObject],
     /// using character retrieval functionality provided by the given [PdfPageText] object.
+    ///
+    /// Characters are measured on a copy of this text object, so overlapping text objects and
+    /// form XObjects drawn more than once on the page do not affect the result. The positions
+    /// and bounds of characters in a text object nested inside a form XObject are expressed
+    /// relative to the form, not to the page.
     #[inline]
     pub fn chars(&self, text: &'a PdfPageText<'a>) -> Result<PdfPageTextChars<'a>, PdfiumError> {
         text.chars_for_object(self)
     }
 
+    /// Returns the advance of the given [PdfPageTextChar], retrieved from this
+    /// [PdfPageTextObject] using the [PdfPageTextObject::chars()] function. The advance is the
+    /// distance along the baseline, in page coordinates, between the origin of the character
+    /// and the origin of the character following it.
+    ///
+    /// The advance is measured from the width of the character's glyph in the font used by
+    /// this [PdfPageTextObject], taking into account any scale factor applied to this text
+    /// object's transformation matrix. Pdfium does not expose the character spacing or word
+    /// spacing of a text object, so any such spacing is not included.
+    pub fn char_advance(&self, char: &PdfPageTextChar) -> PdfPoints {
+        let bindings = self.bindings();
+
+        let mut width = 0.0;
+
+        if bindings.is_true(bindings.FPDFFont_GetGlyphWidth(
+            bindings.FPDFTextObj_GetFont(self.object_handle),
+            char.unicode_value() as c_uint,
+            self.unscaled_font_size().value as c_float,
+            &mut width,
+        )) {
+            // The glyph width is measured along the text object's x axis, which may be
+            // scaled or rotated by the text object's transformation matrix.
+
+            let scale = self
+                .matrix()
+                .map(|matrix| matrix.a().hypot(matrix.b()))
+                .unwrap_or(1.0);
+
+            PdfPoints::new(width * scale)
+        } else {
+            PdfPoints::ZERO
+        }
+    }
+
     /// Returns `true` if any of the characters contained within this [PdfPageTextObject] have a
     /// glyph shape that descends below the font baseline.
     ///
@@ -488,10 +545,30 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageTextObject<'a> {
         document: FPDF_DOCUMENT,
         bindings: &'b dyn PdfiumLibraryBindings,
     ) -> Result<PdfPageObject<'b>, PdfiumError> {
+        // A font handle belongs to the document containing the font. Built-in fonts can be
+        // loaded afresh into the destination document by name, so the copy remains valid
+        // even if the destination is a different document. Any other font must already be
+        // present in the destination document.
+
+        let font = self.font();
+
+        let built_in_font =
+            PdfFontBuiltin::from_pdf_font_name(font.name().as_str()).map(|built_in| {
+                PdfFont::from_pdfium(
+                    bindings.FPDFText_LoadStandardFont(document, built_in.to_pdf_font_name()),
+                    bindings,
+                    Some(built_in),
+                    true,
+                )
+            });
+
         let mut copy = PdfPageTextObject::new_from_handles(
             document,
             self.text(),
-            self.font().handle(),
+            built_in_font
+                .as_ref()
+                .map(|font| font.handle())
+                .unwrap_or_else(|| font.handle()),
             self.unscaled_font_size(),
             bindings,
         )?;
@@ -1,17 +1,18 @@
 //! Defines the [PdfPageObjects] struct, exposing functionality related to the
 //! page objects contained within a single `PdfPage`.
 
-use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE, FPDF_PAGEOBJECT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::page_object::PdfPageObject;
+use crate::matrix::PdfMatrix;
+use crate::page_object::{PdfPageObject, PdfPageObjectType};
 use crate::page_object_group::PdfPageGroupObject;
 use crate::page_object_private::internal::PdfPageObjectPrivate;
 use crate::page_objects_common::{
     PdfPageObjectIndex, PdfPageObjectsCommon, PdfPageObjectsIterator,
 };
 use crate::page_objects_private::internal::PdfPageObjectsPrivate;
-use std::os::raw::c_int;
+use std::os::raw::{c_int, c_ulong};
 
 /// The page objects contained within a single `PdfPage`.
 ///
@@ -76,6 +77,24 @@ impl<'a> PdfPageObjects<'a> {
         Ok(result)
     }
 
+    /// Returns a depth-first iterator over all the [PdfPageObject] objects in this
+    /// [PdfPageObjects] collection, including the child objects of any
+    /// `PdfPageXObjectFormObject` objects, and the children of those children, and so on.
+    ///
+    /// Each form object is yielded before its children. Each object is yielded together with
+    /// the accumulated transformation matrix of the form objects containing it; applying this
+    /// matrix to the object's bounds converts them from the coordinate space of its containing
+    /// form into page coordinates. The matrix for objects directly on the page is
+    /// [PdfMatrix::IDENTITY].
+    ///
+    /// Pdfium does not parse a form that refers to itself, directly or indirectly, a second
+    /// time. As an additional safeguard, a form object is never descended into while it is
+    /// already being visited, so the iterator always terminates.
+    #[inline]
+    pub fn descendants(&self) -> PdfPageObjectsDescendantsIterator<'a> {
+        PdfPageObjectsDescendantsIterator::new(self.page_handle, self.bindings)
+    }
+
     /// Creates a new [PdfPageGroupObject] object group that can accept any [PdfPageObject]
     /// in this [PdfPageObjects] collection. The newly created group will be empty;
     /// you will need to manually add to it the objects you want to manipulate.
@@ -88,6 +107,193 @@ impl<'a> PdfPageObjects<'a> {
             self.do_regenerate_page_content_after_each_change,
         )
     }
+
+    /// Copies the given [PdfPageObject], which may belong to a page in any document, to the end
+    /// of this [PdfPageObjects] collection, returning the newly created copy.
+    ///
+    /// The copy keeps the geometry, colors, stroke settings, and transformation matrix of the
+    /// original object. If a matrix is given, it is applied to the copy afterwards, allowing
+    /// the copy to be repositioned, for instance to stamp the same logo onto every page.
+    ///
+    /// Text, path, and image objects can be copied, subject to the restrictions described in
+    /// `PdfPageObjectCommon::try_copy()`. Image data is re-embedded into the destination document.
+    /// Returns [PdfiumError::UnsupportedPdfPageObjectType] for shading objects and objects of
+    /// unsupported types; any other object that cannot be copied returns an error describing
+    /// why. Nothing is added to the page in either case.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn copy_object_from(
+        &mut self,
+        source: &PdfPageObject,
+        matrix: Option<PdfMatrix>,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let mut copy = source.try_copy_impl(self.document_handle, self.bindings)?;
+
+        if let Some(matrix) = matrix {
+            copy.apply_matrix(matrix)?;
+        }
+
+        self.add_object(copy)
+    }
+
+    /// Moves the given [PdfPageObject] to the given index in this [PdfPageObjects] collection,
+    /// changing the order in which objects are painted. Objects later in the collection are
+    /// painted on top of objects earlier in the collection.
+    ///
+    /// The indices of the other objects in this collection shift to make room for the moved
+    /// object, as if it had been removed and re-inserted at the given index. The properties and
+    /// transformation matrix of every object are preserved.
+    ///
+    /// Returns [PdfiumError::PageObjectNotAttachedToPage] if the given object is not
+    /// in this collection.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn move_object_to_index(
+        &mut self,
+        object: &PdfPageObject<'a>,
+        index: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        let mut handles = (0..self.len())
+            .map(|position| {
+                self.bindings
+                    .FPDFPage_GetObject(self.page_handle, position as c_int)
+            })
+            .collect::<Vec<_>>();
+
+        let current_index = handles
+            .iter()
+            .position(|handle| *handle == object.get_object_handle())
+            .ok_or(PdfiumError::PageObjectNotAttachedToPage)?;
+
+        if index >= handles.len() {
+            return Err(PdfiumError::PageObjectIndexOutOfBounds);
+        }
+
+        if index == current_index {
+            return Ok(());
+        }
+
+        // Pdfium can only append objects to the end of a page. We remove the moved object
+        // along with every object whose position changes, then append them all again
+        // in their new order.
+
+        let handle = handles.remove(current_index);
+
+        handles.insert(index, handle);
+
+        let handles = &handles[current_index.min(index)..];
+
+        for handle in handles {
+            if !self.bindings.is_true(
+                self.bindings
+                    .FPDFPage_RemoveObject(self.page_handle, *handle),
+            ) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+        }
+
+        for handle in handles {
+            self.bindings
+                .FPDFPage_InsertObject(self.page_handle, *handle);
+        }
+
+        if self.do_regenerate_page_content_after_each_change
+            && !self
+                .bindings
+                .is_true(self.bindings.FPDFPage_GenerateContent(self.page_handle))
+        {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Moves the given [PdfPageObject] to the end of this [PdfPageObjects] collection,
+    /// so that it is painted on top of every other object on the page.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn bring_to_front(&mut self, object: &PdfPageObject<'a>) -> Result<(), PdfiumError> {
+        self.move_object_to_index(object, self.len().saturating_sub(1))
+    }
+
+    /// Moves the given [PdfPageObject] to the start of this [PdfPageObjects] collection,
+    /// so that every other object on the page is painted on top of it.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn send_to_back(&mut self, object: &PdfPageObject<'a>) -> Result<(), PdfiumError> {
+        self.move_object_to_index(object, 0)
+    }
+
+    /// Removes every [PdfPageObject] in this [PdfPageObjects] collection for which the given
+    /// predicate returns `false`, returning the number of objects removed.
+    ///
+    /// Objects are passed to the predicate in reverse order, from the highest index downward,
+    /// so that removing an object never changes the index of an object that has not yet been
+    /// visited. The memory owned by each removed object is freed as soon as it is removed.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page once, after all objects have been removed.
+    pub fn retain<F>(&mut self, mut predicate: F) -> Result<PdfPageObjectIndex, PdfiumError>
+    where
+        F: FnMut(&PdfPageObject<'a>) -> bool,
+    {
+        let mut removed = 0;
+
+        for index in (0..self.len()).rev() {
+            let mut object = self.get(index)?;
+
+            if !predicate(&object) {
+                // Once removed from the page, the object owns its own memory,
+                // which will be released when the object is dropped.
+
+                object.remove_object_from_page()?;
+
+                removed += 1;
+            }
+        }
+
+        if removed > 0
+            && self.do_regenerate_page_content_after_each_change
+            && !self
+                .bindings
+                .is_true(self.bindings.FPDFPage_GenerateContent(self.page_handle))
+        {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        Ok(removed)
+    }
+
+    /// Removes every [PdfPageObject] of the given [PdfPageObjectType] from this
+    /// [PdfPageObjects] collection, returning the number of objects removed.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page once, after all objects have been removed.
+    #[inline]
+    pub fn remove_all_of_type(
+        &mut self,
+        object_type: PdfPageObjectType,
+    ) -> Result<PdfPageObjectIndex, PdfiumError> {
+        self.retain(|object| object.object_type() != object_type)
+    }
 }
 
 impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
@@ -178,3 +384,183 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
         })
     }
 }
+
+/// A depth-first iterator over all the [PdfPageObject] objects in a [PdfPageObjects] collection
+/// and in any form objects it contains, yielding each object together with the accumulated
+/// transformation matrix of its containing form objects.
+pub struct PdfPageObjectsDescendantsIterator<'a> {
+    page_handle: FPDF_PAGE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+
+    // The containers currently being visited, innermost last. Each entry holds the form object
+    // being visited (or None for the page itself), the index of the next child to visit,
+    // and the matrix converting the container's coordinate space into page coordinates.
+    stack: Vec<(Option<FPDF_PAGEOBJECT>, usize, PdfMatrix)>,
+}
+
+impl<'a> PdfPageObjectsDescendantsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(page_handle: FPDF_PAGE, bindings: &'a dyn PdfiumLibraryBindings) -> Self {
+        PdfPageObjectsDescendantsIterator {
+            page_handle,
+            bindings,
+            stack: vec![(None, 0, PdfMatrix::IDENTITY)],
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageObjectsDescendantsIterator<'a> {
+    type Item = (PdfPageObject<'a>, PdfMatrix);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (container, index, matrix) = self.stack.last_mut()?;
+
+            let (container, child_index, matrix) = (*container, *index, *matrix);
+
+            *index += 1;
+
+            let (len, handle) = match container {
+                None => (
+                    self.bindings.FPDFPage_CountObjects(self.page_handle) as usize,
+                    self.bindings
+                        .FPDFPage_GetObject(self.page_handle, child_index as c_int),
+                ),
+                Some(form) => (
+                    self.bindings.FPDFFormObj_CountObjects(form) as usize,
+                    self.bindings
+                        .FPDFFormObj_GetObject(form, child_index as c_ulong),
+                ),
+            };
+
+            if child_index >= len {
+                // All the children of this container have been visited.
+
+                self.stack.pop();
+
+                continue;
+            }
+
+            if handle.is_null() {
+                continue;
+            }
+
+            let object =
+                PdfPageObject::from_pdfium(handle, Some(self.page_handle), None, self.bindings);
+
+            if let PdfPageObject::XObjectForm(_) = &object {
+                let is_already_visited = self
+                    .stack
+                    .iter()
+                    .any(|(container, _, _)| *container == Some(handle));
+
+                if !is_already_visited {
+                    if let Ok(form_matrix) = object.matrix() {
+                        self.stack
+                            .push((Some(handle), 0, form_matrix.multiply(matrix)));
+                    }
+                }
+            }
+
+            return Some((object, matrix));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use image::{DynamicImage, RgbImage};
+
+    #[test]
+    fn test_remove_all_image_objects() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, image::Rgb([0, 0, 255])));
+
+        page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &image,
+            Some(PdfPoints::new(200.0)),
+            Some(PdfPoints::new(200.0)),
+        )?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(50.0, 50.0, 150.0, 150.0),
+            None,
+            None,
+            Some(PdfColor::new(255, 0, 0, 255)),
+        )?;
+
+        page.objects_mut().create_image_object(
+            PdfPoints::new(90.0),
+            PdfPoints::new(90.0),
+            &image,
+            Some(PdfPoints::new(20.0)),
+            Some(PdfPoints::new(20.0)),
+        )?;
+
+        // Returns the RGB color of the pixel at the given position in the rendered page.
+
+        let pixel = |page: &PdfPage, x: u32, y: u32| -> Result<Vec<u8>, PdfiumError> {
+            let bitmap =
+                page.render_with_config(&PdfRenderConfig::new().set_target_size(200, 200))?;
+
+            let offset = ((y * bitmap.width() as u32 + x) * 4) as usize;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 3].to_vec())
+        };
+
+        assert_eq!(pixel(&page, 100, 100)?, vec![0, 0, 255]);
+        assert_eq!(pixel(&page, 10, 10)?, vec![0, 0, 255]);
+
+        // Removing both images should leave only the rectangle on an otherwise empty page.
+
+        assert_eq!(
+            page.objects_mut()
+                .remove_all_of_type(PdfPageObjectType::Image)?,
+            2
+        );
+        assert_eq!(page.objects().len(), 1);
+        assert_eq!(
+            page.objects().first()?.object_type(),
+            PdfPageObjectType::Path
+        );
+        assert_eq!(pixel(&page, 100, 100)?, vec![255, 0, 0]);
+        assert_eq!(pixel(&page, 10, 10)?, vec![255, 255, 255]);
+
+        // Removing objects of a type not present on the page should do nothing.
+
+        assert_eq!(
+            page.objects_mut()
+                .remove_all_of_type(PdfPageObjectType::Text)?,
+            0
+        );
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let mut page = document.pages().first()?;
+
+        assert_eq!(page.objects().len(), 1);
+
+        // Retaining nothing should empty the page.
+
+        assert_eq!(page.objects_mut().retain(|_| false)?, 1);
+        assert!(page.objects().is_empty());
+
+        Ok(())
+    }
+}
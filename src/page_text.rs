@@ -14,6 +14,8 @@ use crate::page_objects_common::PdfPageObjectsCommon;
 use crate::page_text_chars::{PdfPageTextCharIndex, PdfPageTextChars};
 use crate::page_text_search::{PdfPageTextSearch, PdfSearchOptions};
 use crate::page_text_segments::PdfPageTextSegments;
+use crate::page_text_struct_element::PdfStructElementText;
+use crate::page_text_styled_chars::PdfPageTextStyledCharsIterator;
 use crate::points::PdfPoints;
 use crate::rect::PdfRect;
 use crate::utils::mem::{create_byte_buffer, create_sized_buffer};
@@ -21,6 +23,7 @@ use crate::utils::utf16le::{
     get_pdfium_utf16le_bytes_from_str, get_string_from_pdfium_utf16le_bytes,
 };
 use bytemuck::cast_slice;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::os::raw::{c_double, c_int};
 use std::ptr::null_mut;
@@ -107,6 +110,14 @@ impl<'a> PdfPageText<'a> {
         PdfPageTextChars::new(self.handle, 0, self.len(), self.bindings)
     }
 
+    /// Returns an iterator over all the characters in the containing [PdfPage], yielding
+    /// a `PdfPageTextStyledChar` for each character that captures its Unicode value, bounds,
+    /// font name, font size, and fill and stroke colors.
+    #[inline]
+    pub fn chars_with_style(&self) -> PdfPageTextStyledCharsIterator {
+        PdfPageTextStyledCharsIterator::new(self)
+    }
+
     /// Returns a collection of all the `PdfPageTextChar` characters in the given [PdfPageTextObject].
     ///
     /// The return result will be empty if the given [PdfPageTextObject] is not attached to the
@@ -388,6 +399,47 @@ impl<'a> PdfPageText<'a> {
         Ok(self.inside_rect(bounds))
     }
 
+    /// Returns the text of the containing [PdfPage] grouped by the elements of the page's
+    /// tagged structure tree, in logical reading order.
+    ///
+    /// The order in which characters are defined in the document need not match the order
+    /// in which they should be read; in multi-column layouts, the two orders often differ.
+    /// The structure tree of a tagged PDF records the intended reading order, along with the
+    /// structural role of each element, such as `H1`, `P`, or `Table`.
+    ///
+    /// Returns [PdfiumError::NoStructureTree] if the containing [PdfPage] has no
+    /// structure tree, as is the case for untagged documents.
+    pub fn structured_text(&self) -> Result<Vec<PdfStructElementText>, PdfiumError> {
+        let tree = self.page.structure_tree()?;
+
+        let marked_content_text = self.marked_content_text();
+
+        Ok(tree
+            .iter()
+            .map(|element| PdfStructElementText::from_element(&element, &marked_content_text))
+            .collect())
+    }
+
+    /// Returns the text of all text objects in the containing [PdfPage] that carry a marked
+    /// content ID, keyed by marked content ID. Text objects sharing the same marked content ID
+    /// are concatenated in the order in which they are defined in the document.
+    fn marked_content_text(&self) -> HashMap<i32, String> {
+        let mut result: HashMap<i32, String> = HashMap::new();
+
+        for object in self.page.objects().iter() {
+            if let Some(object) = object.as_text_object() {
+                if let Some(id) = object.marked_content_id() {
+                    result
+                        .entry(id)
+                        .or_default()
+                        .push_str(self.for_object(object).as_str());
+                }
+            }
+        }
+
+        result
+    }
+
     /// Starts a search for the given text string, returning a new [PdfPageTextSearch]
     /// object that can be used to step through the search results.
     #[inline]
@@ -496,6 +548,307 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_chars_with_style() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().courier();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Hi",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        object.set_fill_color(PdfColor::new(255, 0, 0, 128))?;
+        object.set_stroke_color(PdfColor::new(0, 0, 255, 255))?;
+
+        let text = page.text()?;
+
+        let chars = text.chars_with_style().collect::<Vec<_>>();
+
+        assert_eq!(chars.len(), 2);
+        assert_eq!(chars[0].unicode_char(), Some('H'));
+        assert_eq!(chars[1].unicode_char(), Some('i'));
+
+        for char in chars.iter() {
+            assert!(char.font_name().contains("Courier"));
+            assert_eq!(char.font_size(), PdfPoints::new(12.0));
+            assert!(char.bounds().is_some());
+
+            let fill = char.fill_color().unwrap();
+
+            assert_eq!(
+                (fill.red(), fill.green(), fill.blue(), fill.alpha()),
+                (255, 0, 0, 128)
+            );
+
+            let stroke = char.stroke_color().unwrap();
+
+            assert_eq!(
+                (stroke.red(), stroke.green(), stroke.blue(), stroke.alpha()),
+                (0, 0, 255, 255)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_rotation_and_orientation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().courier();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "A",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut()
+            .create_text_object(
+                PdfPoints::ZERO,
+                PdfPoints::ZERO,
+                "B",
+                font,
+                PdfPoints::new(12.0),
+            )?
+            .rotate_counter_clockwise_degrees(90.0)?;
+
+        page.objects_mut()
+            .create_text_object(
+                PdfPoints::ZERO,
+                PdfPoints::ZERO,
+                "C",
+                font,
+                PdfPoints::new(12.0),
+            )?
+            .flip_horizontally()?;
+
+        let text = page.text()?;
+
+        let chars = text.chars();
+
+        let find = |target: char| {
+            chars
+                .iter()
+                .find(|char| char.unicode_char() == Some(target))
+                .unwrap()
+        };
+
+        let a = find('A');
+
+        assert!(a.rotation_degrees()?.abs() < 0.01);
+        assert_eq!(a.orientation()?, PdfPageTextCharOrientation::Normal);
+
+        let b = find('B');
+
+        assert!((b.rotation_degrees()? - 90.0).abs() < 0.01);
+        assert_eq!(b.orientation()?, PdfPageTextCharOrientation::Normal);
+
+        let c = find('C');
+
+        assert!(c.matrix()?.determinant() < 0.0);
+        assert!((c.rotation_degrees()? - 180.0).abs() < 0.01);
+        assert_eq!(c.orientation()?, PdfPageTextCharOrientation::Mirrored);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_structured_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The content stream of this page defines the second column of text before
+        // the title and the first column; the structure tree defines the reading order.
+
+        let document = pdfium.load_pdf_from_file("./test/structure-tree-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let text = page.text()?;
+
+        let elements = text.structured_text()?;
+
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements[0].role(), "Document");
+
+        let children = elements[0].children();
+
+        assert_eq!(
+            children
+                .iter()
+                .map(|element| element.role())
+                .collect::<Vec<_>>(),
+            vec!["H1", "P", "P"]
+        );
+        assert_eq!(
+            children
+                .iter()
+                .map(|element| element.text().trim())
+                .collect::<Vec<_>>(),
+            vec!["Title", "First column", "Second column"]
+        );
+        assert_eq!(children[2].marked_content_id(), Some(0));
+
+        // Untagged documents have no structure tree.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert!(matches!(
+            page.text()?.structured_text(),
+            Err(PdfiumError::NoStructureTree)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_render_mode() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Hidden",
+            font,
+            PdfPoints::new(48.0),
+        )?;
+
+        let text = object.as_text_object_mut().unwrap();
+
+        assert_eq!(text.render_mode(), PdfPageTextRenderMode::FilledUnstroked);
+
+        let count_pixels = |page: &PdfPage, predicate: fn(&[u8]) -> bool| {
+            page.render_with_config(&PdfRenderConfig::new().set_target_width(595))
+                .map(|bitmap| {
+                    bitmap
+                        .as_rgba_bytes()
+                        .chunks_exact(4)
+                        .filter(|pixel| predicate(pixel))
+                        .count()
+                })
+        };
+
+        let is_dark: fn(&[u8]) -> bool = |pixel| pixel[0] < 128 && pixel[1] < 128;
+        let is_red: fn(&[u8]) -> bool = |pixel| pixel[0] > 200 && pixel[1] < 64;
+
+        assert!(count_pixels(&page, is_dark)? > 0);
+
+        // Stroked text is outlined using the stroke color of the text object.
+
+        text.set_stroke_color(PdfColor::RED)?;
+        text.set_stroke_width(PdfPoints::new(2.0))?;
+        text.set_render_mode(PdfPageTextRenderMode::StrokedUnfilled)?;
+
+        assert_eq!(text.render_mode(), PdfPageTextRenderMode::StrokedUnfilled);
+        assert!(count_pixels(&page, is_red)? > 0);
+
+        // Invisible text is not rendered, but remains extractable.
+
+        text.set_render_mode(PdfPageTextRenderMode::Invisible)?;
+
+        assert_eq!(text.render_mode(), PdfPageTextRenderMode::Invisible);
+        assert_eq!(count_pixels(&page, is_dark)?, 0);
+        assert_eq!(count_pixels(&page, is_red)?, 0);
+        assert_eq!(page.text()?.all().trim(), "Hidden");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars_for_object_geometry() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Größe: 5 €",
+            font,
+            PdfPoints::new(20.0),
+        )?;
+
+        object.scale(1.5, 1.0)?;
+
+        let page_text = page.text()?;
+
+        let text = object.as_text_object().unwrap();
+
+        let chars = text.chars(&page_text)?;
+
+        // The concatenated characters match the text of the object, including
+        // characters outside the ASCII range.
+
+        assert_eq!(
+            chars
+                .iter()
+                .filter_map(|char| char.unicode_char())
+                .collect::<String>(),
+            text.text()
+        );
+
+        // Each character's origin lies within its loose bounds, and the origin of each
+        // character is one advance further along the baseline than the previous one.
+
+        let mut expected_x: Option<f32> = None;
+
+        for char in chars.iter() {
+            let (x, y) = char.origin()?;
+
+            let bounds = char.loose_bounds()?;
+
+            if let Some(expected_x) = expected_x {
+                assert!((x.value - expected_x).abs() < 0.5);
+            }
+
+            assert!(y >= bounds.bottom && y <= bounds.top);
+            assert!(text.char_advance(&char).value > 0.0);
+
+            expected_x = Some(x.value + text.char_advance(&char).value);
+        }
+
+        assert!(expected_x.is_some());
+
+        Ok(())
+    }
+
     fn test_one_overlapping_text_object_results(
         object: &PdfPageObject,
         page_text: &PdfPageText,
@@ -6,6 +6,7 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::color::PdfColor;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::font::{FpdfFontDescriptorFlags, PdfFontWeight};
+use crate::matrix::PdfMatrix;
 use crate::page_object_text::PdfPageTextRenderMode;
 use crate::page_text_chars::PdfPageTextCharIndex;
 use crate::points::PdfPoints;
@@ -14,6 +15,17 @@ use crate::utils::mem::create_byte_buffer;
 use std::convert::TryInto;
 use std::ffi::c_void;
 
+/// The orientation of a single [PdfPageTextChar], as determined by its transformation matrix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfPageTextCharOrientation {
+    /// The character's glyph is drawn the right way round, possibly rotated.
+    Normal,
+
+    /// The character's glyph is drawn as a mirror image, possibly rotated. Mirrored characters
+    /// have a transformation matrix with a negative determinant.
+    Mirrored,
+}
+
 /// A single character in a `PdfPageTextChars` collection.
 pub struct PdfPageTextChar<'a> {
     text_page_handle: FPDF_TEXTPAGE,
@@ -437,8 +449,8 @@ impl<'a> PdfPageTextChar<'a> {
         PdfRect::from_pdfium_as_result(result, bounds, self.bindings)
     }
 
-    /// Returns the current raw transformation matrix for this character.
-    fn matrix(&self) -> Result<FS_MATRIX, PdfiumError> {
+    /// Returns the transformation matrix currently applied to this character.
+    pub fn matrix(&self) -> Result<PdfMatrix, PdfiumError> {
         let mut matrix = FS_MATRIX {
             a: 0.0,
             b: 0.0,
@@ -453,7 +465,7 @@ impl<'a> PdfPageTextChar<'a> {
             self.index,
             &mut matrix,
         )) {
-            Ok(matrix)
+            Ok(PdfMatrix::from_pdfium(matrix))
         } else {
             Err(PdfiumError::PdfiumLibraryInternalError(
                 PdfiumInternalError::Unknown,
@@ -461,6 +473,34 @@ impl<'a> PdfPageTextChar<'a> {
         }
     }
 
+    /// Returns the counter-clockwise rotation of this character's baseline, in degrees,
+    /// normalized to the range `0.0..360.0`.
+    ///
+    /// Characters in a 90 degree rotated caption report `90.0`; characters drawn upside down
+    /// report `180.0`. The rotation of a mirrored character describes the direction of its
+    /// baseline only; use the [PdfPageTextChar::orientation()] function to detect mirroring.
+    pub fn rotation_degrees(&self) -> Result<f32, PdfiumError> {
+        let matrix = self.matrix()?;
+
+        let degrees = matrix.b().atan2(matrix.a()).to_degrees();
+
+        Ok(if degrees < 0.0 {
+            degrees + 360.0
+        } else {
+            degrees
+        })
+    }
+
+    /// Returns the [PdfPageTextCharOrientation] of this character, indicating whether
+    /// the character's glyph is drawn as a mirror image.
+    pub fn orientation(&self) -> Result<PdfPageTextCharOrientation, PdfiumError> {
+        Ok(if self.matrix()?.determinant() < 0.0 {
+            PdfPageTextCharOrientation::Mirrored
+        } else {
+            PdfPageTextCharOrientation::Normal
+        })
+    }
+
     /// Returns the current horizontal and vertical translation of the origin of this character.
     #[inline]
     pub fn get_translation(&self) -> (PdfPoints, PdfPoints) {
@@ -474,7 +514,7 @@ impl<'a> PdfPageTextChar<'a> {
     #[inline]
     pub fn get_horizontal_translation(&self) -> PdfPoints {
         self.matrix()
-            .map(|matrix| PdfPoints::new(matrix.e))
+            .map(|matrix| PdfPoints::new(matrix.e()))
             .unwrap_or(PdfPoints::ZERO)
     }
 
@@ -482,7 +522,7 @@ impl<'a> PdfPageTextChar<'a> {
     #[inline]
     pub fn get_vertical_translation(&self) -> PdfPoints {
         self.matrix()
-            .map(|matrix| PdfPoints::new(matrix.f))
+            .map(|matrix| PdfPoints::new(matrix.f()))
             .unwrap_or(PdfPoints::ZERO)
     }
 
@@ -495,13 +535,13 @@ impl<'a> PdfPageTextChar<'a> {
     /// Returns the current horizontal scale factor applied to this character.
     #[inline]
     pub fn get_horizontal_scale(&self) -> f64 {
-        self.matrix().map(|matrix| matrix.a).unwrap_or(0.0) as f64
+        self.matrix().map(|matrix| matrix.a()).unwrap_or(0.0) as f64
     }
 
     /// Returns the current vertical scale factor applied to this character.
     #[inline]
     pub fn get_vertical_scale(&self) -> f64 {
-        self.matrix().map(|matrix| matrix.d).unwrap_or(0.0) as f64
+        self.matrix().map(|matrix| matrix.d()).unwrap_or(0.0) as f64
     }
 
     /// Returns the counter-clockwise rotation applied to this character, in degrees.
@@ -529,7 +569,7 @@ impl<'a> PdfPageTextChar<'a> {
     #[inline]
     pub fn get_rotation_counter_clockwise_radians(&self) -> f32 {
         self.matrix()
-            .map(|matrix| matrix.b.atan2(matrix.a))
+            .map(|matrix| matrix.b().atan2(matrix.a()))
             .unwrap_or(0.0)
     }
 
@@ -590,7 +630,7 @@ impl<'a> PdfPageTextChar<'a> {
     /// the combined operation.
     #[inline]
     pub fn get_x_axis_skew_radians(&self) -> f32 {
-        self.matrix().map(|matrix| matrix.b.atan()).unwrap_or(0.0)
+        self.matrix().map(|matrix| matrix.b().atan()).unwrap_or(0.0)
     }
 
     /// Returns the current y axis skew applied to this character, in radians.
@@ -599,7 +639,7 @@ impl<'a> PdfPageTextChar<'a> {
     /// the combined operation.
     #[inline]
     pub fn get_y_axis_skew_radians(&self) -> f32 {
-        self.matrix().map(|matrix| matrix.c.atan()).unwrap_or(0.0)
+        self.matrix().map(|matrix| matrix.c().atan()).unwrap_or(0.0)
     }
 
     /// Returns the origin x and y positions of this character relative to its containing page.
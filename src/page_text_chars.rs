@@ -10,6 +10,8 @@ use crate::page_text::PdfPageText;
 use crate::page_text_char::PdfPageTextChar;
 use crate::pages::PdfPageIndex;
 use crate::points::PdfPoints;
+use crate::quad_points::PdfQuadPoints;
+use crate::rect::PdfRect;
 use std::ops::Range;
 use std::os::raw::c_int;
 
@@ -153,6 +155,85 @@ impl<'a> PdfPageTextChars<'a> {
         .ok()
     }
 
+    /// Returns one [PdfQuadPoints] for each line of text spanned by this [PdfPageTextChars]
+    /// collection. Each quad encloses the loose bounds of the characters on its line,
+    /// rotated to match the angle of the line's first character, so that quads follow
+    /// rotated text rather than enclosing it in an oversized axis-aligned box.
+    ///
+    /// Characters that Pdfium cannot measure, such as the line breaks Pdfium generates
+    /// between lines of text, are skipped.
+    pub(crate) fn quad_points_by_line(&self) -> Vec<PdfQuadPoints> {
+        // The distance of a rectangle's center from the origin, measured perpendicular
+        // to a line of text running at the given angle.
+
+        fn offset_across_line(rect: &PdfRect, angle: f32) -> f32 {
+            let (sin, cos) = angle.sin_cos();
+
+            let x = (rect.left.value + rect.right.value) / 2.0;
+            let y = (rect.bottom.value + rect.top.value) / 2.0;
+
+            y * cos - x * sin
+        }
+
+        let mut result = Vec::new();
+
+        let mut rects = Vec::new();
+
+        // The angle, perpendicular offset, and perpendicular tolerance of the current line.
+
+        let mut line: Option<(f32, f32, f32)> = None;
+
+        for char in self.iter() {
+            let bounds = match char.loose_bounds() {
+                Ok(bounds) if bounds.width().value > 0.0 && bounds.height().value > 0.0 => bounds,
+                _ => continue,
+            };
+
+            let angle = char.angle_radians().unwrap_or(0.0);
+
+            let is_new_line = match char.unicode_char() {
+                Some('\r') | Some('\n') => continue,
+                _ => match line {
+                    Some((line_angle, line_offset, tolerance)) => {
+                        (angle - line_angle).abs() > 0.01
+                            || (offset_across_line(&bounds, line_angle) - line_offset).abs()
+                                > tolerance
+                    }
+                    None => true,
+                },
+            };
+
+            if is_new_line {
+                if let Some((line_angle, _, _)) = line {
+                    result.extend(PdfQuadPoints::from_rects_at_angle(
+                        rects.as_slice(),
+                        line_angle,
+                    ));
+                }
+
+                rects.clear();
+
+                let (sin, cos) = angle.sin_cos();
+
+                let tolerance =
+                    (bounds.height().value * cos.abs() + bounds.width().value * sin.abs()) / 2.0;
+
+                line = Some((angle, offset_across_line(&bounds, angle), tolerance));
+            }
+
+            rects.push(bounds);
+        }
+
+        if let Some((line_angle, _, _)) = line {
+            result.extend(PdfQuadPoints::from_rects_at_angle(
+                rects.as_slice(),
+                line_angle,
+            ));
+        }
+
+        result
+    }
+
     /// Returns an iterator over all the characters in this [PdfPageTextChars] collection.
     #[inline]
     pub fn iter(&self) -> PdfPageTextCharsIterator {
@@ -5,6 +5,7 @@ use crate::bindgen::FS_RECTF;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::page_text::PdfPageText;
+use crate::page_text_chars::PdfPageTextCharIndex;
 use crate::page_text_segment::PdfPageTextSegment;
 use crate::rect::PdfRect;
 use std::ops::{Range, RangeInclusive};
@@ -83,6 +84,15 @@ impl<'a> PdfPageTextSegments<'a> {
         }
     }
 
+    /// Returns the range of indices of the characters in the containing [PdfPage] spanned by
+    /// this [PdfPageTextSegments] collection. For a text search result, this is the range of
+    /// characters that matched the search term.
+    #[inline]
+    pub fn char_range(&self) -> Range<PdfPageTextCharIndex> {
+        (self.start as PdfPageTextCharIndex)
+            ..((self.start + self.characters) as PdfPageTextCharIndex)
+    }
+
     /// Returns a single [PdfPageTextSegment] from this [PdfPageTextSegments] collection.
     #[inline]
     pub fn get(&self, index: PdfPageTextSegmentIndex) -> Result<PdfPageTextSegment, PdfiumError> {
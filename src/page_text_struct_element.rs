@@ -0,0 +1,98 @@
+//! Defines the [PdfStructElementText] struct, exposing the text content of a single element
+//! in the tagged structure tree of a `PdfPage`.
+
+use crate::struct_element::PdfStructElement;
+use std::collections::HashMap;
+
+#[cfg(doc)]
+use crate::page_text::PdfPageText;
+
+/// The text content of a single element in the tagged structure tree of a `PdfPage`,
+/// together with the element's structural role and the text of its child elements.
+///
+/// Elements are returned by [PdfPageText::structured_text()] in logical reading order,
+/// as defined by the document's structure tree.
+#[derive(Debug, Clone)]
+pub struct PdfStructElementText {
+    role: String,
+    alt_text: Option<String>,
+    marked_content_id: Option<i32>,
+    text: String,
+    children: Vec<PdfStructElementText>,
+}
+
+impl PdfStructElementText {
+    /// Creates a new [PdfStructElementText] from the given [PdfStructElement],
+    /// recursively loading all child elements. The text content of each element is taken
+    /// from the given map of marked content IDs to the text of the page objects they mark.
+    pub(crate) fn from_element(
+        element: &PdfStructElement,
+        marked_content_text: &HashMap<i32, String>,
+    ) -> Self {
+        let marked_content_id = element.marked_content_id();
+
+        PdfStructElementText {
+            role: element.element_type().unwrap_or_default(),
+            alt_text: element.alt_text(),
+            marked_content_id,
+            text: marked_content_id
+                .and_then(|id| marked_content_text.get(&id))
+                .cloned()
+                .unwrap_or_default(),
+            children: element
+                .children()
+                .map(|child| Self::from_element(&child, marked_content_text))
+                .collect(),
+        }
+    }
+
+    /// Returns the structural role of this element, such as `H1`, `P`, or `Table`.
+    ///
+    /// Documents may define custom roles; these are returned as-is, without being mapped
+    /// to a standard role.
+    #[inline]
+    pub fn role(&self) -> &str {
+        self.role.as_str()
+    }
+
+    /// Returns the alternate description of this element, if any. Alternate descriptions
+    /// are typically set on figures and formulae.
+    #[inline]
+    pub fn alt_text(&self) -> Option<&str> {
+        self.alt_text.as_deref()
+    }
+
+    /// Returns the marked content ID linking this element to content on the page, if any.
+    #[inline]
+    pub fn marked_content_id(&self) -> Option<i32> {
+        self.marked_content_id
+    }
+
+    /// Returns the text content belonging directly to this element, excluding the text
+    /// of its child elements.
+    ///
+    /// Pdfium only exposes a single marked content ID per element, so content linked to
+    /// an element through several marked content references is not included.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the text content of this element and all its child elements,
+    /// concatenated in reading order.
+    pub fn all_text(&self) -> String {
+        let mut result = self.text.clone();
+
+        for child in self.children.iter() {
+            result.push_str(child.all_text().as_str());
+        }
+
+        result
+    }
+
+    /// Returns the child elements of this element, in reading order.
+    #[inline]
+    pub fn children(&self) -> &[PdfStructElementText] {
+        self.children.as_slice()
+    }
+}
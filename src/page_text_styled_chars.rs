@@ -0,0 +1,135 @@
+//! Defines the [PdfPageTextStyledChar] struct, a snapshot of a single character in a
+//! [PdfPageText] collection together with the styling applied to it, and the
+//! [PdfPageTextStyledCharsIterator] used to iterate over them.
+
+use crate::color::PdfColor;
+use crate::page_text::PdfPageText;
+use crate::page_text_char::PdfPageTextChar;
+use crate::page_text_chars::PdfPageTextCharIndex;
+use crate::points::PdfPoints;
+use crate::rect::PdfRect;
+
+/// A single character in a [PdfPageText] collection, together with the font and color
+/// styling applied to it.
+///
+/// Unlike a [PdfPageTextChar], a [PdfPageTextStyledChar] holds copies of all its values,
+/// so it can be retained after the [PdfPageText] that created it has been dropped.
+#[derive(Debug, Clone)]
+pub struct PdfPageTextStyledChar {
+    index: PdfPageTextCharIndex,
+    unicode_value: u32,
+    bounds: Option<PdfRect>,
+    font_name: String,
+    font_size: PdfPoints,
+    fill_color: Option<PdfColor>,
+    stroke_color: Option<PdfColor>,
+}
+
+impl PdfPageTextStyledChar {
+    pub(crate) fn from_char(char: &PdfPageTextChar) -> Self {
+        PdfPageTextStyledChar {
+            index: char.index(),
+            unicode_value: char.unicode_value(),
+            bounds: char.loose_bounds().ok(),
+            font_name: char.font_name(),
+            font_size: char.scaled_font_size(),
+            fill_color: char.fill_color().ok(),
+            stroke_color: char.stroke_color().ok(),
+        }
+    }
+
+    /// Returns the index of this character in the containing [PdfPageText] collection.
+    #[inline]
+    pub fn index(&self) -> PdfPageTextCharIndex {
+        self.index
+    }
+
+    /// Returns the raw Unicode literal value for this character.
+    #[inline]
+    pub fn unicode_value(&self) -> u32 {
+        self.unicode_value
+    }
+
+    /// Returns Rust's Unicode `char` representation for this character, if available.
+    #[inline]
+    pub fn unicode_char(&self) -> Option<char> {
+        char::from_u32(self.unicode_value)
+    }
+
+    /// Returns a loose bounding box for this character, covering the entire glyph bounds.
+    ///
+    /// Returns `None` if Pdfium could not determine the character's bounds.
+    #[inline]
+    pub fn bounds(&self) -> Option<PdfRect> {
+        self.bounds
+    }
+
+    /// Returns the name of the font applied to this character.
+    #[inline]
+    pub fn font_name(&self) -> &str {
+        self.font_name.as_str()
+    }
+
+    /// Returns the effective size of this character when rendered, taking into account both the
+    /// font size applied to the character as well as any vertical scale factor applied
+    /// to the character's transformation matrix.
+    #[inline]
+    pub fn font_size(&self) -> PdfPoints {
+        self.font_size
+    }
+
+    /// Returns the fill color applied to this character, including its alpha value.
+    ///
+    /// Returns `None` for characters with no associated text object, such as the
+    /// whitespace and line breaks Pdfium generates between text objects.
+    #[inline]
+    pub fn fill_color(&self) -> Option<PdfColor> {
+        self.fill_color
+    }
+
+    /// Returns the stroke color applied to this character, including its alpha value.
+    ///
+    /// Returns `None` for characters with no associated text object, such as the
+    /// whitespace and line breaks Pdfium generates between text objects.
+    #[inline]
+    pub fn stroke_color(&self) -> Option<PdfColor> {
+        self.stroke_color
+    }
+}
+
+/// An iterator over all the characters in a [PdfPageText] collection, yielding a
+/// [PdfPageTextStyledChar] for each character.
+pub struct PdfPageTextStyledCharsIterator<'a> {
+    text: &'a PdfPageText<'a>,
+    next_index: i32,
+}
+
+impl<'a> PdfPageTextStyledCharsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(text: &'a PdfPageText<'a>) -> Self {
+        PdfPageTextStyledCharsIterator {
+            text,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageTextStyledCharsIterator<'a> {
+    type Item = PdfPageTextStyledChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.text.len() {
+            return None;
+        }
+
+        let char = PdfPageTextChar::from_pdfium(
+            *self.text.handle(),
+            self.next_index,
+            self.text.bindings(),
+        );
+
+        self.next_index += 1;
+
+        Some(PdfPageTextStyledChar::from_char(&char))
+    }
+}
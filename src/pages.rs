@@ -2,11 +2,12 @@
 //! `PdfDocument`.
 
 use crate::bindgen::{
-    size_t, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE, PAGEMODE_FULLSCREEN, PAGEMODE_UNKNOWN,
-    PAGEMODE_USEATTACHMENTS, PAGEMODE_USENONE, PAGEMODE_USEOC, PAGEMODE_USEOUTLINES,
-    PAGEMODE_USETHUMBS,
+    size_t, FPDF_AVAIL, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE, FX_DOWNLOADHINTS,
+    PAGEMODE_FULLSCREEN, PAGEMODE_UNKNOWN, PAGEMODE_USEATTACHMENTS, PAGEMODE_USENONE,
+    PAGEMODE_USEOC, PAGEMODE_USEOUTLINES, PAGEMODE_USETHUMBS,
 };
 use crate::bindings::PdfiumLibraryBindings;
+use crate::bitmap::PdfBitmap;
 use crate::document::PdfDocument;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::page::{PdfPage, PdfPageContentRegenerationStrategy};
@@ -14,11 +15,15 @@ use crate::page_index_cache::PdfPageIndexCache;
 use crate::page_object_group::PdfPageGroupObject;
 use crate::page_size::PdfPagePaperSize;
 use crate::points::PdfPoints;
+use crate::render_config::PdfRenderConfig;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_double, c_int, c_void};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::bindgen::{PDF_DATA_AVAIL, PDF_DATA_NOTAVAIL};
+
 /// The zero-based index of a single [PdfPage] inside its containing [PdfPages] collection.
 pub type PdfPageIndex = u16;
 
@@ -75,6 +80,10 @@ pub struct PdfPages<'a> {
     document_handle: FPDF_DOCUMENT,
     form_handle: Option<FPDF_FORMHANDLE>,
     bindings: &'a dyn PdfiumLibraryBindings,
+
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This field is never used when compiling to WASM.
+    data_availability: Option<(FPDF_AVAIL, *mut FX_DOWNLOADHINTS)>,
 }
 
 impl<'a> PdfPages<'a> {
@@ -88,9 +97,24 @@ impl<'a> PdfPages<'a> {
             document_handle,
             form_handle,
             bindings,
+            data_availability: None,
         }
     }
 
+    /// Sets the `FPDF_AVAIL` availability provider and `FX_DOWNLOADHINTS` hints receiver
+    /// used to check whether the data for a page has arrived before loading it, when the
+    /// containing document is loaded progressively.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This function is never used when compiling to WASM.
+    #[inline]
+    pub(crate) fn set_data_availability(
+        &mut self,
+        avail: FPDF_AVAIL,
+        hints: *mut FX_DOWNLOADHINTS,
+    ) {
+        self.data_availability = Some((avail, hints));
+    }
+
     /// Returns the [PdfiumLibraryBindings] used by this [PdfPages] collection.
     #[inline]
     pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
@@ -125,11 +149,33 @@ impl<'a> PdfPages<'a> {
     }
 
     /// Returns a single [PdfPage] from this [PdfPages] collection.
+    ///
+    /// If the containing document was loaded progressively using `Pdfium::load_pdf_from_avail()`
+    /// and the data for the page has not yet arrived, [PdfiumError::PageDataNotYetAvailable]
+    /// is returned. The byte ranges needed to load the page are reported to the document's
+    /// `PdfDataAvailability` source; the page can be retrieved once that data has arrived.
     pub fn get(&self, index: PdfPageIndex) -> Result<PdfPage<'a>, PdfiumError> {
         if index >= self.len() {
             return Err(PdfiumError::PageIndexOutOfBounds);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some((avail, hints)) = self.data_availability {
+                let status = self
+                    .bindings
+                    .FPDFAvail_IsPageAvail(avail, index as c_int, hints);
+
+                if status == PDF_DATA_NOTAVAIL as c_int {
+                    return Err(PdfiumError::PageDataNotYetAvailable);
+                } else if status != PDF_DATA_AVAIL as c_int {
+                    return Err(PdfiumError::PdfiumLibraryInternalError(
+                        PdfiumInternalError::Unknown,
+                    ));
+                }
+            }
+        }
+
         let page_handle = self
             .bindings
             .FPDF_LoadPage(self.document_handle, index as c_int);
@@ -143,6 +189,18 @@ impl<'a> PdfPages<'a> {
         result
     }
 
+    /// Returns the label assigned to the page at the given index in this [PdfPages] collection,
+    /// for instance "iv" for the fourth page of a document's front matter.
+    ///
+    /// Returns `Ok(None)` if the document does not define a label for the page.
+    pub fn page_label(&self, index: PdfPageIndex) -> Result<Option<String>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        Ok(self.get_label(index))
+    }
+
     /// Returns the first [PdfPage] in this [PdfPages] collection.
     #[inline]
     pub fn first(&self) -> Result<PdfPage<'a>, PdfiumError> {
@@ -163,6 +221,17 @@ impl<'a> PdfPages<'a> {
         }
     }
 
+    /// Applies every redaction annotation on every page in this [PdfPages] collection,
+    /// permanently removing all page objects that intersect any region marked for redaction.
+    /// See [PdfPage::apply_redactions()] for details.
+    ///
+    /// Returns the number of page objects removed from each page, indexed by page index.
+    pub fn apply_redactions(&mut self) -> Result<Vec<usize>, PdfiumError> {
+        self.as_range()
+            .map(|index| self.get(index)?.apply_redactions())
+            .collect()
+    }
+
     /// Creates a new, empty [PdfPage] with the given [PdfPagePaperSize] and inserts it
     /// at the start of this [PdfPages] collection, shuffling down all other pages.
     #[inline]
@@ -451,41 +520,7 @@ impl<'a> PdfPages<'a> {
             // (Pdfium does not currently include an FPDF_SetPageLabel() function, so the label
             // _will_ be an immutable property of the PdfPage for its entire lifetime.)
 
-            let label = {
-                // Retrieving the label text from Pdfium is a two-step operation. First, we call
-                // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
-                // the label text in bytes. If the length is zero, then there is no such tag.
-
-                // If the length is non-zero, then we reserve a byte buffer of the given
-                // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
-                // this will write the label text to the buffer in UTF16LE format.
-
-                let buffer_length = self.bindings.FPDF_GetPageLabel(
-                    self.document_handle,
-                    index as c_int,
-                    std::ptr::null_mut(),
-                    0,
-                );
-
-                if buffer_length == 0 {
-                    // The label is not present.
-
-                    None
-                } else {
-                    let mut buffer = create_byte_buffer(buffer_length as usize);
-
-                    let result = self.bindings.FPDF_GetPageLabel(
-                        self.document_handle,
-                        index as c_int,
-                        buffer.as_mut_ptr() as *mut c_void,
-                        buffer_length,
-                    );
-
-                    debug_assert_eq!(result, buffer_length);
-
-                    get_string_from_pdfium_utf16le_bytes(buffer)
-                }
-            };
+            let label = self.get_label(index);
 
             Ok(PdfPage::from_pdfium(
                 self.document_handle,
@@ -497,6 +532,45 @@ impl<'a> PdfPages<'a> {
         }
     }
 
+    /// Returns the label assigned to the page at the given index, if any, without
+    /// checking that the index is in bounds.
+    fn get_label(&self, index: PdfPageIndex) -> Option<String> {
+        // Retrieving the label text from Pdfium is a two-step operation. First, we call
+        // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
+        // the label text in bytes, including the trailing null. If the length is zero,
+        // then there is no such label.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
+        // this will write the label text to the buffer in UTF16LE format.
+
+        let buffer_length = self.bindings.FPDF_GetPageLabel(
+            self.document_handle,
+            index as c_int,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            // The label is not present.
+
+            None
+        } else {
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings.FPDF_GetPageLabel(
+                self.document_handle,
+                index as c_int,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+            );
+
+            debug_assert_eq!(result, buffer_length);
+
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        }
+    }
+
     /// Returns the [PdfPageMode] setting embedded in the containing [PdfDocument].
     pub fn page_mode(&self) -> PdfPageMode {
         PdfPageMode::from_pdfium(self.bindings.FPDFDoc_GetPageMode(self.document_handle))
@@ -571,6 +645,28 @@ impl<'a> PdfPages<'a> {
         Ok(())
     }
 
+    /// Renders every page in the given range of this [PdfPages] collection using the
+    /// pixel dimensions, page rotation settings, and rendering options configured in the
+    /// given [PdfRenderConfig], returning the rendered bitmaps in page order.
+    ///
+    /// Pages are loaded, rendered, and released one at a time, so only one page is held
+    /// in memory at once. Rendering stops at the first page that fails to load or render;
+    /// the whole batch then fails with [PdfiumError::PageRenderingFailed], which carries
+    /// the index of the failing page and the underlying error.
+    pub fn render_range(
+        &self,
+        range: Range<PdfPageIndex>,
+        config: &PdfRenderConfig,
+    ) -> Result<Vec<PdfBitmap<'a>>, PdfiumError> {
+        range
+            .map(|index| {
+                self.get(index)
+                    .and_then(|page| page.render_with_config(config))
+                    .map_err(|err| PdfiumError::PageRenderingFailed(index, Box::new(err)))
+            })
+            .collect()
+    }
+
     /// Returns an iterator over all the pages in this [PdfPages] collection.
     #[inline]
     pub fn iter(&self) -> PdfPagesIterator {
@@ -605,3 +701,70 @@ impl<'a> Iterator for PdfPagesIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_page_label() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // A new document has no page label tree.
+
+        assert_eq!(document.pages().page_label(0)?, None);
+
+        assert!(matches!(
+            document.pages().page_label(1),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_range() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4r())?;
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a3())?;
+
+        let config = PdfRenderConfig::new().set_target_width(200);
+
+        let bitmaps = document.pages().render_range(0..3, &config)?;
+
+        // Bitmaps are returned in page order.
+
+        assert_eq!(bitmaps.len(), 3);
+        assert_eq!(bitmaps[0].width(), 200);
+        assert!(bitmaps[0].height() > bitmaps[0].width());
+        assert!(bitmaps[1].height() < bitmaps[1].width());
+
+        assert!(document.pages().render_range(1..1, &config)?.is_empty());
+
+        // A failing page aborts the batch and reports the failing page index.
+
+        assert!(matches!(
+            document.pages().render_range(1..5, &config),
+            Err(PdfiumError::PageRenderingFailed(3, _))
+        ));
+
+        Ok(())
+    }
+}
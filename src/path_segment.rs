@@ -36,6 +36,39 @@ impl PdfPathSegmentType {
     }
 }
 
+/// A single drawing command in a path, assembled from one or more [PdfPathSegment] objects
+/// in a `PdfPathSegments` collection.
+///
+/// Pdfium reports each cubic Bézier curve as three consecutive path segments of type
+/// [PdfPathSegmentType::BezierTo]: the first control point, the second control point, and the
+/// end point. A [PdfPathCommand::BezierTo] command combines all three.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPathCommand {
+    /// Begins a new sub-path at the given point.
+    MoveTo { x: PdfPoints, y: PdfPoints },
+
+    /// Draws a straight line from the current point to the given point. If `close` is `true`,
+    /// the current sub-path is closed after the line is drawn.
+    LineTo {
+        x: PdfPoints,
+        y: PdfPoints,
+        close: bool,
+    },
+
+    /// Draws a cubic Bézier curve from the current point to the given end point, using the
+    /// two given control points. If `close` is `true`, the current sub-path is closed after
+    /// the curve is drawn.
+    BezierTo {
+        control1_x: PdfPoints,
+        control1_y: PdfPoints,
+        control2_x: PdfPoints,
+        control2_y: PdfPoints,
+        x: PdfPoints,
+        y: PdfPoints,
+        close: bool,
+    },
+}
+
 /// A single [PdfPathSegment] in a `PdfPathSegments` collection.
 pub struct PdfPathSegment<'a> {
     handle: FPDF_PATHSEGMENT,
@@ -120,8 +153,10 @@ impl<'a> PdfPathSegment<'a> {
 mod tests {
     use crate::color::PdfColor;
     use crate::matrix::PdfMatrix;
+    use crate::page_object_path::PdfPagePathObject;
     use crate::page_objects_common::PdfPageObjectsCommon;
     use crate::page_size::PdfPagePaperSize;
+    use crate::path_segment::PdfPathCommand;
     use crate::path_segments::PdfPathSegments;
     use crate::points::PdfPoints;
     use crate::utils::test::test_bind_to_pdfium;
@@ -229,4 +264,191 @@ mod tests {
             assert_eq!(transformed.1, raw.1 + delta_y);
         }
     }
+
+    #[test]
+    fn test_path_commands() {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf().unwrap();
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(1.0)),
+            None,
+        )
+        .unwrap();
+
+        path.line_to(PdfPoints::new(200.0), PdfPoints::new(100.0))
+            .unwrap();
+        path.bezier_to(
+            PdfPoints::new(300.0),
+            PdfPoints::new(200.0),
+            PdfPoints::new(250.0),
+            PdfPoints::new(100.0),
+            PdfPoints::new(300.0),
+            PdfPoints::new(150.0),
+        )
+        .unwrap();
+        path.close_path().unwrap();
+
+        let expected = vec![
+            PdfPathCommand::MoveTo {
+                x: PdfPoints::new(100.0),
+                y: PdfPoints::new(100.0),
+            },
+            PdfPathCommand::LineTo {
+                x: PdfPoints::new(200.0),
+                y: PdfPoints::new(100.0),
+                close: false,
+            },
+            PdfPathCommand::BezierTo {
+                control1_x: PdfPoints::new(250.0),
+                control1_y: PdfPoints::new(100.0),
+                control2_x: PdfPoints::new(300.0),
+                control2_y: PdfPoints::new(150.0),
+                x: PdfPoints::new(300.0),
+                y: PdfPoints::new(200.0),
+                close: true,
+            },
+        ];
+
+        assert_eq!(path.segments().len(), 5);
+        assert_eq!(path.segments().commands().collect::<Vec<_>>(), expected);
+
+        // Translating the object changes its page space coordinates,
+        // but not the coordinates of its segments in object space.
+
+        path.translate(PdfPoints::new(10.0), PdfPoints::new(20.0))
+            .unwrap();
+
+        assert_eq!(path.segments().commands().collect::<Vec<_>>(), expected);
+
+        let page_space = path.segments_in_page_space().unwrap();
+
+        assert_eq!(
+            page_space.commands().next(),
+            Some(PdfPathCommand::MoveTo {
+                x: PdfPoints::new(110.0),
+                y: PdfPoints::new(120.0),
+            })
+        );
+        assert_eq!(
+            page_space.commands().last(),
+            Some(PdfPathCommand::BezierTo {
+                control1_x: PdfPoints::new(260.0),
+                control1_y: PdfPoints::new(120.0),
+                control2_x: PdfPoints::new(310.0),
+                control2_y: PdfPoints::new(170.0),
+                x: PdfPoints::new(310.0),
+                y: PdfPoints::new(220.0),
+                close: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rounded_rect_and_arc_commands() {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf().unwrap();
+
+        // A 100 x 40 rectangle with a requested corner radius of 50,
+        // which should be clamped to 20, half the rectangle's height.
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        path.rounded_rect_to(
+            PdfPoints::new(100.0),
+            PdfPoints::new(40.0),
+            PdfPoints::new(50.0),
+        )
+        .unwrap();
+
+        let corners = path
+            .segments()
+            .commands()
+            .filter_map(|command| match command {
+                PdfPathCommand::BezierTo { x, y, .. } => Some((x.value, y.value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            corners,
+            vec![(100.0, 20.0), (80.0, 40.0), (0.0, 20.0), (20.0, 0.0)]
+        );
+
+        // A quarter-circle arc of radius 100 around the origin, from 0 to 90 degrees.
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::new(100.0),
+            PdfPoints::ZERO,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        path.arc_to(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            PdfPoints::new(100.0),
+            0.0,
+            90.0,
+        )
+        .unwrap();
+
+        match path.segments().commands().last() {
+            Some(PdfPathCommand::BezierTo {
+                control1_x,
+                control1_y,
+                control2_x,
+                control2_y,
+                x,
+                y,
+                ..
+            }) => {
+                let k = 55.228;
+
+                assert!((control1_x.value - 100.0).abs() < 0.01);
+                assert!((control1_y.value - k).abs() < 0.01);
+                assert!((control2_x.value - k).abs() < 0.01);
+                assert!((control2_y.value - 100.0).abs() < 0.01);
+                assert!(x.value.abs() < 0.01);
+                assert!((y.value - 100.0).abs() < 0.01);
+            }
+            other => panic!("expected a Bézier curve, found {:?}", other),
+        }
+
+        // A full circle is approximated with four curves.
+
+        path.arc_to(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            PdfPoints::new(100.0),
+            90.0,
+            -360.0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            path.segments()
+                .commands()
+                .filter(|command| matches!(command, PdfPathCommand::BezierTo { .. }))
+                .count(),
+            5
+        );
+    }
 }
@@ -3,7 +3,7 @@
 
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
-use crate::path_segment::PdfPathSegment;
+use crate::path_segment::{PdfPathCommand, PdfPathSegment, PdfPathSegmentType};
 use std::ops::{Range, RangeInclusive};
 
 /// The zero-based index of a single [PdfPathSegment] inside its containing [PdfPathSegments] collection.
@@ -45,6 +45,14 @@ pub trait PdfPathSegments<'a> {
 
     /// Returns an iterator over all the path segments in this [PdfPathSegments] collection.
     fn iter(&'a self) -> PdfPathSegmentsIterator<'a>;
+
+    /// Returns an iterator over the drawing commands described by the path segments in this
+    /// [PdfPathSegments] collection. Each group of three Bézier path segments is combined into
+    /// a single [PdfPathCommand::BezierTo] command.
+    #[inline]
+    fn commands(&'a self) -> PdfPathCommandsIterator<'a> {
+        PdfPathCommandsIterator::new(self.iter())
+    }
 }
 
 /// An iterator over all the [PdfPathSegment] objects in a [PdfPathSegments] collection.
@@ -74,3 +82,60 @@ impl<'a> Iterator for PdfPathSegmentsIterator<'a> {
         next.ok()
     }
 }
+
+/// An iterator over the [PdfPathCommand] drawing commands described by the [PdfPathSegment]
+/// objects in a [PdfPathSegments] collection.
+///
+/// Path segments of unknown type are skipped. Iteration ends early if the collection ends
+/// partway through the three path segments of a Bézier curve.
+pub struct PdfPathCommandsIterator<'a> {
+    segments: PdfPathSegmentsIterator<'a>,
+}
+
+impl<'a> PdfPathCommandsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(segments: PdfPathSegmentsIterator<'a>) -> Self {
+        PdfPathCommandsIterator { segments }
+    }
+}
+
+impl<'a> Iterator for PdfPathCommandsIterator<'a> {
+    type Item = PdfPathCommand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let segment = self.segments.next()?;
+
+            let (x, y) = segment.point();
+
+            match segment.segment_type() {
+                PdfPathSegmentType::MoveTo => return Some(PdfPathCommand::MoveTo { x, y }),
+                PdfPathSegmentType::LineTo => {
+                    return Some(PdfPathCommand::LineTo {
+                        x,
+                        y,
+                        close: segment.is_close(),
+                    })
+                }
+                PdfPathSegmentType::BezierTo => {
+                    let (control2_x, control2_y) = self.segments.next()?.point();
+
+                    let end = self.segments.next()?;
+
+                    let (end_x, end_y) = end.point();
+
+                    return Some(PdfPathCommand::BezierTo {
+                        control1_x: x,
+                        control1_y: y,
+                        control2_x,
+                        control2_y,
+                        x: end_x,
+                        y: end_y,
+                        close: end.is_close(),
+                    });
+                }
+                PdfPathSegmentType::Unknown => continue,
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Defines the [Pdfium] struct, a high-level idiomatic Rust wrapper around Pdfium.
 
 use crate::bindings::PdfiumLibraryBindings;
-use crate::document::{PdfDocument, PdfDocumentVersion};
+use crate::document::{find_linearized_first_page_index, PdfDocument, PdfDocumentVersion};
 use crate::error::{PdfiumError, PdfiumInternalError};
 use std::fmt::{Debug, Formatter};
 
@@ -18,13 +18,24 @@ use crate::native::DynamicPdfiumBindings;
 use crate::linked::StaticPdfiumBindings;
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::utils::files::get_pdfium_file_accessor_from_reader;
+use crate::pages::PdfPageIndex;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::utils::files::{
+    get_pdfium_data_availability_from_source, get_pdfium_file_accessor_from_reader,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::data_availability::PdfDataAvailability;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::pending_document::{PdfPendingDocument, PdfProgressiveDocument};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs::File;
 
 #[cfg(not(target_arch = "wasm32"))]
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
@@ -220,6 +231,14 @@ impl Pdfium {
             self.bindings.FPDF_LoadMemDocument64(bytes, password),
             self.bindings(),
         )
+        .map(|mut document| {
+            document.set_linearized_first_page_index(find_linearized_first_page_index(
+                bytes,
+                bytes.len() as u64,
+            ));
+
+            document
+        })
     }
 
     /// Attempts to open a [PdfDocument] from the given owned byte buffer.
@@ -239,6 +258,11 @@ impl Pdfium {
             self.bindings(),
         )
         .map(|mut document| {
+            document.set_linearized_first_page_index(find_linearized_first_page_index(
+                bytes.as_slice(),
+                bytes.len() as u64,
+            ));
+
             // Give the newly-created document ownership of the byte buffer, so that Pdfium can continue
             // to read from it on an as-needed basis throughout the lifetime of the document.
 
@@ -305,9 +329,15 @@ impl Pdfium {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn load_pdf_from_reader<'a, R: Read + Seek + 'a>(
         &'a self,
-        reader: R,
+        mut reader: R,
         password: Option<&'a str>,
     ) -> Result<PdfDocument<'a>, PdfiumError> {
+        // Pdfium does not report whether a document loaded by FPDF_LoadCustomDocument()
+        // is linearized, so we inspect the start of the file ourselves before handing
+        // the reader over to Pdfium.
+
+        let linearized_first_page_index = Self::read_linearized_first_page_index(&mut reader);
+
         let mut reader = get_pdfium_file_accessor_from_reader(reader);
 
         Pdfium::pdfium_document_handle_to_result(
@@ -320,11 +350,73 @@ impl Pdfium {
             // to read from it on an as-needed basis throughout the lifetime of the document.
 
             document.set_file_access_reader(reader);
+            document.set_linearized_first_page_index(linearized_first_page_index);
 
             document
         })
     }
 
+    /// Returns the first page index declared in the linearization parameter dictionary at the
+    /// start of the given reader, if any, leaving the reader positioned at the start of its data.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_linearized_first_page_index<R: Read + Seek>(reader: &mut R) -> Option<PdfPageIndex> {
+        let file_length = reader.seek(SeekFrom::End(0)).ok()?;
+
+        reader.seek(SeekFrom::Start(0)).ok()?;
+
+        let mut header = Vec::with_capacity(1024);
+
+        let result = reader
+            .by_ref()
+            .take(1024)
+            .read_to_end(&mut header)
+            .ok()
+            .and_then(|_| find_linearized_first_page_index(header.as_slice(), file_length));
+
+        reader.seek(SeekFrom::Start(0)).ok()?;
+
+        result
+    }
+
+    /// Attempts to open a [PdfDocument] progressively from the given [PdfDataAvailability]
+    /// source, before all of the document's data has arrived.
+    ///
+    /// This allows the first page of a large document to be displayed while the rest of the
+    /// document is still being downloaded. If enough data has arrived to open the document,
+    /// [PdfProgressiveDocument::Loaded] is returned. Otherwise, the byte ranges Pdfium needs
+    /// are reported to the source through [PdfDataAvailability::request_data()], and
+    /// [PdfProgressiveDocument::Pending] is returned; call [PdfPendingDocument::poll()] to
+    /// try again once more data has arrived. Once the document is open, retrieving a page
+    /// whose data has not yet arrived returns [PdfiumError::PageDataNotYetAvailable].
+    ///
+    /// Only linearized documents can be opened before all of their data has arrived.
+    ///
+    /// If the document is password protected, the given password will be used
+    /// to unlock it.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_pdf_from_avail<'a>(
+        &'a self,
+        source: impl PdfDataAvailability + 'a,
+        password: Option<&'a str>,
+    ) -> Result<PdfProgressiveDocument<'a>, PdfiumError> {
+        let mut data_availability = get_pdfium_data_availability_from_source(source);
+
+        let avail = self.bindings.FPDFAvail_Create(
+            data_availability.as_fx_file_avail_mut_ptr(),
+            data_availability.as_fpdf_file_access_mut_ptr(),
+        );
+
+        if avail.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        PdfPendingDocument::from_pdfium(avail, data_availability, password, self.bindings()).poll()
+    }
+
     /// Attempts to open a [PdfDocument] by loading document data from the given URL.
     /// The Javascript `fetch()` API is used to download data over the network.
     ///
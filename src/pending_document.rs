@@ -0,0 +1,131 @@
+//! Defines the [PdfPendingDocument] struct, a progressively loaded `PdfDocument` that
+//! is waiting for more of its data to arrive.
+
+use crate::bindgen::{
+    FPDF_AVAIL, PDF_DATA_AVAIL, PDF_DATA_ERROR, PDF_DATA_NOTAVAIL, PDF_LINEARIZED,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::document::PdfDocument;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pages::PdfPageIndex;
+use crate::pdfium::Pdfium;
+use crate::utils::files::FpdfDataAvailabilityExt;
+use std::os::raw::c_int;
+
+/// The result of attempting to open a `PdfDocument` progressively from a
+/// `PdfDataAvailability` source.
+#[allow(clippy::large_enum_variant)]
+// A PdfDocument is usually returned, so boxing it would only add an allocation.
+pub enum PdfProgressiveDocument<'a> {
+    /// Enough data has arrived to open the document.
+    Loaded(PdfDocument<'a>),
+
+    /// Not enough data has yet arrived to open the document. Call
+    /// [PdfPendingDocument::poll()] to try again once more data has arrived.
+    Pending(PdfPendingDocument<'a>),
+}
+
+/// A `PdfDocument` being opened progressively using `Pdfium::load_pdf_from_avail()` that
+/// cannot be opened until more of its data has arrived.
+///
+/// The [PdfPendingDocument] retains the document's `PdfDataAvailability` source, so the
+/// source does not need to be re-created each time the document is polled.
+pub struct PdfPendingDocument<'a> {
+    avail: FPDF_AVAIL,
+    data_availability: Option<Box<FpdfDataAvailabilityExt<'a>>>,
+    password: Option<&'a str>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPendingDocument<'a> {
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This function is never used when compiling to WASM.
+    #[inline]
+    pub(crate) fn from_pdfium(
+        avail: FPDF_AVAIL,
+        data_availability: Box<FpdfDataAvailabilityExt<'a>>,
+        password: Option<&'a str>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPendingDocument {
+            avail,
+            data_availability: Some(data_availability),
+            password,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPendingDocument].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Checks whether enough data has now arrived to open this [PdfPendingDocument].
+    ///
+    /// Returns [PdfProgressiveDocument::Loaded] containing the opened document if so.
+    /// Otherwise, the byte ranges Pdfium needs are reported to the document's
+    /// `PdfDataAvailability` source through `PdfDataAvailability::request_data()`, and
+    /// this [PdfPendingDocument] is returned inside [PdfProgressiveDocument::Pending]
+    /// so it can be polled again later.
+    pub fn poll(mut self) -> Result<PdfProgressiveDocument<'a>, PdfiumError> {
+        let status = match self.data_availability.as_mut() {
+            Some(data_availability) => self
+                .bindings
+                .FPDFAvail_IsDocAvail(self.avail, data_availability.as_fx_download_hints_mut_ptr()),
+            // The source is only handed over once the document has been opened, so it is
+            // always present while the document is pending.
+            None => PDF_DATA_ERROR,
+        };
+
+        if status == PDF_DATA_NOTAVAIL as c_int {
+            return Ok(PdfProgressiveDocument::Pending(self));
+        }
+
+        if status != PDF_DATA_AVAIL as c_int {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let mut document = Pdfium::pdfium_document_handle_to_result(
+            self.bindings
+                .FPDFAvail_GetDocument(self.avail, self.password),
+            self.bindings,
+        )?;
+
+        // Give the newly-created document ownership of the availability provider and
+        // its source, so that Pdfium can continue to read from them on an as-needed
+        // basis throughout the lifetime of the document.
+
+        if let Some(data_availability) = self.data_availability.take() {
+            document.set_data_availability(self.avail, data_availability);
+        }
+
+        // Pdfium's availability provider has by now read the start of the file, so it can
+        // tell us whether the file is linearized. We ask it here, rather than when the
+        // document was first polled, because the start of the file may not have arrived then.
+
+        let linearized_first_page_index =
+            if self.bindings.FPDFAvail_IsLinearized(self.avail) == PDF_LINEARIZED as c_int {
+                Some(self.bindings.FPDFAvail_GetFirstPageNum(document.handle()) as PdfPageIndex)
+            } else {
+                None
+            };
+
+        document.set_linearized_first_page_index(linearized_first_page_index);
+
+        Ok(PdfProgressiveDocument::Loaded(document))
+    }
+}
+
+impl<'a> Drop for PdfPendingDocument<'a> {
+    /// Destroys the availability provider of this [PdfPendingDocument], unless it has
+    /// been handed over to a newly opened `PdfDocument`.
+    #[inline]
+    fn drop(&mut self) {
+        if self.data_availability.is_some() {
+            self.bindings.FPDFAvail_Destroy(self.avail);
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //! that outline the bounds of a four-sided quadrilateral.
 
 use crate::bindgen::FS_QUADPOINTSF;
+use crate::matrix::PdfMatrix;
 use crate::points::PdfPoints;
 use crate::rect::PdfRect;
 use std::fmt::{Display, Formatter};
@@ -112,6 +113,121 @@ impl PdfQuadPoints {
         )
     }
 
+    /// Returns the smallest axis-aligned [PdfRect] that encloses all four vertices
+    /// of this [PdfQuadPoints].
+    pub fn to_rect(&self) -> PdfRect {
+        let xs = [self.x1.value, self.x2.value, self.x3.value, self.x4.value];
+        let ys = [self.y1.value, self.y2.value, self.y3.value, self.y4.value];
+
+        PdfRect::new_from_values(
+            ys.iter().copied().fold(f32::MAX, f32::min),
+            xs.iter().copied().fold(f32::MAX, f32::min),
+            ys.iter().copied().fold(f32::MIN, f32::max),
+            xs.iter().copied().fold(f32::MIN, f32::max),
+        )
+    }
+
+    /// Returns `true` if the given point lies inside this [PdfQuadPoints], or on one of its edges.
+    ///
+    /// The quadrilateral is treated as the convex region enclosed by its four vertices,
+    /// so the result does not depend on the order in which the vertices are specified.
+    pub fn contains(&self, x: PdfPoints, y: PdfPoints) -> bool {
+        let vertices = [
+            (self.x1.value, self.y1.value),
+            (self.x2.value, self.y2.value),
+            (self.x3.value, self.y3.value),
+            (self.x4.value, self.y4.value),
+        ];
+
+        // A point lies inside the convex region enclosed by the four vertices if and only if
+        // it lies inside at least one of the four triangles formed from any three of them.
+
+        [(0, 1, 2), (0, 1, 3), (0, 2, 3), (1, 2, 3)]
+            .iter()
+            .any(|&(a, b, c)| {
+                Self::triangle_contains(vertices[a], vertices[b], vertices[c], (x.value, y.value))
+            })
+    }
+
+    /// Returns `true` if the given point lies inside the triangle with the given vertices,
+    /// or on one of its edges.
+    fn triangle_contains(a: (f32, f32), b: (f32, f32), c: (f32, f32), point: (f32, f32)) -> bool {
+        let cross = |(x1, y1): (f32, f32), (x2, y2): (f32, f32)| {
+            (x2 - x1) * (point.1 - y1) - (y2 - y1) * (point.0 - x1)
+        };
+
+        let d1 = cross(a, b);
+        let d2 = cross(b, c);
+        let d3 = cross(c, a);
+
+        let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+        !(has_negative && has_positive)
+    }
+
+    /// Returns the result of applying the given [PdfMatrix] to each vertex of this [PdfQuadPoints].
+    #[inline]
+    pub fn transform(&self, matrix: PdfMatrix) -> PdfQuadPoints {
+        let (x1, y1) = matrix.apply_to_points(self.x1, self.y1);
+        let (x2, y2) = matrix.apply_to_points(self.x2, self.y2);
+        let (x3, y3) = matrix.apply_to_points(self.x3, self.y3);
+        let (x4, y4) = matrix.apply_to_points(self.x4, self.y4);
+
+        PdfQuadPoints::new(x1, y1, x2, y2, x3, y3, x4, y4)
+    }
+
+    /// Creates a new [PdfQuadPoints] describing the smallest quadrilateral, rotated
+    /// counter-clockwise by the given angle in radians, that encloses every corner of
+    /// the given rectangles. An angle of zero yields the union of the given rectangles.
+    ///
+    /// Returns `None` if no rectangles are given.
+    pub(crate) fn from_rects_at_angle(rects: &[PdfRect], angle_radians: f32) -> Option<Self> {
+        if rects.is_empty() {
+            return None;
+        }
+
+        // Project every rectangle corner onto the axes of the rotated coordinate space,
+        // tracking the extent along each axis.
+
+        let (sin, cos) = angle_radians.sin_cos();
+
+        let mut min_u = f32::MAX;
+        let mut max_u = f32::MIN;
+        let mut min_v = f32::MAX;
+        let mut max_v = f32::MIN;
+
+        for rect in rects {
+            for (x, y) in [
+                (rect.left.value, rect.bottom.value),
+                (rect.right.value, rect.bottom.value),
+                (rect.left.value, rect.top.value),
+                (rect.right.value, rect.top.value),
+            ] {
+                let u = x * cos + y * sin;
+                let v = y * cos - x * sin;
+
+                min_u = min_u.min(u);
+                max_u = max_u.max(u);
+                min_v = min_v.min(v);
+                max_v = max_v.max(v);
+            }
+        }
+
+        // Map the extents back into page space, using the same vertex order as from_rect().
+
+        let to_page = |u: f32, v: f32| (u * cos - v * sin, u * sin + v * cos);
+
+        let (x1, y1) = to_page(min_u, max_v);
+        let (x2, y2) = to_page(max_u, max_v);
+        let (x3, y3) = to_page(min_u, min_v);
+        let (x4, y4) = to_page(max_u, min_v);
+
+        Some(PdfQuadPoints::new_from_values(
+            x1, y1, x2, y2, x3, y3, x4, y4,
+        ))
+    }
+
     #[inline]
     pub(crate) fn as_pdfium(&self) -> FS_QUADPOINTSF {
         FS_QUADPOINTSF {
@@ -143,3 +259,72 @@ impl Display for PdfQuadPoints {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::quad_points::PdfQuadPoints;
+    use crate::rect::PdfRect;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 0.001, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_from_rects_at_angle_zero_is_union() {
+        let result = PdfQuadPoints::from_rects_at_angle(
+            &[
+                PdfRect::new_from_values(10.0, 10.0, 20.0, 30.0),
+                PdfRect::new_from_values(12.0, 30.0, 22.0, 50.0),
+            ],
+            0.0,
+        )
+        .unwrap();
+
+        let expected = PdfQuadPoints::from_rect(PdfRect::new_from_values(10.0, 10.0, 22.0, 50.0));
+
+        assert_close(result.x1.value, expected.x1.value);
+        assert_close(result.y1.value, expected.y1.value);
+        assert_close(result.x2.value, expected.x2.value);
+        assert_close(result.y2.value, expected.y2.value);
+        assert_close(result.x3.value, expected.x3.value);
+        assert_close(result.y3.value, expected.y3.value);
+        assert_close(result.x4.value, expected.x4.value);
+        assert_close(result.y4.value, expected.y4.value);
+    }
+
+    #[test]
+    fn test_from_rects_at_angle_quarter_turn() {
+        // Text running vertically upwards; the "top" of each glyph faces left.
+
+        let result = PdfQuadPoints::from_rects_at_angle(
+            &[PdfRect::new_from_values(100.0, 10.0, 200.0, 20.0)],
+            std::f32::consts::FRAC_PI_2,
+        )
+        .unwrap();
+
+        assert_close(result.x1.value, 10.0);
+        assert_close(result.y1.value, 100.0);
+        assert_close(result.x2.value, 10.0);
+        assert_close(result.y2.value, 200.0);
+        assert_close(result.x3.value, 20.0);
+        assert_close(result.y3.value, 100.0);
+        assert_close(result.x4.value, 20.0);
+        assert_close(result.y4.value, 200.0);
+    }
+
+    #[test]
+    fn test_to_rect_encloses_rotated_quad() {
+        let rect =
+            PdfQuadPoints::new_from_values(10.0, 0.0, 20.0, 10.0, 0.0, 10.0, 10.0, 20.0).to_rect();
+
+        assert_close(rect.bottom.value, 0.0);
+        assert_close(rect.left.value, 0.0);
+        assert_close(rect.top.value, 20.0);
+        assert_close(rect.right.value, 20.0);
+    }
+
+    #[test]
+    fn test_from_rects_at_angle_rejects_empty_input() {
+        assert!(PdfQuadPoints::from_rects_at_angle(&[], 0.0).is_none());
+    }
+}
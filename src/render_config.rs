@@ -16,6 +16,7 @@ use crate::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::page::PdfPageOrientation::{Landscape, Portrait};
 use crate::page::{PdfPage, PdfPageOrientation, PdfPageRenderRotation};
 use crate::points::PdfPoints;
+use crate::rect::PdfRect;
 use std::os::raw::c_int;
 
 #[cfg(doc)]
@@ -87,6 +88,7 @@ pub struct PdfRenderConfig {
     form_field_highlight: Option<Vec<(PdfFormFieldType, PdfColor)>>,
     transformation_matrix: PdfMatrix,
     clip_rect: Option<(Pixels, Pixels, Pixels, Pixels)>,
+    clip_to_page_rect: Option<PdfRect>,
 
     // The fields below set Pdfium's page rendering flags. Coverage for the
     // FPDF_DEBUG_INFO and FPDF_NO_CATCH flags is omitted since they are obsolete.
@@ -125,6 +127,7 @@ impl PdfRenderConfig {
             form_field_highlight: None,
             transformation_matrix: PdfMatrix::IDENTITY,
             clip_rect: None,
+            clip_to_page_rect: None,
             do_set_flag_render_annotations: true,
             do_set_flag_use_lcd_text_rendering: false,
             do_set_flag_no_native_text: false,
@@ -394,6 +397,10 @@ impl PdfRenderConfig {
 
     /// Controls whether user-supplied annotations should be included during rendering of
     /// the [PdfPage]. The default is `true`.
+    ///
+    /// Even when annotation rendering is enabled, Pdfium will skip any annotation with the
+    /// `PdfAnnotationFlags::Hidden` flag set, and any annotation with the
+    /// `PdfAnnotationFlags::NoView` flag set unless rendering for printing.
     #[inline]
     pub fn render_annotations(mut self, do_render: bool) -> Self {
         self.do_set_flag_render_annotations = do_render;
@@ -412,6 +419,22 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Enables subpixel anti-aliasing of rendered text, optimized for LCD displays. This is
+    /// equivalent to calling [PdfRenderConfig::use_lcd_text_rendering()] with a value of `true`.
+    ///
+    /// Subpixel rendering deliberately introduces red and blue color fringes around glyphs,
+    /// which are only invisible when the rendered bitmap is displayed unscaled on an LCD panel
+    /// with a known, horizontal RGB subpixel layout. It should not be used when the rendered
+    /// output will be scaled, rotated, printed, or processed further, for instance by OCR.
+    ///
+    /// Subpixel rendering relies on the separate color channels of the rendered bitmap, so it
+    /// cannot be combined with [PdfRenderConfig::render_grayscale()]. It also has no effect
+    /// if anti-aliasing of text has been disabled.
+    #[inline]
+    pub fn enable_lcd_text(self) -> Self {
+        self.use_lcd_text_rendering(true)
+    }
+
     /// Controls whether platform text rendering should be disabled on platforms that support it.
     /// The alternative is for Pdfium to render all text internally, which may give more
     /// consistent rendering results across platforms but may also be slower.
@@ -432,6 +455,28 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Controls whether the [PdfPage] should be rendered directly into a single-channel
+    /// grayscale bitmap. The default is `false`.
+    ///
+    /// Enabling this sets both the grayscale rendering flag, as per
+    /// [PdfRenderConfig::use_grayscale_rendering()], and the pixel format of the rendered bitmap
+    /// to [PdfBitmapFormat::Gray], so that the bitmap uses one byte per pixel and is converted to
+    /// a single-channel `DynamicImage::ImageLuma8` image rather than a four-channel color image.
+    /// Disabling this clears the grayscale rendering flag and, if the pixel format was
+    /// [PdfBitmapFormat::Gray], restores the default pixel format.
+    #[inline]
+    pub fn render_grayscale(mut self, enabled: bool) -> Self {
+        self.do_set_flag_grayscale = enabled;
+
+        if enabled {
+            self.format = PdfBitmapFormat::Gray;
+        } else if self.format == PdfBitmapFormat::Gray {
+            self.format = PdfBitmapFormat::default();
+        }
+
+        self
+    }
+
     /// Controls whether Pdfium should limit its image cache size during rendering.
     /// A smaller cache size may result in lower memory usage at the cost of slower rendering.
     /// The default is `false`.
@@ -495,6 +540,34 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Controls whether anti-aliasing of rendered text should be disabled. The default is `false`.
+    ///
+    /// This is the inverse of [PdfRenderConfig::set_text_smoothing()]. Disabling anti-aliasing
+    /// produces crisp, unsmoothed output suitable for OCR or for pixel-exact comparison of
+    /// rendered pages.
+    #[inline]
+    pub fn disable_text_antialiasing(self, do_set_flag: bool) -> Self {
+        self.set_text_smoothing(!do_set_flag)
+    }
+
+    /// Controls whether anti-aliasing of rendered images should be disabled.
+    /// The default is `false`.
+    ///
+    /// This is the inverse of [PdfRenderConfig::set_image_smoothing()].
+    #[inline]
+    pub fn disable_image_antialiasing(self, do_set_flag: bool) -> Self {
+        self.set_image_smoothing(!do_set_flag)
+    }
+
+    /// Controls whether anti-aliasing of rendered vector paths should be disabled.
+    /// The default is `false`.
+    ///
+    /// This is the inverse of [PdfRenderConfig::set_path_smoothing()].
+    #[inline]
+    pub fn disable_path_antialiasing(self, do_set_flag: bool) -> Self {
+        self.set_path_smoothing(!do_set_flag)
+    }
+
     /// Controls whether the byte order of generated image data should be reversed
     /// during rendering. The default is `true`, so that Pdfium returns pixel data as
     /// four-channel RGBA rather than its default of four-channel BGRA.
@@ -518,6 +591,21 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Sets the highlight color of all rendered form fields, replacing any highlight colors set
+    /// by earlier calls to this function or to [PdfRenderConfig::highlight_form_fields_of_type()]
+    /// and its related functions. Specifying `None` disables form field highlighting entirely.
+    ///
+    /// Note that specifying a solid color with no opacity will overprint any user data in the field.
+    #[inline]
+    pub fn set_form_field_highlight(mut self, color: Option<PdfColor>) -> Self {
+        self.form_field_highlight = Some(match color {
+            Some(color) => vec![(PdfFormFieldType::Unknown, color)],
+            None => vec![],
+        });
+
+        self
+    }
+
     /// Highlights all rendered form fields with the given color.
     /// Note that specifying a solid color with no opacity will overprint any user data in the field.
     #[inline]
@@ -652,6 +740,28 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Renders only the given rectangle of the [PdfPage], expressed in page coordinates.
+    ///
+    /// The rectangle takes the place of the whole page when computing the pixel dimensions
+    /// of the rendered bitmap, so the bitmap will be sized to fit the rectangle alone, and
+    /// the rectangle will fill the bitmap. Any target or maximum pixel sizes, scaling factors,
+    /// and rotation settings are applied to the rectangle rather than the page. Content outside
+    /// the rectangle is never rasterized, making this suitable for rendering very large pages
+    /// as a series of tiles.
+    ///
+    /// Pdfium's rendering pipeline supports _either_ rendering with form data _or_ clipping rendering
+    /// output, but not both at the same time. Applying a clipping rectangle automatically disables
+    /// rendering of form data. If you must render form data while simultaneously applying a
+    /// clipping rectangle, consider using the [PdfPage::flatten()] function to flatten the
+    /// form elements and form data into the containing page.
+    #[inline]
+    pub fn clip_to_rect(mut self, rect: PdfRect) -> Self {
+        self.clip_to_page_rect = Some(rect);
+        self.do_render_form_data = false;
+
+        self
+    }
+
     /// Computes the pixel dimensions and rotation settings for the given [PdfPage]
     /// based on the configuration of this [PdfRenderConfig].
     #[inline]
@@ -683,6 +793,14 @@ impl PdfRenderConfig {
             (PdfPageRenderRotation::None, false)
         };
 
+        // If rendering is restricted to a rectangle on the page, the rectangle takes the place
+        // of the page when computing the output dimensions.
+
+        let (source_width, source_height) = match self.clip_to_page_rect {
+            Some(rect) => (rect.width(), rect.height()),
+            None => (source_width, source_height),
+        };
+
         let width_scale = if let Some(scale) = self.scale_width_factor {
             Some(scale)
         } else {
@@ -823,6 +941,17 @@ impl PdfRenderConfig {
         // 90-degree rotation need to be applied to the transformation matrix now.
 
         let transformation_matrix = if !self.do_render_form_data {
+            // Pdfium's rendering matrix operates on page coordinates with the origin at the
+            // top left of the page. To render only a rectangle on the page, we move the top left
+            // corner of the rectangle to the origin, so the rectangle fills the output bitmap.
+
+            let base_matrix = match self.clip_to_page_rect {
+                Some(rect) => self
+                    .transformation_matrix
+                    .translate(-rect.left, rect.top - page.height()),
+                None => Ok(self.transformation_matrix),
+            };
+
             let result = if target_rotation != PdfPageRenderRotation::None {
                 // Translate the origin to the center of the page before rotating.
 
@@ -833,13 +962,13 @@ impl PdfRenderConfig {
                     PdfPageRenderRotation::Degrees270 => (-source_height, PdfPoints::ZERO),
                 };
 
-                self.transformation_matrix
-                    .translate(delta_x, delta_y)
+                base_matrix
+                    .and_then(|result| result.translate(delta_x, delta_y))
                     .and_then(|result| {
                         result.rotate_clockwise_degrees(target_rotation.as_degrees())
                     })
             } else {
-                Ok(self.transformation_matrix)
+                base_matrix
             };
 
             result.and_then(|result| result.scale(width_scale, height_scale))
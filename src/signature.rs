@@ -3,10 +3,118 @@
 
 use crate::bindgen::FPDF_SIGNATURE;
 use crate::bindings::PdfiumLibraryBindings;
-use crate::utils::mem::create_byte_buffer;
+use crate::certificate::PdfCertificate;
+use crate::error::PdfiumError;
+use crate::utils::asn1::{
+    read_element, DerElement, TAG_OBJECT_IDENTIFIER, TAG_OCTET_STRING, TAG_SEQUENCE, TAG_SET,
+};
+use crate::utils::mem::{create_byte_buffer, create_sized_buffer};
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
 use std::ffi::CString;
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
+
+// Object identifiers used when locating the expected digest inside a PKCS#7 signature.
+
+const OID_PKCS7_SIGNED_DATA: &str = "1.2.840.113549.1.7.2";
+const OID_PKCS9_MESSAGE_DIGEST: &str = "1.2.840.113549.1.9.4";
+
+/// The hash function used to compute the digest of the signed byte ranges of a [PdfSignature].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfSignatureDigestAlgorithm {
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl PdfSignatureDigestAlgorithm {
+    pub(crate) fn from_oid(oid: &str) -> Result<Self, PdfiumError> {
+        match oid {
+            "1.3.14.3.2.26" => Ok(PdfSignatureDigestAlgorithm::Sha1),
+            "2.16.840.1.101.3.4.2.4" => Ok(PdfSignatureDigestAlgorithm::Sha224),
+            "2.16.840.1.101.3.4.2.1" => Ok(PdfSignatureDigestAlgorithm::Sha256),
+            "2.16.840.1.101.3.4.2.2" => Ok(PdfSignatureDigestAlgorithm::Sha384),
+            "2.16.840.1.101.3.4.2.3" => Ok(PdfSignatureDigestAlgorithm::Sha512),
+            _ => Err(PdfiumError::SignatureDigestAlgorithmNotSupported),
+        }
+    }
+
+    /// Computes the digest of the given byte slices, in order, using this algorithm.
+    fn digest(&self, slices: &[&[u8]]) -> Vec<u8> {
+        fn digest_with<D: Digest>(slices: &[&[u8]]) -> Vec<u8> {
+            let mut hasher = D::new();
+
+            for slice in slices {
+                hasher.update(slice);
+            }
+
+            hasher.finalize().to_vec()
+        }
+
+        match self {
+            PdfSignatureDigestAlgorithm::Sha1 => digest_with::<Sha1>(slices),
+            PdfSignatureDigestAlgorithm::Sha224 => digest_with::<Sha224>(slices),
+            PdfSignatureDigestAlgorithm::Sha256 => digest_with::<Sha256>(slices),
+            PdfSignatureDigestAlgorithm::Sha384 => digest_with::<Sha384>(slices),
+            PdfSignatureDigestAlgorithm::Sha512 => digest_with::<Sha512>(slices),
+        }
+    }
+}
+
+/// The result of checking the integrity of the byte ranges covered by a [PdfSignature],
+/// as returned by [PdfSignature::verify()].
+///
+/// Only the integrity of the signed data is checked. No attempt is made to validate the
+/// signer's certificate chain or the cryptographic signature over the digest itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfSignatureVerification {
+    sub_filter: Option<String>,
+    digest_algorithm: Option<PdfSignatureDigestAlgorithm>,
+    digest_matches: Option<bool>,
+    covers_whole_document: bool,
+}
+
+impl PdfSignatureVerification {
+    /// Returns the `/SubFilter` value of the signature dictionary, if any, describing the
+    /// encoding of the signature, for instance `adbe.pkcs7.detached` or `ETSI.CAdES.detached`.
+    #[inline]
+    pub fn sub_filter(&self) -> Option<&str> {
+        self.sub_filter.as_deref()
+    }
+
+    /// Returns the [PdfSignatureDigestAlgorithm] used to compute the digest of the signed
+    /// byte ranges, or `None` if the signature does not record a readable digest.
+    #[inline]
+    pub fn digest_algorithm(&self) -> Option<PdfSignatureDigestAlgorithm> {
+        self.digest_algorithm
+    }
+
+    /// Returns `Some(true)` if the digest computed over the signed byte ranges matches the
+    /// digest recorded in the signature. A value of `Some(false)` indicates that the signed
+    /// bytes have been altered since signing.
+    ///
+    /// Returns `None` if the signature does not record a digest that can be read without
+    /// decrypting the signature itself. This is the case for signatures encoded as raw PKCS#1
+    /// binaries, such as those using the `adbe.x509.rsa_sha1` sub-filter, and for PKCS#7
+    /// signatures without a message digest attribute.
+    #[inline]
+    pub fn digest_matches(&self) -> Option<bool> {
+        self.digest_matches
+    }
+
+    /// Returns `true` if the signed byte ranges cover the entire document, excepting only
+    /// the signature contents themselves.
+    ///
+    /// A value of `false` indicates that data, typically one or more incremental updates,
+    /// has been appended to the document after this signature was applied.
+    #[inline]
+    pub fn covers_whole_document(&self) -> bool {
+        self.covers_whole_document
+    }
+}
 
 /// A single digital signature in a `PdfDocument`.
 pub struct PdfSignature<'a> {
@@ -142,4 +250,402 @@ impl<'a> PdfSignature<'a> {
             None
         }
     }
+
+    /// Returns the `/SubFilter` value of this [PdfSignature], if any, describing the encoding
+    /// of the signature, for instance `adbe.pkcs7.detached` or `ETSI.CAdES.detached`.
+    pub fn sub_filter(&self) -> Option<String> {
+        // Retrieving the sub-filter from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetSubFilter() with a null buffer; this will retrieve the length of
+        // the sub-filter in bytes. If the length is zero, then there is no sub-filter.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFSignatureObj_GetSubFilter() again with a pointer to the buffer;
+        // this will write the sub-filter to the buffer as a null-terminated ASCII string.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetSubFilter(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no sub-filter given for this signature.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFSignatureObj_GetSubFilter(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        if let Ok(result) = CString::from_vec_with_nul(buffer) {
+            result.into_string().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `/ByteRange` of this [PdfSignature] as a list of `(offset, length)` pairs
+    /// describing the regions of the document covered by the signature.
+    fn byte_range(&self) -> Result<Vec<(usize, usize)>, PdfiumError> {
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetByteRange(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 || buffer_length % 2 != 0 {
+            return Err(PdfiumError::SignatureByteRangeInvalid);
+        }
+
+        let mut buffer = create_sized_buffer::<c_int>(buffer_length as usize);
+
+        let result = self.bindings().FPDFSignatureObj_GetByteRange(
+            self.handle,
+            buffer.as_mut_ptr(),
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        buffer
+            .chunks_exact(2)
+            .map(|pair| {
+                if pair[0] < 0 || pair[1] < 0 {
+                    Err(PdfiumError::SignatureByteRangeInvalid)
+                } else {
+                    Ok((pair[0] as usize, pair[1] as usize))
+                }
+            })
+            .collect()
+    }
+
+    /// Checks the integrity of the data covered by this [PdfSignature] by computing a digest
+    /// of the signed byte ranges of the given document data and comparing it against the
+    /// digest recorded in the PKCS#7 signature contents.
+    ///
+    /// Pdfium does not retain the raw bytes of a loaded document, so the caller must supply
+    /// the exact bytes the containing `PdfDocument` was loaded from. Bytes obtained from
+    /// `PdfDocument::save_to_bytes()` will not generally match, since saving re-serializes
+    /// the document.
+    ///
+    /// The returned [PdfSignatureVerification] reports whether the digest matches and whether
+    /// the signature covers the whole of the given data. In a document that has been signed
+    /// several times using incremental updates, typically only the most recent signature
+    /// covers the whole document; earlier signatures will report a matching digest but
+    /// will not cover the whole document.
+    ///
+    /// Signatures encoded as raw PKCS#1 binaries (such as those using the `adbe.x509.rsa_sha1`
+    /// sub-filter) do not record a digest that can be read without decrypting the signature
+    /// itself; for these signatures, only the coverage of the signed byte ranges is reported,
+    /// and [PdfSignatureVerification::digest_matches()] returns `None`.
+    pub fn verify(&self, document_bytes: &[u8]) -> Result<PdfSignatureVerification, PdfiumError> {
+        let byte_range = self.byte_range()?;
+
+        let mut signed_slices = Vec::with_capacity(byte_range.len());
+
+        for (offset, length) in byte_range.iter() {
+            let end = offset
+                .checked_add(*length)
+                .ok_or(PdfiumError::SignatureByteRangeInvalid)?;
+
+            signed_slices.push(
+                document_bytes
+                    .get(*offset..end)
+                    .ok_or(PdfiumError::SignatureByteRangeInvalid)?,
+            );
+        }
+
+        let (digest_algorithm, digest_matches) =
+            match Self::expected_digest_from_pkcs7(self.bytes().as_slice())? {
+                Some((digest_algorithm, expected_digest)) => (
+                    Some(digest_algorithm),
+                    Some(digest_algorithm.digest(signed_slices.as_slice()) == expected_digest),
+                ),
+                None => (None, None),
+            };
+
+        // The signature covers the whole document if the signed byte ranges start at the
+        // beginning of the data and finish at the end of it.
+
+        let covers_whole_document = byte_range.first().map(|(offset, _)| *offset) == Some(0)
+            && byte_range
+                .iter()
+                .map(|(offset, length)| offset + length)
+                .max()
+                == Some(document_bytes.len());
+
+        Ok(PdfSignatureVerification {
+            sub_filter: self.sub_filter(),
+            digest_algorithm,
+            digest_matches,
+            covers_whole_document,
+        })
+    }
+
+    /// Returns the X.509 certificates embedded in the PKCS#7 contents of this [PdfSignature],
+    /// in the order in which they are embedded.
+    ///
+    /// The embedded certificates typically include the signer's certificate and some or all
+    /// of the intermediate certificates in its chain, but no particular order is guaranteed.
+    /// An empty `Vec` is returned if the signature does not embed any certificates, or if
+    /// the signature is not a PKCS#7 signature. (For signatures using the `adbe.x509.rsa_sha1`
+    /// sub-filter, certificates are stored outside the signature contents, in the signature
+    /// dictionary's `/Cert` entry, which Pdfium does not expose.)
+    pub fn signing_certificates(&self) -> Result<Vec<PdfCertificate>, PdfiumError> {
+        let contents = self.bytes();
+
+        let signed_data = match Self::pkcs7_signed_data(contents.as_slice())? {
+            Some(signed_data) => signed_data,
+            None => return Ok(Vec::new()),
+        };
+
+        // The optional certificates field, if present, immediately follows the
+        // encapsulated content info as an implicitly tagged SET OF Certificate.
+
+        match signed_data
+            .get(3)
+            .filter(|element| element.is_context_specific(0))
+        {
+            Some(certificates) => Ok(certificates
+                .children()
+                .ok_or(PdfiumError::SignatureContentsMalformed)?
+                .iter()
+                .filter(|certificate| certificate.tag == TAG_SEQUENCE)
+                .map(|certificate| PdfCertificate::from_der(certificate.encoded.to_vec()))
+                .collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Locates the digest of the signed byte ranges recorded in the given PKCS#7 signature
+    /// contents, returning the digest together with the algorithm used to compute it, or
+    /// `None` if the contents do not record a digest.
+    fn expected_digest_from_pkcs7(
+        contents: &[u8],
+    ) -> Result<Option<(PdfSignatureDigestAlgorithm, Vec<u8>)>, PdfiumError> {
+        let malformed = || PdfiumError::SignatureContentsMalformed;
+
+        let signed_data = match Self::pkcs7_signed_data(contents)? {
+            Some(signed_data) => signed_data,
+            None => return Ok(None),
+        };
+
+        let encapsulated_content_info = signed_data
+            .get(2)
+            .filter(|element| element.tag == TAG_SEQUENCE)
+            .and_then(|element| element.children())
+            .ok_or_else(malformed)?;
+
+        if let Some(content) = encapsulated_content_info
+            .get(1)
+            .filter(|element| element.is_context_specific(0))
+        {
+            // The adbe.pkcs7.sha1 sub-filter embeds a SHA-1 digest of the signed byte ranges
+            // directly as the encapsulated content.
+
+            let content = Self::first_child(content)?;
+
+            if content.tag != TAG_OCTET_STRING {
+                return Err(malformed());
+            }
+
+            return Ok(Some((
+                PdfSignatureDigestAlgorithm::Sha1,
+                content.contents.to_vec(),
+            )));
+        }
+
+        // Otherwise the signature is detached, and the digest of the signed byte ranges is
+        // recorded in the message digest attribute of the signer.
+
+        let signer_info = signed_data
+            .last()
+            .filter(|element| element.tag == TAG_SET)
+            .and_then(|element| element.children())
+            .and_then(|signer_infos| signer_infos.first().and_then(|element| element.children()))
+            .ok_or_else(malformed)?;
+
+        // SignerInfo ::= SEQUENCE { version, sid, digestAlgorithm,
+        //     signedAttrs [0] IMPLICIT OPTIONAL, signatureAlgorithm, signature, ... }
+
+        let digest_algorithm = signer_info
+            .get(2)
+            .and_then(|element| element.children())
+            .and_then(|algorithm| algorithm.first().and_then(|element| element.as_oid()))
+            .ok_or_else(malformed)?;
+
+        let digest_algorithm = PdfSignatureDigestAlgorithm::from_oid(digest_algorithm.as_str())?;
+
+        let signed_attributes = match signer_info
+            .get(3)
+            .filter(|element| element.is_context_specific(0))
+            .and_then(|element| element.children())
+        {
+            Some(signed_attributes) => signed_attributes,
+            None => return Ok(None),
+        };
+
+        for attribute in signed_attributes {
+            let attribute = attribute.children().ok_or_else(malformed)?;
+
+            if attribute
+                .first()
+                .filter(|element| element.tag == TAG_OBJECT_IDENTIFIER)
+                .and_then(|element| element.as_oid())
+                .as_deref()
+                == Some(OID_PKCS9_MESSAGE_DIGEST)
+            {
+                let digest = Self::first_child(attribute.get(1).ok_or_else(malformed)?)?;
+
+                if digest.tag != TAG_OCTET_STRING {
+                    return Err(malformed());
+                }
+
+                return Ok(Some((digest_algorithm, digest.contents.to_vec())));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the elements of the PKCS#7 SignedData structure in the given signature contents,
+    /// or `None` if the contents are not a PKCS#7 SignedData structure.
+    ///
+    /// The elements are, in order: version, digestAlgorithms, encapContentInfo,
+    /// certificates (optional), crls (optional), and signerInfos.
+    fn pkcs7_signed_data(contents: &[u8]) -> Result<Option<Vec<DerElement>>, PdfiumError> {
+        // The signature contents may be padded with trailing zero bytes, so we read only
+        // the first element and ignore anything remaining. Contents that do not begin with
+        // a PKCS#7 ContentInfo structure are most likely a raw PKCS#1 signature.
+
+        let content_info = match read_element(contents)
+            .filter(|(element, _)| element.tag == TAG_SEQUENCE)
+            .and_then(|(element, _)| element.children())
+            .filter(|children| {
+                children
+                    .first()
+                    .and_then(|element| element.as_oid())
+                    .as_deref()
+                    == Some(OID_PKCS7_SIGNED_DATA)
+            }) {
+            Some(content_info) => content_info,
+            None => return Ok(None),
+        };
+
+        let signed_data = Self::first_child(
+            content_info
+                .get(1)
+                .ok_or(PdfiumError::SignatureContentsMalformed)?,
+        )?;
+
+        signed_data
+            .children()
+            .filter(|_| signed_data.tag == TAG_SEQUENCE)
+            .map(Some)
+            .ok_or(PdfiumError::SignatureContentsMalformed)
+    }
+
+    /// Returns the first child of the given constructed DER element.
+    #[inline]
+    fn first_child<'b>(element: &DerElement<'b>) -> Result<DerElement<'b>, PdfiumError> {
+        read_element(element.contents)
+            .map(|(child, _)| child)
+            .ok_or(PdfiumError::SignatureContentsMalformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_verify_multiple_signatures() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The sample file was signed three times using incremental updates, so only the
+        // most recent signature covers the whole file.
+
+        let bytes = std::fs::read("./test/signatures-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        let verifications = document
+            .signatures()
+            .iter()
+            .map(|signature| signature.verify(&bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            verifications
+                .iter()
+                .map(|verification| verification.covers_whole_document())
+                .collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+
+        // Raw PKCS#1 signatures do not record a digest that can be compared.
+
+        for verification in verifications.iter() {
+            assert_eq!(verification.sub_filter(), Some("adbe.x509.rsa_sha1"));
+            assert_eq!(verification.digest_algorithm(), None);
+            assert_eq!(verification.digest_matches(), None);
+        }
+
+        // Byte ranges extending beyond the given data should be rejected.
+
+        let signature = document.signatures().get(2)?;
+
+        assert!(matches!(
+            signature.verify(&bytes[..bytes.len() - 1]),
+            Err(PdfiumError::SignatureByteRangeInvalid)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut bytes =
+            std::fs::read("./test/signatures-pkcs7-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let verification = {
+            let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+            let signature = document.signatures().get(0)?;
+
+            signature.verify(&bytes)?
+        };
+
+        assert_eq!(verification.sub_filter(), Some("adbe.pkcs7.detached"));
+        assert_eq!(
+            verification.digest_algorithm(),
+            Some(PdfSignatureDigestAlgorithm::Sha256)
+        );
+        assert_eq!(verification.digest_matches(), Some(true));
+        assert!(verification.covers_whole_document());
+
+        // Altering a single byte of the signed page content should be detected.
+
+        let offset = bytes
+            .windows(b"Signed document".len())
+            .position(|window| window == b"Signed document")
+            .unwrap();
+
+        bytes[offset] = b'Z';
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        let verification = document.signatures().get(0)?.verify(&bytes)?;
+
+        assert_eq!(verification.digest_matches(), Some(false));
+        assert!(verification.covers_whole_document());
+
+        Ok(())
+    }
 }
@@ -1,9 +1,10 @@
 //! Defines the [PdfSignatures] struct, a collection of all the `PdfSignature` objects in a
 //! `PdfDocument`.
 
-use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_FORMHANDLE};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::form_field::{PdfFormField, PdfFormFieldCommon, PdfFormFieldType};
 use crate::signature::PdfSignature;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::c_int;
@@ -13,6 +14,7 @@ pub type PdfSignatureIndex = u16;
 /// The collection of [PdfSignature] objects inside a `PdfDocument`.
 pub struct PdfSignatures<'a> {
     document_handle: FPDF_DOCUMENT,
+    form_handle: Option<FPDF_FORMHANDLE>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -20,10 +22,12 @@ impl<'a> PdfSignatures<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         document_handle: FPDF_DOCUMENT,
+        form_handle: Option<FPDF_FORMHANDLE>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfSignatures {
             document_handle,
+            form_handle,
             bindings,
         }
     }
@@ -81,6 +85,79 @@ impl<'a> PdfSignatures<'a> {
         }
     }
 
+    /// Returns the [PdfSignature] applied to the signature form field with the given name.
+    ///
+    /// Matching against the field name is case-sensitive. If no signature field with the
+    /// given name exists, [PdfiumError::SignatureNotFound] is returned.
+    ///
+    /// Pdfium does not directly expose the form field associated with each signature.
+    /// Pdfium lists signatures in the order their fields appear in the document's form,
+    /// so this function locates the named field among the signature fields on every page,
+    /// taken in page order, and returns the signature at the same position. If the number of
+    /// signature fields on the document's pages differs from the number of signatures,
+    /// the correspondence cannot be established and [PdfiumError::SignatureNotFound]
+    /// is returned.
+    pub fn get_by_field_name(&self, name: &str) -> Result<PdfSignature<'a>, PdfiumError> {
+        let field_names = self.signature_field_names();
+
+        if field_names.len() != self.len() as usize {
+            return Err(PdfiumError::SignatureNotFound);
+        }
+
+        match field_names.iter().position(|field_name| field_name == name) {
+            Some(index) => self.get(index as PdfSignatureIndex),
+            None => Err(PdfiumError::SignatureNotFound),
+        }
+    }
+
+    /// Returns the names of all the signature fields attached to widget annotations on
+    /// every page in the containing `PdfDocument`, in page order. A field with more than one
+    /// widget annotation is listed only once.
+    fn signature_field_names(&self) -> Vec<String> {
+        let mut result: Vec<String> = Vec::new();
+
+        let form_handle = match self.form_handle {
+            Some(form_handle) => form_handle,
+            None => return result,
+        };
+
+        let bindings = self.bindings();
+
+        for page_index in 0..bindings.FPDF_GetPageCount(self.document_handle) {
+            let page_handle = bindings.FPDF_LoadPage(self.document_handle, page_index);
+
+            if page_handle.is_null() {
+                continue;
+            }
+
+            for index in 0..bindings.FPDFPage_GetAnnotCount(page_handle) {
+                let annotation_handle = bindings.FPDFPage_GetAnnot(page_handle, index);
+
+                if annotation_handle.is_null() {
+                    continue;
+                }
+
+                if let Some(field) =
+                    PdfFormField::from_pdfium(form_handle, annotation_handle, bindings)
+                {
+                    if field.field_type() == PdfFormFieldType::Signature {
+                        if let Some(field_name) = field.name() {
+                            if !result.contains(&field_name) {
+                                result.push(field_name);
+                            }
+                        }
+                    }
+                }
+
+                bindings.FPDFPage_CloseAnnot(annotation_handle);
+            }
+
+            bindings.FPDF_ClosePage(page_handle);
+        }
+
+        result
+    }
+
     /// Returns an iterator over all the signatures in this [PdfSignatures] collection.
     #[inline]
     pub fn iter(&self) -> PdfSignaturesIterator {
@@ -115,3 +192,56 @@ impl<'a> Iterator for PdfSignaturesIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_get_by_field_name() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = std::fs::read("./test/signatures-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        let signatures = document.signatures();
+
+        // The second and third signatures share the same signing date and reason, so they
+        // can only be told apart by their field names. Only the third signature covers
+        // the whole file.
+
+        let signature = signatures.get_by_field_name("Alice-3-1014426354")?;
+
+        assert!(signature.verify(&bytes)?.covers_whole_document());
+        assert_eq!(signature.bytes(), signatures.get(2)?.bytes());
+
+        let signature = signatures.get_by_field_name("Alice-2-1074011529")?;
+
+        assert!(!signature.verify(&bytes)?.covers_whole_document());
+        assert_eq!(signature.bytes(), signatures.get(1)?.bytes());
+
+        assert!(matches!(
+            signatures.get_by_field_name("alice-1-1016955823"),
+            Err(PdfiumError::SignatureNotFound)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_by_field_name_single_signature() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/signatures-pkcs7-test.pdf", None)?;
+
+        let signature = document
+            .signatures()
+            .get_by_field_name("ApprovalSignature1")?;
+
+        assert_eq!(signature.reason(), Some("Approval".to_owned()));
+
+        Ok(())
+    }
+}
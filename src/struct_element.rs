@@ -0,0 +1,218 @@
+//! Defines the [PdfStructElement] struct, exposing functionality related to a single
+//! element in a `PdfStructTree`.
+
+use crate::bindgen::FPDF_STRUCTELEMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+#[cfg(doc)]
+use crate::struct_tree::PdfStructTree;
+
+/// The zero-based index of a single [PdfStructElement] amongst its siblings.
+pub type PdfStructElementIndex = usize;
+
+/// A single element in a [PdfStructTree], such as a heading, paragraph, table, or figure.
+///
+/// Pdfium does not expose an element's `/ActualText` entry, so the replacement text of
+/// an element is not available.
+pub struct PdfStructElement<'a> {
+    handle: FPDF_STRUCTELEMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTELEMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructElement].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the structure type of this [PdfStructElement], such as `H1`, `P`, or `Table`.
+    ///
+    /// Documents may define custom structure types; these are returned as-is, without being
+    /// mapped to a standard structure type.
+    pub fn element_type(&self) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetType(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the title of this [PdfStructElement], if any.
+    pub fn title(&self) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetTitle(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the alternate description of this [PdfStructElement], if any. Alternate
+    /// descriptions are typically set on figures and formulae.
+    pub fn alt_text(&self) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetAltText(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the element identifier of this [PdfStructElement], if any.
+    pub fn id(&self) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetID(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the natural language of the content of this [PdfStructElement], if specified.
+    pub fn language(&self) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetLang(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the value of the given string attribute of this [PdfStructElement], if set.
+    pub fn string_attribute(&self, name: &str) -> Option<String> {
+        self.get_string(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetStringAttribute(self.handle, name, buffer, length)
+        })
+    }
+
+    /// Returns the marked content ID linking this [PdfStructElement] to content on the page,
+    /// if any.
+    ///
+    /// Pdfium only reports a marked content ID for elements whose content is a single
+    /// marked content sequence. `None` is returned for elements linked to several marked
+    /// content sequences, and for elements whose content is entirely made up of child elements.
+    pub fn marked_content_id(&self) -> Option<i32> {
+        match self
+            .bindings
+            .FPDF_StructElement_GetMarkedContentID(self.handle)
+        {
+            -1 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Returns the number of children of this [PdfStructElement].
+    ///
+    /// The count includes children that are marked content or object references, rather than
+    /// structure elements; these cannot be retrieved using [PdfStructElement::get()].
+    #[inline]
+    pub fn len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructElement_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns `true` if this [PdfStructElement] has no children.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a single child element of this [PdfStructElement].
+    pub fn get(&self, index: PdfStructElementIndex) -> Result<PdfStructElement<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructElement_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            // The child at this index is a marked content or object reference,
+            // rather than a structure element.
+
+            Err(PdfiumError::StructElementIndexOutOfBounds)
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over the child elements of this [PdfStructElement],
+    /// in reading order.
+    #[inline]
+    pub fn children(&self) -> PdfStructElementChildrenIterator<'a> {
+        PdfStructElementChildrenIterator::new(PdfStructElement::from_pdfium(
+            self.handle,
+            self.bindings,
+        ))
+    }
+
+    /// Retrieves a string value from this [PdfStructElement] using the given
+    /// Pdfium string accessor function.
+    fn get_string(&self, accessor: impl Fn(*mut c_void, c_ulong) -> c_ulong) -> Option<String> {
+        // Retrieving a string value from Pdfium is a two-step operation. First, we call
+        // the accessor with a null buffer; this will retrieve the length of the value
+        // in bytes. If the length is zero, then the value is not set.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the accessor again with a pointer to the buffer;
+        // this will write the value to the buffer in UTF16-LE format.
+
+        let buffer_length = accessor(std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = accessor(buffer.as_mut_ptr() as *mut c_void, buffer_length);
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+/// An iterator over the child elements of a single [PdfStructElement].
+///
+/// Children that are marked content or object references, rather than structure elements,
+/// are skipped.
+pub struct PdfStructElementChildrenIterator<'a> {
+    parent: PdfStructElement<'a>,
+    next_index: PdfStructElementIndex,
+}
+
+impl<'a> PdfStructElementChildrenIterator<'a> {
+    #[inline]
+    pub(crate) fn new(parent: PdfStructElement<'a>) -> Self {
+        PdfStructElementChildrenIterator {
+            parent,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructElementChildrenIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.parent.len() {
+            let next = self.parent.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Ok(next) = next {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,127 @@
+//! Defines the [PdfStructTree] struct, exposing functionality related to the tagged
+//! structure tree of a single `PdfPage`.
+
+use crate::bindgen::FPDF_STRUCTTREE;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::struct_element::{PdfStructElement, PdfStructElementIndex};
+use std::os::raw::c_int;
+
+#[cfg(doc)]
+use crate::page::PdfPage;
+
+/// The tagged structure tree of a single [PdfPage].
+///
+/// The structure tree of a tagged PDF records the logical structure of the page's content,
+/// such as headings, paragraphs, tables, and figures, together with the intended reading order.
+/// Use the [PdfPage::structure_tree()] function to retrieve the structure tree for a page.
+///
+/// Only the elements of the document's structure tree that contain content on the page
+/// are included, along with their ancestors.
+pub struct PdfStructTree<'a> {
+    handle: FPDF_STRUCTTREE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructTree<'a> {
+    /// Creates a new [PdfStructTree] from the given handle, returning
+    /// [PdfiumError::NoStructureTree] if the handle is null or the tree is empty.
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTTREE,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        if handle.is_null() {
+            return Err(PdfiumError::NoStructureTree);
+        }
+
+        let tree = PdfStructTree { handle, bindings };
+
+        if tree.is_empty() {
+            // Dropping the tree will close the handle.
+
+            Err(PdfiumError::NoStructureTree)
+        } else {
+            Ok(tree)
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructTree].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of top-level elements in this [PdfStructTree].
+    #[inline]
+    pub fn len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructTree_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns `true` if this [PdfStructTree] has no top-level elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a single top-level [PdfStructElement] from this [PdfStructTree].
+    pub fn get(&self, index: PdfStructElementIndex) -> Result<PdfStructElement, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructTree_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::StructElementIndexOutOfBounds)
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over the top-level elements in this [PdfStructTree],
+    /// in reading order.
+    #[inline]
+    pub fn iter(&self) -> PdfStructTreeIterator {
+        PdfStructTreeIterator::new(self)
+    }
+}
+
+impl<'a> Drop for PdfStructTree<'a> {
+    /// Closes this [PdfStructTree], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_StructTree_Close(self.handle);
+    }
+}
+
+/// An iterator over the top-level [PdfStructElement] objects in a [PdfStructTree].
+pub struct PdfStructTreeIterator<'a> {
+    tree: &'a PdfStructTree<'a>,
+    next_index: PdfStructElementIndex,
+}
+
+impl<'a> PdfStructTreeIterator<'a> {
+    #[inline]
+    pub(crate) fn new(tree: &'a PdfStructTree<'a>) -> Self {
+        PdfStructTreeIterator {
+            tree,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructTreeIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.tree.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
@@ -10,15 +10,20 @@ use crate::bindgen::{
     FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
     FPDF_CLIPPATH, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD, FPDF_FILEACCESS,
     FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO, FPDF_FORMHANDLE, FPDF_GLYPHPATH,
-    FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE, FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT,
-    FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE, FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE,
-    FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR,
-    FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF,
+    FPDF_IMAGEOBJ_METADATA, FPDF_JAVASCRIPT_ACTION, FPDF_LINK, FPDF_OBJECT_TYPE, FPDF_PAGE,
+    FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE, FPDF_PATHSEGMENT,
+    FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE,
+    FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF,
+    FS_QUADPOINTSF, FS_RECTF, IFSDK_PAUSE,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use crate::bindgen::{FPDF_AVAIL, FX_DOWNLOADHINTS, FX_FILEAVAIL};
 use crate::bindings::PdfiumLibraryBindings;
 use once_cell::sync::Lazy;
 use std::cell::RefCell;
-use std::os::raw::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_ulong, c_ushort, c_void};
+use std::os::raw::{
+    c_char, c_double, c_float, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void,
+};
 use std::sync::{Mutex, MutexGuard};
 
 static PDFIUM_THREAD_MARSHALL: Lazy<Mutex<PdfiumThreadMarshall>> =
@@ -134,6 +139,65 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         self.bindings.FPDF_LoadCustomDocument(pFileAccess, password)
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_Create(
+        &self,
+        file_avail: *mut FX_FILEAVAIL,
+        file: *mut FPDF_FILEACCESS,
+    ) -> FPDF_AVAIL {
+        self.bindings.FPDFAvail_Create(file_avail, file)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_Destroy(&self, avail: FPDF_AVAIL) {
+        self.bindings.FPDFAvail_Destroy(avail)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsDocAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
+        self.bindings.FPDFAvail_IsDocAvail(avail, hints)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_GetDocument(&self, avail: FPDF_AVAIL, password: Option<&str>) -> FPDF_DOCUMENT {
+        self.bindings.FPDFAvail_GetDocument(avail, password)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_GetFirstPageNum(&self, doc: FPDF_DOCUMENT) -> c_int {
+        self.bindings.FPDFAvail_GetFirstPageNum(doc)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsPageAvail(
+        &self,
+        avail: FPDF_AVAIL,
+        page_index: c_int,
+        hints: *mut FX_DOWNLOADHINTS,
+    ) -> c_int {
+        self.bindings
+            .FPDFAvail_IsPageAvail(avail, page_index, hints)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAvail_IsLinearized(&self, avail: FPDF_AVAIL) -> c_int {
+        self.bindings.FPDFAvail_IsLinearized(avail)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SaveAsCopy(
@@ -959,6 +1023,37 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
             .FPDF_RenderPageBitmapWithMatrix(bitmap, page, matrix, clipping, flags)
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmap_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        self.bindings.FPDF_RenderPageBitmap_Start(
+            bitmap, page, start_x, start_y, size_x, size_y, rotate, flags, pause,
+        )
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Continue(&self, page: FPDF_PAGE, pause: *mut IFSDK_PAUSE) -> c_int {
+        self.bindings.FPDF_RenderPage_Continue(page, pause)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        self.bindings.FPDF_RenderPage_Close(page)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
@@ -1498,6 +1593,22 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         self.bindings.FPDFAnnot_SetDest(annot, page_dest, x, y, z)
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_GetFileAttachment(&self, annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT {
+        self.bindings.FPDFAnnot_GetFileAttachment(annot)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_AddFileAttachment(
+        &self,
+        annot: FPDF_ANNOTATION,
+        name: FPDF_WIDESTRING,
+    ) -> FPDF_ATTACHMENT {
+        self.bindings.FPDFAnnot_AddFileAttachment(annot, name)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDOC_InitFormFillEnvironment(
@@ -1545,6 +1656,12 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         self.bindings.FPDF_SetFormFieldHighlightAlpha(handle, alpha)
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_RemoveFormFieldHighlight(&self, handle: FPDF_FORMHANDLE) {
+        self.bindings.FPDF_RemoveFormFieldHighlight(handle)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_FFLDraw(
@@ -3041,6 +3158,31 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
             .FPDF_VIEWERREF_GetName(document, key, buffer, length)
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_CountNamedDests(&self, document: FPDF_DOCUMENT) -> FPDF_DWORD {
+        self.bindings.FPDF_CountNamedDests(document)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDestByName(&self, document: FPDF_DOCUMENT, name: &str) -> FPDF_DEST {
+        self.bindings.FPDF_GetNamedDestByName(document, name)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDest(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: *mut c_long,
+    ) -> FPDF_DEST {
+        self.bindings
+            .FPDF_GetNamedDest(document, index, buffer, buflen)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetAttachmentCount(&self, document: FPDF_DOCUMENT) -> c_int {
@@ -3147,4 +3289,62 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         self.bindings
             .FPDFAttachment_GetFile(attachment, buffer, buflen, out_buflen)
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFAttachment_GetSubtype(
+        &self,
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        self.bindings
+            .FPDFAttachment_GetSubtype(attachment, buffer, buflen)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptActionCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        self.bindings.FPDFDoc_GetJavaScriptActionCount(document)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptAction(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+    ) -> FPDF_JAVASCRIPT_ACTION {
+        self.bindings.FPDFDoc_GetJavaScriptAction(document, index)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFDoc_CloseJavaScriptAction(&self, javascript: FPDF_JAVASCRIPT_ACTION) {
+        self.bindings.FPDFDoc_CloseJavaScriptAction(javascript)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetName(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        self.bindings
+            .FPDFJavaScriptAction_GetName(javascript, buffer, buflen)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetScript(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        self.bindings
+            .FPDFJavaScriptAction_GetScript(javascript, buffer, buflen)
+    }
 }
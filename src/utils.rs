@@ -83,6 +83,73 @@ pub(crate) mod pixels {
             .collect::<Vec<_>>()
     }
 
+    /// Converts the given byte array, containing pixel data encoded as four-channel BGRA with
+    /// zero or more empty alignment bytes at the end of each scanline, into pixel data encoded
+    /// as four-channel RGBA. The number of alignment bytes to be skipped is determined from
+    /// the given width and stride parameters.
+    #[inline]
+    pub(crate) fn aligned_bgra_to_rgba(bgra: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        bgra.chunks_exact(stride)
+            .flat_map(|scanline| {
+                scanline[..width * BYTES_PER_FOUR_CHANNEL_PIXEL]
+                    .chunks_exact(BYTES_PER_FOUR_CHANNEL_PIXEL)
+            })
+            .flat_map(|channels| [channels[2], channels[1], channels[0], channels[3]])
+            .collect::<Vec<_>>()
+    }
+
+    /// Converts the given byte array, containing pixel data encoded as four-channel BGRx with
+    /// zero or more empty alignment bytes at the end of each scanline, into pixel data encoded
+    /// as four-channel RGBA. The unused fourth channel is replaced with an alpha channel
+    /// of full opacity.
+    #[inline]
+    pub(crate) fn aligned_bgrx_to_rgba(bgrx: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        bgrx.chunks_exact(stride)
+            .flat_map(|scanline| {
+                scanline[..width * BYTES_PER_FOUR_CHANNEL_PIXEL]
+                    .chunks_exact(BYTES_PER_FOUR_CHANNEL_PIXEL)
+            })
+            .flat_map(|channels| [channels[2], channels[1], channels[0], 255])
+            .collect::<Vec<_>>()
+    }
+
+    /// Converts the given byte array, containing pixel data encoded as four-channel RGBA with
+    /// zero or more empty alignment bytes at the end of each scanline, into tightly-packed
+    /// four-channel RGBA pixel data.
+    #[inline]
+    pub(crate) fn aligned_rgba_to_rgba(rgba: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        rgba.chunks_exact(stride)
+            .flat_map(|scanline| &scanline[..width * BYTES_PER_FOUR_CHANNEL_PIXEL])
+            .copied()
+            .collect::<Vec<_>>()
+    }
+
+    /// Converts the given byte array, containing pixel data encoded as four-channel RGBx with
+    /// zero or more empty alignment bytes at the end of each scanline, into pixel data encoded
+    /// as four-channel RGBA. The unused fourth channel is replaced with an alpha channel
+    /// of full opacity.
+    #[inline]
+    pub(crate) fn aligned_rgbx_to_rgba(rgbx: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        rgbx.chunks_exact(stride)
+            .flat_map(|scanline| {
+                scanline[..width * BYTES_PER_FOUR_CHANNEL_PIXEL]
+                    .chunks_exact(BYTES_PER_FOUR_CHANNEL_PIXEL)
+            })
+            .flat_map(|channels| [channels[0], channels[1], channels[2], 255])
+            .collect::<Vec<_>>()
+    }
+
+    /// Converts the given byte array, containing single-channel grayscale pixel data with
+    /// zero or more empty alignment bytes at the end of each scanline, into tightly-packed
+    /// single-channel grayscale pixel data.
+    #[inline]
+    pub(crate) fn aligned_gray_to_gray(gray: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        gray.chunks_exact(stride)
+            .flat_map(|scanline| &scanline[..width])
+            .copied()
+            .collect::<Vec<_>>()
+    }
+
     /// Converts the given byte array, containing pixel data encoded as three-channel RGB,
     /// into pixel data encoded as four-channel BGRA. A new alpha channel is created with full opacity.
     #[inline]
@@ -134,6 +201,7 @@ pub(crate) mod pixels {
 }
 
 pub(crate) mod dates {
+    use crate::error::PdfiumError;
     use chrono::prelude::*;
     use std::fmt::Display;
 
@@ -153,6 +221,111 @@ pub(crate) mod dates {
 
         format!("D:{}{}", date_part, timezone_part)
     }
+
+    /// Parses a formatted PDF date string, as defined in The PDF Reference Manual, sixth edition,
+    /// section 3.8.3, on page 160, into a [DateTime].
+    ///
+    /// All fields after the year are optional, as is the leading `D:` prefix. Omitted fields
+    /// take their default values: January 1st, midnight, UTC. Returns
+    /// [PdfiumError::ParsePdfDateStringError] if the string is not a valid PDF date string.
+    pub(crate) fn pdf_string_to_date_time(
+        value: &str,
+    ) -> Result<DateTime<FixedOffset>, PdfiumError> {
+        let value = value.strip_prefix("D:").unwrap_or(value);
+
+        let bytes = value.as_bytes();
+
+        let mut position = 0;
+
+        // Reads the next field of the given number of digits, if present.
+
+        let mut read_field = |digits: usize| -> Result<Option<u32>, PdfiumError> {
+            match bytes.get(position) {
+                Some(byte) if byte.is_ascii_digit() => {
+                    let field = bytes
+                        .get(position..position + digits)
+                        .filter(|field| field.iter().all(u8::is_ascii_digit))
+                        .ok_or(PdfiumError::ParsePdfDateStringError)?;
+
+                    position += digits;
+
+                    // The field consists only of ASCII digits, so it is valid UTF-8 and
+                    // will always parse successfully.
+
+                    Ok(std::str::from_utf8(field)
+                        .ok()
+                        .and_then(|field| field.parse().ok()))
+                }
+                _ => Ok(None),
+            }
+        };
+
+        let year = read_field(4)?.ok_or(PdfiumError::ParsePdfDateStringError)?;
+        let month = read_field(2)?.unwrap_or(1);
+        let day = read_field(2)?.unwrap_or(1);
+        let hour = read_field(2)?.unwrap_or(0);
+        let minute = read_field(2)?.unwrap_or(0);
+        let second = read_field(2)?.unwrap_or(0);
+
+        let offset = match &value[position..] {
+            "" => 0,
+            timezone => {
+                let (sign, timezone) = if let Some(rest) = timezone.strip_prefix(&['Z', '+'][..]) {
+                    (1, rest)
+                } else if let Some(rest) = timezone.strip_prefix('-') {
+                    (-1, rest)
+                } else {
+                    return Err(PdfiumError::ParsePdfDateStringError);
+                };
+
+                // The offset is given as HH'mm', where the minutes and apostrophes are optional.
+
+                let mut parts = timezone.split('\'');
+
+                let hours = parts.next().unwrap_or("");
+                let minutes = parts.next().unwrap_or("");
+
+                if parts.any(|part| !part.is_empty()) {
+                    return Err(PdfiumError::ParsePdfDateStringError);
+                }
+
+                let parse_part = |part: &str| -> Result<i32, PdfiumError> {
+                    match part.len() {
+                        0 => Ok(0),
+                        2 if part.bytes().all(|byte| byte.is_ascii_digit()) => part
+                            .parse()
+                            .map_err(|_| PdfiumError::ParsePdfDateStringError),
+                        _ => Err(PdfiumError::ParsePdfDateStringError),
+                    }
+                };
+
+                sign * (parse_part(hours)? * 3600 + parse_part(minutes)? * 60)
+            }
+        };
+
+        FixedOffset::east_opt(offset)
+            .and_then(|offset| {
+                offset
+                    .with_ymd_and_hms(year as i32, month, day, hour, minute, second)
+                    .single()
+            })
+            .ok_or(PdfiumError::ParsePdfDateStringError)
+    }
+}
+
+pub(crate) mod content {
+    /// Formats the given value as a PDF number, omitting any unnecessary trailing zeros.
+    pub(crate) fn format_pdf_number(value: f32) -> String {
+        let formatted = format!("{:.3}", value);
+
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+
+        if trimmed == "-0" {
+            "0".to_owned()
+        } else {
+            trimmed.to_owned()
+        }
+    }
 }
 
 pub(crate) mod mem {
@@ -236,7 +409,10 @@ pub(crate) mod utf16le {
 }
 
 pub(crate) mod files {
-    use crate::bindgen::{FPDF_FILEACCESS, FPDF_FILEWRITE};
+    use crate::bindgen::{
+        FPDF_BOOL, FPDF_FILEACCESS, FPDF_FILEWRITE, FX_DOWNLOADHINTS, FX_FILEAVAIL,
+    };
+    use crate::data_availability::PdfDataAvailability;
     use std::io::{Read, Seek, SeekFrom, Write};
     use std::ops::Deref;
     use std::os::raw::{c_int, c_uchar, c_ulong, c_void};
@@ -359,6 +535,158 @@ pub(crate) mod files {
         }
     }
 
+    // Progressive loading through Pdfium's FPDFAvail_*() functions needs three callback
+    // structs: an FX_FILEAVAIL that reports whether a byte range has arrived, an
+    // FX_DOWNLOADHINTS that reports the byte ranges Pdfium is waiting for, and an
+    // FPDF_FILEACCESS that reads data. All three are connected to the same user-provided
+    // PdfDataAvailability source. As with the reader above, the source is used throughout
+    // the lifetime of the document, so the combined struct must be boxed and bound to the
+    // lifetime of the PdfDocument we create.
+
+    /// Returns a wrapped set of Pdfium `FX_FILEAVAIL`, `FX_DOWNLOADHINTS`, and `FPDF_FILEACCESS`
+    /// structs that use the given [PdfDataAvailability] source for Pdfium's progressive
+    /// loading callback functions.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // This function is never used when compiling to WASM.
+    pub(crate) fn get_pdfium_data_availability_from_source<'a, S: PdfDataAvailability + 'a>(
+        source: S,
+    ) -> Box<FpdfDataAvailabilityExt<'a>> {
+        let mut result = Box::new(FpdfDataAvailabilityExt {
+            file_avail: FpdfFileAvailExt {
+                file_avail: FX_FILEAVAIL {
+                    version: 1,
+                    IsDataAvail: Some(is_data_avail_from_callback),
+                },
+                data_availability_ptr: null_mut(), // We'll set this value in just a moment.
+            },
+            download_hints: FpdfDownloadHintsExt {
+                download_hints: FX_DOWNLOADHINTS {
+                    version: 1,
+                    AddSegment: Some(add_segment_from_callback),
+                },
+                data_availability_ptr: null_mut(), // We'll set this value in just a moment.
+            },
+            file_access: FPDF_FILEACCESS {
+                m_FileLen: source.content_length() as c_ulong,
+                m_GetBlock: Some(read_available_block_from_callback),
+                m_Param: null_mut(), // We'll set this value in just a moment.
+            },
+            source: Box::new(source),
+        });
+
+        // Update the struct with pointers to its own memory location. These pointers will
+        // be passed to the callback functions that Pdfium invokes, allowing those callbacks to
+        // retrieve this FpdfDataAvailabilityExt struct and, from there, the boxed Rust source.
+
+        let data_availability_ptr: *mut FpdfDataAvailabilityExt = result.as_mut();
+
+        result.file_avail.data_availability_ptr = data_availability_ptr;
+        result.download_hints.data_availability_ptr = data_availability_ptr;
+        result.file_access.m_Param = data_availability_ptr as *mut c_void;
+
+        result
+    }
+
+    #[repr(C)]
+    pub(crate) struct FpdfFileAvailExt<'a> {
+        // An extension of Pdfium's FX_FILEAVAIL struct that adds an extra field to carry a
+        // pointer back to the containing FpdfDataAvailabilityExt struct.
+        file_avail: FX_FILEAVAIL,
+        data_availability_ptr: *mut FpdfDataAvailabilityExt<'a>,
+    }
+
+    #[repr(C)]
+    pub(crate) struct FpdfDownloadHintsExt<'a> {
+        // An extension of Pdfium's FX_DOWNLOADHINTS struct that adds an extra field to carry a
+        // pointer back to the containing FpdfDataAvailabilityExt struct.
+        download_hints: FX_DOWNLOADHINTS,
+        data_availability_ptr: *mut FpdfDataAvailabilityExt<'a>,
+    }
+
+    pub(crate) struct FpdfDataAvailabilityExt<'a> {
+        file_avail: FpdfFileAvailExt<'a>,
+        download_hints: FpdfDownloadHintsExt<'a>,
+        file_access: FPDF_FILEACCESS,
+        source: Box<dyn PdfDataAvailability + 'a>,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    // These functions are never used when compiling to WASM.
+    impl<'a> FpdfDataAvailabilityExt<'a> {
+        /// Returns an `FX_FILEAVAIL` pointer suitable for passing to `FPDFAvail_Create()`.
+        #[inline]
+        pub(crate) fn as_fx_file_avail_mut_ptr(&mut self) -> *mut FX_FILEAVAIL {
+            &mut self.file_avail as *mut FpdfFileAvailExt as *mut FX_FILEAVAIL
+        }
+
+        /// Returns an `FPDF_FILEACCESS` pointer suitable for passing to `FPDFAvail_Create()`.
+        #[inline]
+        pub(crate) fn as_fpdf_file_access_mut_ptr(&mut self) -> *mut FPDF_FILEACCESS {
+            &mut self.file_access
+        }
+
+        /// Returns an `FX_DOWNLOADHINTS` pointer suitable for passing to `FPDFAvail_IsDocAvail()`
+        /// and `FPDFAvail_IsPageAvail()`.
+        #[inline]
+        pub(crate) fn as_fx_download_hints_mut_ptr(&mut self) -> *mut FX_DOWNLOADHINTS {
+            &mut self.download_hints as *mut FpdfDownloadHintsExt as *mut FX_DOWNLOADHINTS
+        }
+    }
+
+    // The callback functions invoked by Pdfium.
+
+    pub(crate) extern "C" fn is_data_avail_from_callback(
+        file_avail_ptr: *mut FX_FILEAVAIL,
+        offset: usize,
+        size: usize,
+    ) -> FPDF_BOOL {
+        let is_available = unsafe {
+            let data_availability_ptr =
+                (*(file_avail_ptr as *mut FpdfFileAvailExt)).data_availability_ptr;
+
+            (*data_availability_ptr)
+                .source
+                .is_data_available(offset as u64, size)
+        };
+
+        is_available as FPDF_BOOL
+    }
+
+    pub(crate) extern "C" fn add_segment_from_callback(
+        download_hints_ptr: *mut FX_DOWNLOADHINTS,
+        offset: usize,
+        size: usize,
+    ) {
+        unsafe {
+            let data_availability_ptr =
+                (*(download_hints_ptr as *mut FpdfDownloadHintsExt)).data_availability_ptr;
+
+            (*data_availability_ptr)
+                .source
+                .request_data(offset as u64, size);
+        }
+    }
+
+    pub(crate) extern "C" fn read_available_block_from_callback(
+        data_availability_ptr: *mut c_void,
+        position: c_ulong,
+        buf: *mut c_uchar,
+        size: c_ulong,
+    ) -> c_int {
+        #[allow(clippy::unnecessary_cast)]
+        // c_ulong isn't guaranteed to be u64 on all platforms
+        let is_read = unsafe {
+            (*(data_availability_ptr as *mut FpdfDataAvailabilityExt))
+                .source
+                .read_data(
+                    position as u64,
+                    slice::from_raw_parts_mut(buf, size as usize),
+                )
+        };
+
+        is_read as c_int
+    }
+
     /// Returns a wrapped Pdfium `FPDF_FILEWRITE` struct that uses the given writer as an
     /// output source for Pdfium's file writing callback function.
     pub(crate) fn get_pdfium_file_writer_from_writer<W: Write + 'static>(
@@ -433,6 +761,158 @@ pub(crate) mod files {
     }
 }
 
+pub(crate) mod asn1 {
+    // A minimal reader for DER-encoded ASN.1 data, sufficient for walking the PKCS#7
+    // and X.509 structures embedded in digital signatures.
+
+    /// The DER tag for an ASN.1 INTEGER.
+    pub(crate) const TAG_INTEGER: u8 = 0x02;
+
+    /// The DER tag for an ASN.1 OCTET STRING.
+    pub(crate) const TAG_OCTET_STRING: u8 = 0x04;
+
+    /// The DER tag for an ASN.1 OBJECT IDENTIFIER.
+    pub(crate) const TAG_OBJECT_IDENTIFIER: u8 = 0x06;
+
+    /// The DER tag for an ASN.1 SEQUENCE.
+    pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+
+    /// The DER tag for an ASN.1 SET.
+    pub(crate) const TAG_SET: u8 = 0x31;
+
+    /// A single DER-encoded ASN.1 element.
+    #[derive(Debug, Copy, Clone)]
+    pub(crate) struct DerElement<'a> {
+        /// The single-byte identifier tag of this element.
+        pub(crate) tag: u8,
+
+        /// The contents of this element, excluding the tag and length bytes.
+        pub(crate) contents: &'a [u8],
+
+        /// The complete encoding of this element, including the tag and length bytes.
+        pub(crate) encoded: &'a [u8],
+    }
+
+    impl<'a> DerElement<'a> {
+        /// Returns `true` if this element is a context-specific tagged element
+        /// with the given tag number.
+        #[inline]
+        pub(crate) fn is_context_specific(&self, number: u8) -> bool {
+            self.tag & 0xC0 == 0x80 && self.tag & 0x1F == number
+        }
+
+        /// Parses the contents of this constructed element into its child elements.
+        #[inline]
+        pub(crate) fn children(&self) -> Option<Vec<DerElement<'a>>> {
+            read_all_elements(self.contents)
+        }
+
+        /// Returns the dotted decimal representation of this element's contents,
+        /// assuming this element is an OBJECT IDENTIFIER.
+        pub(crate) fn as_oid(&self) -> Option<String> {
+            if self.tag != TAG_OBJECT_IDENTIFIER || self.contents.is_empty() {
+                return None;
+            }
+
+            let mut arcs = Vec::new();
+
+            let mut value: u64 = 0;
+
+            for byte in self.contents {
+                value = value.checked_mul(128)? | (byte & 0x7F) as u64;
+
+                if byte & 0x80 == 0 {
+                    if arcs.is_empty() {
+                        // The first encoded value combines the first two arcs.
+
+                        let first = (value / 40).min(2);
+
+                        arcs.push(first);
+                        arcs.push(value - first * 40);
+                    } else {
+                        arcs.push(value);
+                    }
+
+                    value = 0;
+                }
+            }
+
+            Some(
+                arcs.iter()
+                    .map(|arc| arc.to_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            )
+        }
+    }
+
+    /// Reads a single DER element from the start of the given buffer, returning the element
+    /// and the remaining unread bytes in the buffer.
+    ///
+    /// Only the low tag number form and definite length encodings are supported.
+    pub(crate) fn read_element(input: &[u8]) -> Option<(DerElement, &[u8])> {
+        let (&tag, rest) = input.split_first()?;
+
+        if tag & 0x1F == 0x1F {
+            // High tag number form is not used by any of the structures we need to read.
+
+            return None;
+        }
+
+        let (&first_length_byte, rest) = rest.split_first()?;
+
+        let (length, rest) = if first_length_byte & 0x80 == 0 {
+            // Short form length.
+
+            (first_length_byte as usize, rest)
+        } else {
+            // Long form length. A value of 0x80 indicates BER indefinite length encoding,
+            // which is not valid DER.
+
+            let count = (first_length_byte & 0x7F) as usize;
+
+            if count == 0 || count > std::mem::size_of::<u32>() || rest.len() < count {
+                return None;
+            }
+
+            let length = rest[..count]
+                .iter()
+                .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+
+            (length, &rest[count..])
+        };
+
+        if rest.len() < length {
+            return None;
+        }
+
+        let header_length = input.len() - rest.len();
+
+        Some((
+            DerElement {
+                tag,
+                contents: &rest[..length],
+                encoded: &input[..header_length + length],
+            },
+            &rest[length..],
+        ))
+    }
+
+    /// Reads consecutive DER elements from the given buffer until the buffer is exhausted.
+    pub(crate) fn read_all_elements(mut input: &[u8]) -> Option<Vec<DerElement>> {
+        let mut result = Vec::new();
+
+        while !input.is_empty() {
+            let (element, rest) = read_element(input)?;
+
+            result.push(element);
+            input = rest;
+        }
+
+        Some(result)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     // Provides a function that binds to the correct Pdfium configuration during unit tests,
@@ -459,6 +939,9 @@ pub(crate) mod test {
 
 #[cfg(test)]
 mod tests {
+    use crate::error::PdfiumError;
+    use crate::utils::asn1::*;
+    use crate::utils::content::*;
     use crate::utils::dates::*;
     use crate::utils::pixels::*;
     use chrono::prelude::*;
@@ -548,6 +1031,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_aligned_bgra_to_rgba() {
+        // Interpret the sample data as 12-byte scanlines with each line consisting of two pixels
+        // (each taking four bytes) followed by four alignment bytes.
+
+        let data: [u8; 24] = [
+            2, 1, 0, 3, 6, 5, 4, 7, 99, 99, 99, 99, 10, 9, 8, 11, 14, 13, 12, 15, 99, 99, 99, 99,
+        ];
+
+        assert_eq!(
+            aligned_bgra_to_rgba(data.as_slice(), 2, 12),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+
+        assert_eq!(
+            aligned_bgrx_to_rgba(data.as_slice(), 2, 12),
+            [0, 1, 2, 255, 4, 5, 6, 255, 8, 9, 10, 255, 12, 13, 14, 255]
+        );
+
+        assert_eq!(
+            aligned_rgba_to_rgba(data.as_slice(), 2, 12),
+            [2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11, 14, 13, 12, 15]
+        );
+
+        assert_eq!(
+            aligned_rgbx_to_rgba(data.as_slice(), 2, 12),
+            [2, 1, 0, 255, 6, 5, 4, 255, 10, 9, 8, 255, 14, 13, 12, 255]
+        );
+    }
+
+    #[test]
+    fn test_aligned_gray_to_gray() {
+        // Interpret the sample data as four-byte scanlines with each line consisting of
+        // three pixels (each taking one byte) followed by one alignment byte.
+
+        let data: [u8; 8] = [0, 1, 2, 99, 3, 4, 5, 99];
+
+        assert_eq!(
+            aligned_gray_to_gray(data.as_slice(), 3, 4),
+            [0, 1, 2, 3, 4, 5]
+        );
+    }
+
     #[test]
     fn test_rgb_to_bgra() {
         let data: [u8; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
@@ -596,4 +1122,110 @@ mod tests {
             "D:19981223195200-08'00'"
         )
     }
+
+    #[test]
+    fn test_pdf_string_to_date_time() {
+        assert_eq!(
+            pdf_string_to_date_time("D:19981223195200-08'00'").unwrap(),
+            FixedOffset::west_opt(8 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(1998, 12, 23, 19, 52, 0)
+                .unwrap()
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("D:19981223195200Z00'00'").unwrap(),
+            Utc.with_ymd_and_hms(1998, 12, 23, 19, 52, 0).unwrap()
+        );
+
+        // All fields after the year are optional, as is the D: prefix.
+
+        assert_eq!(
+            pdf_string_to_date_time("D:1998").unwrap(),
+            Utc.with_ymd_and_hms(1998, 1, 1, 0, 0, 0).unwrap()
+        );
+
+        assert_eq!(
+            pdf_string_to_date_time("199812231952+05").unwrap(),
+            FixedOffset::east_opt(5 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(1998, 12, 23, 19, 52, 0)
+                .unwrap()
+        );
+
+        // Round-tripping a formatted date should return the original date.
+
+        let date = Utc.with_ymd_and_hms(2023, 6, 30, 12, 34, 56).unwrap();
+
+        assert_eq!(
+            pdf_string_to_date_time(&date_time_to_pdf_string(date)).unwrap(),
+            date
+        );
+    }
+
+    #[test]
+    fn test_pdf_string_to_date_time_rejects_malformed_strings() {
+        for value in [
+            "",
+            "D:",
+            "D:98",
+            "D:1998123",
+            "D:19981323",
+            "D:19981223195200X",
+            "D:19981223195200+8",
+            "D:19981223195200+08'00'junk",
+            "yesterday",
+        ] {
+            assert!(
+                matches!(
+                    pdf_string_to_date_time(value),
+                    Err(PdfiumError::ParsePdfDateStringError)
+                ),
+                "{}",
+                value
+            );
+        }
+    }
+
+    // Tests of DER parsing functions.
+
+    #[test]
+    fn test_read_der_sequence() {
+        // SEQUENCE { INTEGER 5, OBJECT IDENTIFIER 1.2.840.113549.1.7.2 } followed by
+        // trailing zero padding, as found in the /Contents of signature dictionaries.
+
+        let data: [u8; 20] = [
+            0x30, 0x0E, 0x02, 0x01, 0x05, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01,
+            0x07, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let (sequence, rest) = read_element(data.as_slice()).unwrap();
+
+        assert_eq!(sequence.tag, TAG_SEQUENCE);
+        assert_eq!(sequence.encoded, &data[..16]);
+        assert_eq!(rest, [0, 0, 0, 0]);
+
+        let children = sequence.children().unwrap();
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].tag, TAG_INTEGER);
+        assert_eq!(children[0].contents, [5]);
+        assert_eq!(children[1].as_oid().unwrap(), "1.2.840.113549.1.7.2");
+    }
+
+    #[test]
+    fn test_read_der_rejects_truncated_input() {
+        assert!(read_element([0x30, 0x05, 0x02, 0x01].as_slice()).is_none());
+        assert!(read_element([0x30, 0x80, 0x00, 0x00].as_slice()).is_none());
+    }
+
+    // Tests of content stream formatting functions.
+
+    #[test]
+    fn test_format_pdf_number() {
+        assert_eq!(format_pdf_number(12.0), "12");
+        assert_eq!(format_pdf_number(0.5), "0.5");
+        assert_eq!(format_pdf_number(1.0 / 3.0), "0.333");
+        assert_eq!(format_pdf_number(-0.0001), "0");
+    }
 }
@@ -3,10 +3,11 @@ use crate::bindgen::{
     FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL,
     FPDF_CLIPPATH, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD, FPDF_FILEACCESS,
     FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO, FPDF_FORMHANDLE, FPDF_GLYPHPATH,
-    FPDF_IMAGEOBJ_METADATA, FPDF_LINK, FPDF_OBJECT_TYPE, FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT,
-    FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE, FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE,
-    FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE, FPDF_TEXT_RENDERMODE, FPDF_WCHAR,
-    FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF, FS_QUADPOINTSF, FS_RECTF,
+    FPDF_IMAGEOBJ_METADATA, FPDF_JAVASCRIPT_ACTION, FPDF_LINK, FPDF_OBJECT_TYPE, FPDF_PAGE,
+    FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK, FPDF_PAGERANGE, FPDF_PATHSEGMENT,
+    FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRUCTELEMENT, FPDF_STRUCTTREE, FPDF_TEXTPAGE,
+    FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FS_FLOAT, FS_MATRIX, FS_POINTF,
+    FS_QUADPOINTSF, FS_RECTF, IFSDK_PAUSE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
@@ -20,7 +21,9 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 use std::ffi::CString;
 use std::mem::size_of;
-use std::os::raw::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_ulong, c_ushort, c_void};
+use std::os::raw::{
+    c_char, c_double, c_float, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void,
+};
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use wasm_bindgen::intern;
 use wasm_bindgen::prelude::*;
@@ -1222,6 +1225,12 @@ impl WasmPdfiumBindings {
         Self::js_value_from_offset(attachment as usize)
     }
 
+    /// Converts a pointer to an `FPDF_JAVASCRIPT_ACTION` struct to a [JsValue].
+    #[inline]
+    fn js_value_from_javascript_action(javascript: FPDF_JAVASCRIPT_ACTION) -> JsValue {
+        Self::js_value_from_offset(javascript as usize)
+    }
+
     /// Converts a pointer to an `FPDF_SCHHANDLE` struct to a [JsValue].
     #[inline]
     fn js_value_from_search(search: FPDF_SCHHANDLE) -> JsValue {
@@ -3983,6 +3992,90 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         state.free(ptr_clipping);
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPageBitmap_Start(
+        &self,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        _pause: *mut IFSDK_PAUSE,
+    ) -> c_int {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RenderPageBitmap_Start()");
+
+        // Pdfium cannot invoke the NeedToPauseNow() callback in our WASM heap, so we pass
+        // a null pause interface. Pdfium will render the entire page in a single step.
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDF_RenderPageBitmap_Start",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Self::js_array_from_vec(vec![
+                    Self::js_value_from_bitmap(bitmap),
+                    Self::js_value_from_page(page),
+                    JsValue::from(start_x),
+                    JsValue::from(start_y),
+                    JsValue::from(size_x),
+                    JsValue::from(size_y),
+                    JsValue::from(rotate),
+                    JsValue::from(flags),
+                    Self::js_value_from_offset(0),
+                ]))),
+            )
+            .as_f64()
+            .unwrap() as c_int
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Continue(&self, page: FPDF_PAGE, _pause: *mut IFSDK_PAUSE) -> c_int {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RenderPage_Continue()");
+
+        // As for FPDF_RenderPageBitmap_Start(), we pass a null pause interface.
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDF_RenderPage_Continue",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_page(page),
+                    &Self::js_value_from_offset(0),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as c_int
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RenderPage_Close()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FPDF_RenderPage_Close",
+            JsFunctionArgumentType::Void,
+            Some(vec![JsFunctionArgumentType::Pointer]),
+            Some(&JsValue::from(Array::of1(&Self::js_value_from_page(page)))),
+        );
+    }
+
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAnnot_IsSupportedSubtype()");
@@ -4658,10 +4751,12 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
                 Some(vec![
                     JsFunctionArgumentType::Pointer,
                     JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
                 ]),
-                Some(&JsValue::from(Array::of2(
+                Some(&JsValue::from(Array::of3(
                     &Self::js_value_from_annotation(annot),
                     &Self::js_value_from_offset(ptr_buffer),
+                    &JsValue::from_f64(length as f64),
                 ))),
             )
             .as_f64()
@@ -4717,11 +4812,13 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
                     JsFunctionArgumentType::Pointer,
                     JsFunctionArgumentType::Number,
                     JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
                 ]),
-                Some(&JsValue::from(Array::of3(
+                Some(&JsValue::from(Array::of4(
                     &Self::js_value_from_annotation(annot),
                     &JsValue::from_f64(path_index as f64),
                     &Self::js_value_from_offset(ptr_buffer),
+                    &JsValue::from_f64(length as f64),
                 ))),
             )
             .as_f64()
@@ -5915,6 +6012,56 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         result
     }
 
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_GetFileAttachment(&self, annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAnnot_GetFileAttachment()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDFAnnot_GetFileAttachment",
+                JsFunctionArgumentType::Number,
+                Some(vec![JsFunctionArgumentType::Pointer]),
+                Some(&JsValue::from(Array::of1(&Self::js_value_from_annotation(
+                    annot,
+                )))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_ATTACHMENT
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFAnnot_AddFileAttachment(
+        &self,
+        annot: FPDF_ANNOTATION,
+        name: FPDF_WIDESTRING,
+    ) -> FPDF_ATTACHMENT {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAnnot_AddFileAttachment()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let name_ptr = state.copy_struct_to_pdfium(name);
+
+        let result = state
+            .call(
+                "FPDFAnnot_AddFileAttachment",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_annotation(annot),
+                    &Self::js_value_from_offset(name_ptr),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_ATTACHMENT;
+
+        state.free(name_ptr);
+
+        result
+    }
+
     #[allow(non_snake_case)]
     fn FPDFDOC_InitFormFillEnvironment(
         &self,
@@ -6044,6 +6191,20 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         );
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_RemoveFormFieldHighlight(&self, handle: FPDF_FORMHANDLE) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_RemoveFormFieldHighlight()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FPDF_RemoveFormFieldHighlight",
+            JsFunctionArgumentType::Void,
+            Some(vec![JsFunctionArgumentType::Pointer]),
+            Some(&JsValue::from(Array::of1(&Self::js_value_from_form(
+                handle,
+            )))),
+        );
+    }
+
     #[allow(non_snake_case)]
     fn FPDF_FFLDraw(
         &self,
@@ -11504,6 +11665,116 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
         result as c_ulong
     }
 
+    #[allow(non_snake_case)]
+    fn FPDF_CountNamedDests(&self, document: FPDF_DOCUMENT) -> FPDF_DWORD {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_CountNamedDests()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDF_CountNamedDests",
+                JsFunctionArgumentType::Number,
+                Some(vec![JsFunctionArgumentType::Pointer]),
+                Some(&JsValue::from(Array::of1(&Self::js_value_from_document(
+                    document,
+                )))),
+            )
+            .as_f64()
+            .unwrap() as FPDF_DWORD
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDestByName(&self, document: FPDF_DOCUMENT, name: &str) -> FPDF_DEST {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_GetNamedDestByName()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let c_name = CString::new(name).unwrap();
+
+        let name_ptr = state.copy_bytes_to_pdfium(&c_name.into_bytes_with_nul());
+
+        let result = state
+            .call(
+                "FPDF_GetNamedDestByName",
+                JsFunctionArgumentType::Pointer,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_document(document),
+                    &Self::js_value_from_offset(name_ptr),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_DEST;
+
+        state.free(name_ptr);
+
+        result
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDF_GetNamedDest(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: *mut c_long,
+    ) -> FPDF_DEST {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_GetNamedDest()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let buffer_length = unsafe { *buflen }.max(0) as usize;
+
+        let buffer_ptr = if !buffer.is_null() && buffer_length > 0 {
+            state.malloc(buffer_length)
+        } else {
+            0
+        };
+
+        let buflen_length = size_of::<c_long>();
+
+        let buflen_ptr = state.copy_struct_to_pdfium(buflen);
+
+        let result = state
+            .call(
+                "FPDF_GetNamedDest",
+                JsFunctionArgumentType::Pointer,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                ]),
+                Some(&JsValue::from(Array::of4(
+                    &Self::js_value_from_document(document),
+                    &JsValue::from_f64(index as f64),
+                    &Self::js_value_from_offset(buffer_ptr),
+                    &Self::js_value_from_offset(buflen_ptr),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_DEST;
+
+        unsafe {
+            *buflen = state
+                .copy_bytes_from_pdfium(buflen_ptr, buflen_length)
+                .try_into()
+                .map(c_long::from_le_bytes)
+                .unwrap_or(0);
+
+            if buffer_ptr != 0 && *buflen > 0 && *buflen as usize <= buffer_length {
+                state.copy_struct_from_pdfium(buffer_ptr, *buflen as usize, buffer);
+            }
+        }
+
+        state.free(buffer_ptr);
+        state.free(buflen_ptr);
+
+        result
+    }
+
     #[allow(non_snake_case)]
     fn FPDFDoc_GetAttachmentCount(&self, document: FPDF_DOCUMENT) -> c_int {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFDoc_GetAttachmentCount()");
@@ -11925,4 +12196,222 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
 
         result
     }
+
+    #[allow(non_snake_case)]
+    fn FPDFAttachment_GetSubtype(
+        &self,
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAttachment_GetSubtype()");
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let buffer_length = buflen as usize;
+
+        let buffer_ptr = if buffer_length > 0 {
+            state.malloc(buffer_length)
+        } else {
+            0
+        };
+
+        let result = state
+            .call(
+                "FPDFAttachment_GetSubtype",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of3(
+                    &Self::js_value_from_attachment(attachment),
+                    &Self::js_value_from_offset(buffer_ptr),
+                    &JsValue::from_f64(buffer_length as f64),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize;
+
+        if result > 0 && result <= buffer_length {
+            state.copy_struct_from_pdfium(buffer_ptr, result, buffer);
+        }
+
+        state.free(buffer_ptr);
+
+        result as c_ulong
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptActionCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFDoc_GetJavaScriptActionCount()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDFDoc_GetJavaScriptActionCount",
+                JsFunctionArgumentType::Number,
+                Some(vec![JsFunctionArgumentType::Pointer]),
+                Some(&JsValue::from(Array::of1(&Self::js_value_from_document(
+                    document,
+                )))),
+            )
+            .as_f64()
+            .unwrap() as c_int
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFDoc_GetJavaScriptAction(
+        &self,
+        document: FPDF_DOCUMENT,
+        index: c_int,
+    ) -> FPDF_JAVASCRIPT_ACTION {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFDoc_GetJavaScriptAction()");
+
+        PdfiumRenderWasmState::lock()
+            .call(
+                "FPDFDoc_GetJavaScriptAction",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of2(
+                    &Self::js_value_from_document(document),
+                    &JsValue::from_f64(index as f64),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize as FPDF_JAVASCRIPT_ACTION
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFDoc_CloseJavaScriptAction(&self, javascript: FPDF_JAVASCRIPT_ACTION) {
+        log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFDoc_CloseJavaScriptAction()");
+
+        PdfiumRenderWasmState::lock().call(
+            "FPDFDoc_CloseJavaScriptAction",
+            JsFunctionArgumentType::Void,
+            Some(vec![JsFunctionArgumentType::Pointer]),
+            Some(&JsValue::from(Array::of1(
+                &Self::js_value_from_javascript_action(javascript),
+            ))),
+        );
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetName(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetName(): entering"
+        );
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let buffer_length = buflen as usize;
+
+        let buffer_ptr = if buffer_length > 0 {
+            log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetName(): allocating buffer of {} bytes in Pdfium's WASM heap", buffer_length);
+
+            state.malloc(buffer_length)
+        } else {
+            0
+        };
+
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetName(): calling FPDFJavaScriptAction_GetName()"
+        );
+
+        let result = state
+            .call(
+                "FPDFJavaScriptAction_GetName",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of3(
+                    &Self::js_value_from_javascript_action(javascript),
+                    &Self::js_value_from_offset(buffer_ptr),
+                    &JsValue::from_f64(buffer_length as f64),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize;
+
+        if result > 0 && result <= buffer_length {
+            state.copy_struct_from_pdfium(buffer_ptr, result, buffer);
+        }
+
+        state.free(buffer_ptr);
+
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetName(): leaving"
+        );
+
+        result as c_ulong
+    }
+
+    #[allow(non_snake_case)]
+    fn FPDFJavaScriptAction_GetScript(
+        &self,
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong {
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetScript(): entering"
+        );
+
+        let state = PdfiumRenderWasmState::lock();
+
+        let buffer_length = buflen as usize;
+
+        let buffer_ptr = if buffer_length > 0 {
+            log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetScript(): allocating buffer of {} bytes in Pdfium's WASM heap", buffer_length);
+
+            state.malloc(buffer_length)
+        } else {
+            0
+        };
+
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetScript(): calling FPDFJavaScriptAction_GetScript()"
+        );
+
+        let result = state
+            .call(
+                "FPDFJavaScriptAction_GetScript",
+                JsFunctionArgumentType::Number,
+                Some(vec![
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Pointer,
+                    JsFunctionArgumentType::Number,
+                ]),
+                Some(&JsValue::from(Array::of3(
+                    &Self::js_value_from_javascript_action(javascript),
+                    &Self::js_value_from_offset(buffer_ptr),
+                    &JsValue::from_f64(buffer_length as f64),
+                ))),
+            )
+            .as_f64()
+            .unwrap() as usize;
+
+        if result > 0 && result <= buffer_length {
+            state.copy_struct_from_pdfium(buffer_ptr, result, buffer);
+        }
+
+        state.free(buffer_ptr);
+
+        log::debug!(
+            "pdfium-render::PdfiumLibraryBindings::FPDFJavaScriptAction_GetScript(): leaving"
+        );
+
+        result as c_ulong
+    }
 }